@@ -0,0 +1,219 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+/// Derives `rquery_builder::Table` for a struct with named fields.
+///
+/// The table is named after the struct in snake case, e.g. `order_item` for `OrderItem`,
+/// unless set with `#[table(name = "...")]`, which may be schema qualified.
+/// Every field is a column bound through `SqlValue::from`, with these options:
+/// - `#[column(rename = "...")]` names the column instead of the field.
+/// - `#[column(skip)]` leaves the field out, e.g. a computed or joined value.
+/// - `#[column(json)]` binds the field serialized as `jsonb`, for any `Serialize` type.
+/// - `#[column(primary_key)]` marks the field as (part of) the primary key, which is the
+///   `id` field when none is marked.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Table)]
+/// #[table(name = "app.users")]
+/// struct User {
+///     id: i64,
+///     #[column(rename = "email_address")]
+///     email: String,
+///     #[column(json)]
+///     profile: Profile,
+///     #[column(skip)]
+///     order_count: i64,
+/// }
+/// ```
+#[proc_macro_derive(Table, attributes(table, column))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct Column {
+    name: String,
+    value: TokenStream2,
+    primary_key: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "Table can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "Table can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut table = snake_case(&ident.to_string());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("table"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                table = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else {
+                Err(meta.error("unsupported table attribute, expected `name`"))
+            }
+        })?;
+    }
+
+    let mut columns: Vec<Column> = Vec::new();
+    for field in fields {
+        let Some(field_ident) = &field.ident else {
+            continue;
+        };
+        let mut name = field_ident.to_string().trim_start_matches("r#").to_string();
+        let (mut skip, mut json, mut primary_key) = (false, false, false);
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("column"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("json") {
+                    json = true;
+                } else if meta.path.is_ident("primary_key") {
+                    primary_key = true;
+                } else {
+                    return Err(meta.error(
+                        "unsupported column attribute, expected `rename`, `skip`, `json` or `primary_key`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        if skip {
+            continue;
+        }
+        let value = if json {
+            quote! {
+                ::rquery_builder::table::json_value(
+                    stringify!(#ident),
+                    #name,
+                    &self.#field_ident,
+                )?
+            }
+        } else {
+            quote! {
+                ::rquery_builder::SqlValue::from(::core::clone::Clone::clone(&self.#field_ident))
+            }
+        };
+        columns.push(Column {
+            name,
+            value,
+            primary_key,
+        });
+    }
+
+    let names: Vec<&str> = columns.iter().map(|column| column.name.as_str()).collect();
+    let mut keys: Vec<&str> = columns
+        .iter()
+        .filter(|column| column.primary_key)
+        .map(|column| column.name.as_str())
+        .collect();
+    if keys.is_empty() && names.contains(&"id") {
+        keys.push("id");
+    }
+    let values = columns.iter().map(|column| &column.value);
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::rquery_builder::Table for #ident #type_generics #where_clause {
+            fn table_name() -> &'static str {
+                #table
+            }
+
+            fn columns() -> Vec<&'static str> {
+                vec![#(#names),*]
+            }
+
+            fn primary_key() -> Vec<&'static str> {
+                vec![#(#keys),*]
+            }
+
+            fn values(&self) -> ::rquery_builder::Result<Vec<::rquery_builder::SqlValue>> {
+                Ok(vec![#(#values),*])
+            }
+        }
+    })
+}
+
+/// `OrderItem` to `order_item`.
+fn snake_case(value: &str) -> String {
+    let mut result = String::new();
+    for (index, character) in value.char_indices() {
+        if character.is_uppercase() {
+            if index > 0 {
+                result.push('_');
+            }
+            result.extend(character.to_lowercase());
+        } else {
+            result.push(character);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+pub mod test_derive {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("User"), "user");
+        assert_eq!(snake_case("OrderItem"), "order_item");
+        assert_eq!(snake_case("order"), "order");
+    }
+
+    #[test]
+    fn test_expand_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Point(i32, i32);
+        };
+        let result = expand(input).map(|_| ());
+        assert_eq!(
+            result.map_err(|e| e.to_string()),
+            Err("Table can only be derived for structs with named fields".to_string())
+        );
+
+        let input: DeriveInput = syn::parse_quote! {
+            struct User {
+                #[column(index)]
+                id: i64,
+            }
+        };
+        let result = expand(input).map(|_| ());
+        assert_eq!(
+            result.map_err(|e| e.to_string()),
+            Err(
+                "unsupported column attribute, expected `rename`, `skip`, `json` or `primary_key`"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use crate::error::{Error, Result};
+use crate::postgres::Operator;
+
+/// The upsert clause of an insert, rendered with the syntax of the dialect.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Upsert {
+    DoNothing(Vec<String>),             // conflict target columns
+    DoUpdate(Vec<String>, Vec<String>), // conflict target columns, columns set from the inserted row
+}
+
+/// The syntax differences between the databases the builders can render for.
+///
+/// The builders in `postgres` render for PostgreSQL with `build()`, and for any dialect
+/// with `build_with(&dialect)`. Clauses the dialect can't express are rejected with an
+/// [`Error::UnsupportedClause`] whose `target` is [`Dialect::name`].
+///
+/// # Example
+/// ```ignore
+/// let statement = builder.build_with(&Sqlite)?; // same query definition, SQLite syntax
+/// ```
+pub trait Dialect {
+    /// The name of the database, used as the `target` of unsupported clause errors.
+    fn name(&self) -> &'static str;
+
+    /// Quotes a single identifier when it would not be read back as-is unquoted.
+    fn quote_identifier(&self, value: &str) -> String;
+
+    /// Renders the n-th (starting at 1) placeholder.
+    fn placeholder(&self, index: usize) -> String;
+
+    /// Renders the pagination clause. The row counts are either inlined numbers or `?`.
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> Option<String>;
+
+    /// Whether the ANSI `OFFSET n ROWS FETCH FIRST n ROWS ONLY` form is supported.
+    fn supports_fetch_first(&self) -> bool;
+
+    /// Renders a boolean literal.
+    fn boolean(&self, value: bool) -> &'static str;
+
+    /// Whether `INSERT`, `UPDATE` and `DELETE` support `RETURNING`.
+    fn supports_returning(&self) -> bool;
+
+    /// Whether `FULL JOIN` is supported.
+    fn supports_full_join(&self) -> bool;
+
+    /// Whether `JOIN LATERAL` subqueries are supported.
+    fn supports_lateral(&self) -> bool;
+
+    /// Renders the upsert clause that follows the `VALUES` of an insert.
+    fn upsert(&self, value: &Upsert) -> Result<String>;
+
+    /// Whether the JSON operator is supported. Other operators are always supported,
+    /// except `ILIKE`, see [`supports_ilike`](Self::supports_ilike).
+    fn supports_json_operator(&self, operator: &Operator) -> bool;
+
+    /// Whether the PostgreSQL `::type` casts of the typed placeholders, e.g. `$1::uuid`,
+    /// are supported. They are dropped for the dialects that don't, whose values are
+    /// bound untyped.
+    fn supports_casts(&self) -> bool;
+
+    /// Whether the case-insensitive `ILIKE` is supported.
+    fn supports_ilike(&self) -> bool {
+        false
+    }
+
+    /// Quotes a single identifier as [`quote_identifier`](Self::quote_identifier), also
+    /// keeping the case of the names with uppercase letters.
+    fn quote_exact_identifier(&self, value: &str) -> String {
+        self.quote_identifier(value)
+    }
+
+    /// Quotes each part of a dotted name, e.g. `public.users`.
+    fn quote_qualified(&self, value: &str) -> String {
+        value
+            .split('.')
+            .map(|part| self.quote_identifier(part))
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// Replaces each `?` placeholder in the statement with the placeholder of the dialect,
+    /// keeping its cast if [`supports_casts`](Self::supports_casts). Quoted text is left
+    /// untouched, and a `??`, the escaped `?` of the JSONB `?`, `?|`, `?&` and `@?`
+    /// operators, is written back as `?` unless the placeholders are `?`.
+    fn render_placeholders(&self, statement: &str) -> String {
+        crate::placeholder::render_placeholders(
+            statement,
+            &|index| self.placeholder(index),
+            self.supports_casts(),
+        )
+    }
+
+    /// Returns an error for the first JSON operator or `ILIKE` the dialect doesn't support.
+    fn check_operators(&self, builder: &'static str, operators: &[Operator]) -> Result<()> {
+        if let Some(operator) = operators.iter().find(|operator| {
+            !self.supports_json_operator(operator)
+                || (**operator == Operator::ILike && !self.supports_ilike())
+        }) {
+            return Err(Error::UnsupportedClause {
+                builder,
+                field: operator.to_string(),
+                target: self.name().to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn is_json_operator(operator: &Operator) -> bool {
+    matches!(
+        operator,
+        Operator::JsonbValue
+            | Operator::JsonbValueAsText
+            | Operator::JsonbContains
+            | Operator::JsonbContained
+            | Operator::JsonbHasKey
+            | Operator::JsonbHasAnyKeys
+            | Operator::JsonbHasAllKeys
+            | Operator::JsonbConcatenate
+            | Operator::JsonbRemoveKey
+            | Operator::JsonbRemovePath
+            | Operator::JsonbHasPath
+            | Operator::JsonbPathExists
+    )
+}
+
+fn conflict_columns(dialect: &dyn Dialect, columns: &[String]) -> String {
+    if columns.is_empty() {
+        return String::new();
+    }
+    let columns: Vec<String> = columns
+        .iter()
+        .map(|column| dialect.quote_identifier(column))
+        .collect();
+    format!(" ({})", columns.join(", "))
+}
+
+/// `ON CONFLICT (columns) DO NOTHING | DO UPDATE SET column = excluded.column`, shared by
+/// PostgreSQL and SQLite.
+fn on_conflict(dialect: &dyn Dialect, value: &Upsert) -> Result<String> {
+    match value {
+        Upsert::DoNothing(columns) => Ok(format!(
+            "ON CONFLICT{} DO NOTHING",
+            conflict_columns(dialect, columns)
+        )),
+        Upsert::DoUpdate(columns, updates) => {
+            // DO UPDATE needs a conflict target.
+            if columns.is_empty() || updates.is_empty() {
+                return Err(Error::EmptyField {
+                    builder: "InsertBuilder",
+                    field: "on_conflict".to_string(),
+                });
+            }
+            let updates: Vec<String> = updates
+                .iter()
+                .map(|column| {
+                    let column = dialect.quote_identifier(column);
+                    format!("{column} = excluded.{column}")
+                })
+                .collect();
+            Ok(format!(
+                "ON CONFLICT{} DO UPDATE SET {}",
+                conflict_columns(dialect, columns),
+                updates.join(", ")
+            ))
+        }
+    }
+}
+
+/// PostgreSQL: `$1` placeholders and every JSONB operator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Postgres;
+
+impl Dialect for Postgres {
+    fn name(&self) -> &'static str {
+        "PostgreSQL"
+    }
+
+    fn quote_identifier(&self, value: &str) -> String {
+        crate::identifier::quote_identifier(value)
+    }
+
+    fn quote_exact_identifier(&self, value: &str) -> String {
+        crate::identifier::quote_exact_identifier(value)
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("${index}")
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> Option<String> {
+        let mut statement: Vec<String> = Vec::new();
+        if let Some(value) = limit {
+            statement.push(format!("LIMIT {value}"));
+        }
+        if let Some(value) = offset {
+            statement.push(format!("OFFSET {value}"));
+        }
+        (!statement.is_empty()).then(|| statement.join(" "))
+    }
+
+    fn supports_fetch_first(&self) -> bool {
+        true
+    }
+
+    fn boolean(&self, value: bool) -> &'static str {
+        if value { "TRUE" } else { "FALSE" }
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn supports_full_join(&self) -> bool {
+        true
+    }
+
+    fn supports_lateral(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, value: &Upsert) -> Result<String> {
+        on_conflict(self, value)
+    }
+
+    fn supports_json_operator(&self, _operator: &Operator) -> bool {
+        true
+    }
+
+    fn supports_casts(&self) -> bool {
+        true
+    }
+
+    fn supports_ilike(&self) -> bool {
+        true
+    }
+}
+
+/// SQLite: `?` placeholders, `1`/`0` booleans, only the `->`/`->>` JSON operators and no
+/// `LATERAL` joins.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    fn name(&self) -> &'static str {
+        "SQLite"
+    }
+
+    fn quote_identifier(&self, value: &str) -> String {
+        crate::identifier::quote_identifier(value)
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> Option<String> {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => Some(format!("LIMIT {limit} OFFSET {offset}")),
+            (Some(limit), None) => Some(format!("LIMIT {limit}")),
+            // SQLite only accepts OFFSET after a LIMIT, -1 means no limit.
+            (None, Some(offset)) => Some(format!("LIMIT -1 OFFSET {offset}")),
+            (None, None) => None,
+        }
+    }
+
+    fn supports_fetch_first(&self) -> bool {
+        false
+    }
+
+    fn boolean(&self, value: bool) -> &'static str {
+        if value { "1" } else { "0" }
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn supports_full_join(&self) -> bool {
+        true
+    }
+
+    fn supports_lateral(&self) -> bool {
+        false
+    }
+
+    fn upsert(&self, value: &Upsert) -> Result<String> {
+        on_conflict(self, value)
+    }
+
+    fn supports_json_operator(&self, operator: &Operator) -> bool {
+        !is_json_operator(operator)
+            || matches!(operator, Operator::JsonbValue | Operator::JsonbValueAsText)
+    }
+
+    fn supports_casts(&self) -> bool {
+        false
+    }
+}
+
+/// MySQL: backtick quoting, `?` placeholders, `ON DUPLICATE KEY UPDATE`, and no
+/// `RETURNING` nor `FULL JOIN`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MySql;
+
+impl Dialect for MySql {
+    fn name(&self) -> &'static str {
+        "MySQL"
+    }
+
+    fn quote_identifier(&self, value: &str) -> String {
+        format!("`{}`", value.replace('`', "``"))
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    /// Renders `LIMIT offset, count`. Bound row counts are rendered `LIMIT ? OFFSET ?`
+    /// instead, so that their values keep the order of the other dialects.
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> Option<String> {
+        // MySQL needs a LIMIT with an offset, this is the largest row count it accepts.
+        let count = limit.unwrap_or("18446744073709551615");
+        match (limit, offset) {
+            (_, Some("?")) => Some(format!("LIMIT {count} OFFSET ?")),
+            (_, Some(offset)) => Some(format!("LIMIT {offset}, {count}")),
+            (Some(limit), None) => Some(format!("LIMIT {limit}")),
+            (None, None) => None,
+        }
+    }
+
+    fn supports_fetch_first(&self) -> bool {
+        false
+    }
+
+    fn boolean(&self, value: bool) -> &'static str {
+        if value { "TRUE" } else { "FALSE" }
+    }
+
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    fn supports_full_join(&self) -> bool {
+        false
+    }
+
+    fn supports_lateral(&self) -> bool {
+        true
+    }
+
+    /// MySQL has no conflict target: the update applies to any duplicate key.
+    fn upsert(&self, value: &Upsert) -> Result<String> {
+        match value {
+            Upsert::DoNothing(_) => Err(Error::UnsupportedClause {
+                builder: "InsertBuilder",
+                field: "DO NOTHING".to_string(),
+                target: self.name().to_string(),
+            }),
+            Upsert::DoUpdate(_, updates) => {
+                if updates.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "InsertBuilder",
+                        field: "on_conflict".to_string(),
+                    });
+                }
+                let updates: Vec<String> = updates
+                    .iter()
+                    .map(|column| {
+                        let column = self.quote_identifier(column);
+                        format!("{column} = VALUES({column})")
+                    })
+                    .collect();
+                Ok(format!("ON DUPLICATE KEY UPDATE {}", updates.join(", ")))
+            }
+        }
+    }
+
+    fn supports_json_operator(&self, operator: &Operator) -> bool {
+        !is_json_operator(operator)
+            || matches!(operator, Operator::JsonbValue | Operator::JsonbValueAsText)
+    }
+
+    fn supports_casts(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+pub mod test_dialect {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dialect() {
+        let statement = "SELECT * FROM users WHERE id = ? AND name = ?";
+        assert_eq!(
+            Postgres.render_placeholders(statement),
+            "SELECT * FROM users WHERE id = $1 AND name = $2"
+        );
+        assert_eq!(Sqlite.render_placeholders(statement), statement);
+        assert_eq!(MySql.render_placeholders(statement), statement);
+
+        // The escaped `?` of the JSONB operators and quoted text aren't placeholders.
+        let statement = "SELECT * FROM t WHERE t.data ?? ? AND t.data ??| ? AND t.note = '?'";
+        assert_eq!(
+            Postgres.render_placeholders(statement),
+            "SELECT * FROM t WHERE t.data ? $1 AND t.data ?| $2 AND t.note = '?'"
+        );
+
+        assert_eq!(Postgres.quote_qualified("public.Users"), "public.Users");
+        assert_eq!(Postgres.quote_qualified("public.User"), "public.\"user\"");
+        assert_eq!(Postgres.quote_exact_identifier("User"), "\"User\"");
+        assert_eq!(Sqlite.quote_qualified("users"), "users");
+        assert_eq!(MySql.quote_qualified("shop.users"), "`shop`.`users`");
+
+        assert_eq!(
+            Postgres.limit_offset(None, Some("5")),
+            Some("OFFSET 5".to_string())
+        );
+        assert_eq!(
+            Sqlite.limit_offset(None, Some("?")),
+            Some("LIMIT -1 OFFSET ?".to_string())
+        );
+        assert_eq!(
+            MySql.limit_offset(Some("10"), Some("5")),
+            Some("LIMIT 5, 10".to_string())
+        );
+        assert_eq!(
+            MySql.limit_offset(None, Some("5")),
+            Some("LIMIT 5, 18446744073709551615".to_string())
+        );
+        assert_eq!(
+            MySql.limit_offset(Some("?"), Some("?")),
+            Some("LIMIT ? OFFSET ?".to_string())
+        );
+        assert_eq!(Postgres.limit_offset(None, None), None);
+
+        let statement = "INSERT INTO t(id, at) VALUES (?::uuid, ?::timestamptz[])";
+        assert_eq!(
+            Postgres.render_placeholders(statement),
+            "INSERT INTO t(id, at) VALUES ($1::uuid, $2::timestamptz[])"
+        );
+        assert_eq!(
+            Sqlite.render_placeholders(statement),
+            "INSERT INTO t(id, at) VALUES (?, ?)"
+        );
+
+        assert_eq!(Postgres.boolean(true), "TRUE");
+        assert_eq!(Sqlite.boolean(false), "0");
+        assert!(!MySql.supports_returning());
+    }
+
+    #[tokio::test]
+    async fn test_dialect_upsert() {
+        let upsert = Upsert::DoUpdate(vec!["email".to_string()], vec!["name".to_string()]);
+        assert_eq!(
+            Postgres.upsert(&upsert),
+            Ok("ON CONFLICT (email) DO UPDATE SET name = excluded.name".to_string())
+        );
+        assert_eq!(Sqlite.upsert(&upsert), Postgres.upsert(&upsert));
+        assert_eq!(
+            MySql.upsert(&upsert),
+            Ok("ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)".to_string())
+        );
+        assert_eq!(
+            Sqlite.upsert(&Upsert::DoNothing(vec![])),
+            Ok("ON CONFLICT DO NOTHING".to_string())
+        );
+        assert_eq!(
+            MySql.upsert(&Upsert::DoNothing(vec![])),
+            Err(Error::UnsupportedClause {
+                builder: "InsertBuilder",
+                field: "DO NOTHING".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+        assert!(
+            Postgres
+                .upsert(&Upsert::DoUpdate(vec![], vec!["name".to_string()]))
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dialect_json_operators() {
+        let operators = vec![Operator::Eq, Operator::JsonbValueAsText];
+        assert_eq!(Sqlite.check_operators("SelectBuilder", &operators), Ok(()));
+        let operators = vec![Operator::Eq, Operator::JsonbContains];
+        assert_eq!(
+            Postgres.check_operators("SelectBuilder", &operators),
+            Ok(())
+        );
+        assert_eq!(
+            Sqlite.check_operators("SelectBuilder", &operators),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "@>".to_string(),
+                target: "SQLite".to_string(),
+            })
+        );
+
+        let operators = vec![Operator::Like, Operator::ILike];
+        assert_eq!(
+            Postgres.check_operators("SelectBuilder", &operators),
+            Ok(())
+        );
+        assert_eq!(
+            MySql.check_operators("SelectBuilder", &operators),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "ILIKE".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::placeholder::PlaceholderKind;
+use thiserror::Error as ThisError;
+
+/// Errors returned by the builders.
+///
+/// Every variant carries the name of the builder that produced it and the field
+/// (or clause) that was rejected, so callers can map them to their own responses.
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum Error {
+    #[error("{builder}: `{field}` is empty")]
+    EmptyField {
+        builder: &'static str,
+        field: String,
+    },
+
+    #[error("{builder}: mismatched number of `{field}`, expected {expected} but got {found}")]
+    CountMismatch {
+        builder: &'static str,
+        field: String,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("{builder}: `{field}` can only be set once")]
+    DuplicateClause {
+        builder: &'static str,
+        field: String,
+    },
+
+    #[error("{builder}: `{field}` has an invalid identifier `{value}`")]
+    InvalidIdentifier {
+        builder: &'static str,
+        field: String,
+        value: String,
+    },
+
+    #[error("{builder}: operator `{operator}` does not support {value} on `{field}`")]
+    UnsupportedOperatorValue {
+        builder: &'static str,
+        field: String,
+        operator: String,
+        value: String,
+    },
+
+    #[error("{builder}: `{field}` is not supported by {target}")]
+    UnsupportedClause {
+        builder: &'static str,
+        field: String,
+        target: String,
+    },
+
+    #[error("{builder}: named parameter `{field}` has no value")]
+    MissingParameter {
+        builder: &'static str,
+        field: String,
+    },
+
+    #[error("{builder}: `{field}` could not be converted to a value: {message}")]
+    InvalidValue {
+        builder: &'static str,
+        field: String,
+        message: String,
+    },
+
+    #[error(
+        "{builder}: `{field}` should be using the {expected:?} placeholder kind but got {found:?}"
+    )]
+    PlaceholderKindConflict {
+        builder: &'static str,
+        field: String,
+        expected: PlaceholderKind,
+        found: PlaceholderKind,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,208 @@
+use crate::error::{Error, Result};
+
+/// Checks that `value` is a plain SQL identifier: a letter or underscore followed by
+/// letters, digits, underscores or dollar signs.
+pub fn is_valid_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Keywords PostgreSQL reserves, including those it allows as function or type names,
+/// which have to be quoted to be used as identifiers.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "binary",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "collation",
+    "column",
+    "concurrently",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_schema",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "ilike",
+    "in",
+    "initially",
+    "inner",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "lateral",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "notnull",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "overlaps",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "right",
+    "select",
+    "session_user",
+    "similar",
+    "some",
+    "symmetric",
+    "table",
+    "tablesample",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "variadic",
+    "verbose",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// Quotes `value` with double quotes when it would not be read back as-is unquoted:
+/// when it has special characters or is a reserved keyword. Uppercase letters are left
+/// unquoted, so the name folds to lowercase as when written in the SQL by hand; use
+/// [`quote_exact_identifier`] to keep them. Embedded double quotes are escaped by doubling them.
+///
+/// # Example
+/// ```ignore
+/// quote_identifier("users"); // users
+/// quote_identifier("MySchema"); // MySchema
+/// quote_identifier("user"); // "user"
+/// ```
+pub fn quote_identifier(value: &str) -> String {
+    if !is_valid_identifier(value) {
+        return quote(value);
+    }
+    // A reserved keyword is quoted as the unquoted name would fold, e.g. Left as "left".
+    let folded = value.to_ascii_lowercase();
+    if RESERVED_KEYWORDS.contains(&folded.as_str()) {
+        quote(&folded)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Quotes `value` as [`quote_identifier`] does, and also when it has uppercase letters so
+/// that the case-sensitive name is kept.
+///
+/// # Example
+/// ```ignore
+/// quote_exact_identifier("MySchema"); // "MySchema"
+/// ```
+pub fn quote_exact_identifier(value: &str) -> String {
+    if value.chars().any(|c| c.is_ascii_uppercase()) {
+        quote(value)
+    } else {
+        quote_identifier(value)
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Returns an [`Error::InvalidIdentifier`] when `value` is not a plain SQL identifier.
+pub fn validate_identifier(builder: &'static str, field: &str, value: &str) -> Result<()> {
+    if !is_valid_identifier(value) {
+        return Err(Error::InvalidIdentifier {
+            builder,
+            field: field.to_string(),
+            value: value.to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod test_identifier {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_identifier() {
+        assert!(is_valid_identifier("t"));
+        assert!(is_valid_identifier("_my_table1"));
+        assert!(is_valid_identifier("col$1"));
+        assert!(!is_valid_identifier(""));
+        assert!(!is_valid_identifier("1t"));
+        assert!(!is_valid_identifier("t.id"));
+        assert!(!is_valid_identifier("t; DROP TABLE users"));
+
+        assert_eq!(quote_identifier("users"), "users");
+        assert_eq!(quote_identifier("MySchema"), "MySchema");
+        assert_eq!(quote_identifier("user"), "\"user\"");
+        assert_eq!(quote_identifier("Left"), "\"left\"");
+        assert_eq!(quote_identifier("tablesample"), "\"tablesample\"");
+        assert_eq!(quote_exact_identifier("MySchema"), "\"MySchema\"");
+        assert_eq!(quote_exact_identifier("order"), "\"order\"");
+        assert_eq!(quote_identifier("order items"), "\"order items\"");
+        assert_eq!(quote_identifier("my\"table"), "\"my\"\"table\"");
+
+        let result = validate_identifier("ConditionBuilder", "table_alias", "t t");
+        assert_eq!(
+            result,
+            Err(Error::InvalidIdentifier {
+                builder: "ConditionBuilder",
+                field: "table_alias".to_string(),
+                value: "t t".to_string(),
+            })
+        );
+    }
+}
@@ -1,2 +1,22 @@
+extern crate self as rquery_builder;
+
+pub mod dialect;
+pub mod error;
+pub mod identifier;
+pub mod literal;
+#[cfg(feature = "mysql")]
+pub mod mysql;
 pub mod placeholder;
 pub mod postgres;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "surreal")]
+pub mod surreal;
+pub mod table;
+pub mod value;
+
+pub use error::{Error, Result};
+#[cfg(feature = "derive")]
+pub use rquery_builder_derive::Table;
+pub use table::Table;
+pub use value::{SqlType, SqlValue};
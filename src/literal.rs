@@ -0,0 +1,357 @@
+use crate::error::{Error, Result};
+use crate::placeholder::{
+    check_named, check_positional, is_in_list, split_cast, split_placeholders,
+};
+use serde_json::Value;
+
+/// Renders a value as a PostgreSQL literal:
+///
+/// - strings are single-quoted with their quotes doubled, and switched to an `E'...'`
+///   string when they hold backslashes;
+/// - numbers, booleans and `NULL` are written as is;
+/// - objects are cast to JSONB, `'{"a":1}'::jsonb`;
+/// - arrays become `ARRAY[...]`, or `'{}'` when empty.
+///
+/// # Example
+/// ```ignore
+/// quote_literal("builder", &json!("O'Brien")); // 'O''Brien'
+/// quote_literal("builder", &json!([1, 2])); // ARRAY[1, 2]
+/// ```
+pub fn quote_literal(builder: &'static str, value: &Value) -> Result<String> {
+    match value {
+        Value::Null => Ok("NULL".to_string()),
+        Value::Bool(true) => Ok("TRUE".to_string()),
+        Value::Bool(false) => Ok("FALSE".to_string()),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::String(text) => quote_text(builder, text),
+        Value::Object(_) => Ok(format!(
+            "{}::jsonb",
+            quote_text(builder, &value.to_string())?
+        )),
+        Value::Array(items) if items.is_empty() => Ok("'{}'".to_string()),
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(|item| quote_literal(builder, item))
+                .collect::<Result<Vec<String>>>()?;
+            Ok(format!("ARRAY[{}]", items.join(", ")))
+        }
+    }
+}
+
+fn quote_text(builder: &'static str, text: &str) -> Result<String> {
+    // PostgreSQL text can't hold NUL characters, whatever the escaping
+    if text.contains('\0') {
+        return Err(Error::UnsupportedClause {
+            builder,
+            field: "values".to_string(),
+            target: "PostgreSQL literals".to_string(),
+        });
+    }
+    let quoted = text.replace('\'', "''");
+    if quoted.contains('\\') {
+        Ok(format!("E'{}'", quoted.replace('\\', "\\\\")))
+    } else {
+        Ok(format!("'{quoted}'"))
+    }
+}
+
+/// Renders the literal of a placeholder followed by the `::type` cast `cast`. A JSON
+/// array or object cast to `jsonb` is written as its JSON text, `'["a"]'::jsonb`, and the
+/// array of an `IN (?)` list, `list`, as its items, `IN ('a', 'b')`.
+fn cast_literal(
+    builder: &'static str,
+    value: &Value,
+    cast: Option<&str>,
+    list: bool,
+) -> Result<String> {
+    match (value, cast) {
+        (Value::Array(items), None) if list => {
+            if items.is_empty() {
+                return Err(Error::EmptyField {
+                    builder,
+                    field: "values".to_string(),
+                });
+            }
+            let items = items
+                .iter()
+                .map(|item| quote_literal(builder, item))
+                .collect::<Result<Vec<String>>>()?;
+            Ok(items.join(", "))
+        }
+        (Value::Array(_) | Value::Object(_), Some("jsonb")) => Ok(format!(
+            "{}::jsonb",
+            quote_text(builder, &value.to_string())?
+        )),
+        (value, Some(cast)) => Ok(format!("{}::{cast}", quote_literal(builder, value)?)),
+        (value, None) => quote_literal(builder, value),
+    }
+}
+
+/// Replaces the `?` placeholders of a statement with its values rendered as literals,
+/// for statements that can't take bind parameters, such as DDL. The placeholders keep
+/// their casts, so the JSON values of the JSONB operators, bound as `?::jsonb`, become
+/// `'[...]'::jsonb`, and the arrays of the `IN (?)` lists are written as their items.
+/// Named parameters are refused as they have no value here.
+///
+/// # Example
+/// ```ignore
+/// inline_values("builder", "WHERE status IN (?) AND tags @> ?::jsonb", vec![json!(["a", "b"]), json!(["x"])]);
+/// // WHERE status IN ('a', 'b') AND tags @> '["x"]'::jsonb
+/// ```
+pub fn inline_values(builder: &'static str, statement: &str, values: Vec<Value>) -> Result<String> {
+    let (parts, placeholders) = split_placeholders(statement, true);
+    check_named(builder, &placeholders)?;
+    check_positional(builder, &placeholders, values.len())?;
+    let mut output = parts[0].to_owned();
+    for (value, part) in values.iter().zip(&parts[1..]) {
+        let (cast, rest) = split_cast(part);
+        let list = is_in_list(&output, rest);
+        output.push_str(&cast_literal(builder, value, cast, list)?);
+        output.push_str(rest);
+    }
+    Ok(output)
+}
+
+/// Renders a statement with its placeholders, `?` or `$N`, replaced by the values as
+/// escaped literals, see [`quote_literal`]. Meant for logs, `EXPLAIN` copy-paste and
+/// DDL.
+///
+/// **Not for execution with untrusted input.** The escaping follows PostgreSQL's rules
+/// with `standard_conforming_strings` on, but bind parameters are the only safe way to
+/// send user input to the database.
+///
+/// # Example
+/// ```ignore
+/// interpolate("builder", "SELECT * FROM orders as o WHERE o.id = $1", &[json!(42)]);
+/// // SELECT * FROM orders as o WHERE o.id = 42
+/// ```
+pub fn interpolate(builder: &'static str, statement: &str, values: &[Value]) -> Result<String> {
+    let mismatch = |found: usize| Error::CountMismatch {
+        builder,
+        field: "values".to_string(),
+        expected: found,
+        found: values.len(),
+    };
+    let mut positional: usize = 0;
+    let mut highest: usize = 0;
+    let mut quote: Option<char> = None;
+    let mut output = String::new();
+    let mut rest = statement;
+    while let Some(c) = rest.chars().next() {
+        let next = &rest[c.len_utf8()..];
+        if let Some(value) = quote {
+            if c == value {
+                quote = None;
+            }
+            output.push(c);
+            rest = next;
+            continue;
+        }
+        match c {
+            '\'' | '"' => {
+                quote = Some(c);
+                output.push(c);
+                rest = next;
+            }
+            '?' if next.starts_with('?') => {
+                // An escaped `?`, such as the one of the JSONB `?|` operator.
+                output.push(c);
+                rest = &next[1..];
+            }
+            '?' => {
+                positional += 1;
+                let value = values
+                    .get(positional - 1)
+                    .ok_or_else(|| mismatch(positional))?;
+                let (cast, after) = split_cast(next);
+                let list = is_in_list(&output, after);
+                output.push_str(&cast_literal(builder, value, cast, list)?);
+                rest = after;
+            }
+            '$' if next.starts_with(|c: char| c.is_ascii_digit()) => {
+                let digits = next
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(next.len());
+                let position: usize = next[..digits].parse().unwrap_or(0);
+                let value = position
+                    .checked_sub(1)
+                    .and_then(|position| values.get(position))
+                    .ok_or_else(|| mismatch(position))?;
+                let (cast, after) = split_cast(&next[digits..]);
+                let list = is_in_list(&output, after);
+                output.push_str(&cast_literal(builder, value, cast, list)?);
+                highest = highest.max(position);
+                rest = after;
+            }
+            '$' => {
+                // A `$tag$ ... $tag$` dollar-quoted string is copied up to its closing tag.
+                let name = next
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(next.len());
+                if !next[name..].starts_with('$') {
+                    output.push(c);
+                    rest = next;
+                    continue;
+                }
+                let tag = &rest[..name + 2];
+                let close = rest[tag.len()..]
+                    .find(tag)
+                    .map(|start| 2 * tag.len() + start)
+                    .unwrap_or(rest.len());
+                output.push_str(&rest[..close]);
+                rest = &rest[close..];
+            }
+            _ => {
+                output.push(c);
+                rest = next;
+            }
+        }
+    }
+    let found = positional.max(highest);
+    if found != values.len() {
+        return Err(mismatch(found));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+pub mod test_literal {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_quote_literal() {
+        assert_eq!(quote_literal("test", &json!(null)), Ok("NULL".to_string()));
+        assert_eq!(quote_literal("test", &json!(true)), Ok("TRUE".to_string()));
+        assert_eq!(quote_literal("test", &json!(-1.5)), Ok("-1.5".to_string()));
+        assert_eq!(
+            quote_literal("test", &json!("O'Brien")),
+            Ok("'O''Brien'".to_string())
+        );
+        assert_eq!(
+            quote_literal("test", &json!("C:\\tmp\\'x'")),
+            Ok("E'C:\\\\tmp\\\\''x'''".to_string())
+        );
+        assert_eq!(
+            quote_literal("test", &json!({"name": "O'Brien", "path": "a\\b"})),
+            Ok(r#"E'{"name":"O''Brien","path":"a\\\\b"}'::jsonb"#.to_string())
+        );
+        assert_eq!(
+            quote_literal("test", &json!([["a", "b'c"], []])),
+            Ok("ARRAY[ARRAY['a', 'b''c'], '{}']".to_string())
+        );
+        assert!(quote_literal("test", &json!("a\0b")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_inline_values() {
+        let result = inline_values(
+            "test",
+            "WHERE note = '?' AND status IN (?) AND age > ? AND name = ?",
+            vec![
+                json!(["a", "b"]),
+                json!(18),
+                json!("x'; DROP TABLE users; --"),
+            ],
+        );
+        assert_eq!(
+            result,
+            Ok("WHERE note = '?' AND status IN ('a', 'b') AND age > 18 AND name = 'x''; DROP TABLE users; --'".to_string())
+        );
+
+        let result = inline_values(
+            "test",
+            "WHERE tags @> ?::jsonb AND data ??| ?::text[] AND ids = ANY(?)",
+            vec![json!(["a", "b"]), json!(["c"]), json!([1, 2])],
+        );
+        assert_eq!(
+            result,
+            Ok(r#"WHERE tags @> '["a","b"]'::jsonb AND data ?| ARRAY['c']::text[] AND ids = ANY(ARRAY[1, 2])"#.to_string())
+        );
+
+        let result = inline_values("test", "WHERE email = :email", vec![]);
+        assert_eq!(
+            result,
+            Err(Error::MissingParameter {
+                builder: "test",
+                field: "email".to_string(),
+            })
+        );
+
+        let result = inline_values("test", "WHERE age > ?", vec![]);
+        assert!(result.is_err(), "expecting a missing value to be rejected");
+
+        let result = inline_values(
+            "test",
+            "WHERE data ??| ? AND data ?? ?",
+            vec![json!(["a"]), json!("b")],
+        );
+        assert_eq!(
+            result,
+            Ok("WHERE data ?| ARRAY['a'] AND data ? 'b'".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_interpolate() {
+        let result = interpolate(
+            "test",
+            "SELECT * FROM orders as o WHERE o.id = $1 AND o.note != '$1?' AND o.data @> $2 AND o.tags && $3 AND (o.owner = $4 OR o.editor = $4) AND o.closed = $5 AND o.deleted_at IS NOT DISTINCT FROM $6",
+            &[
+                json!(42),
+                json!({"kind": "it's"}),
+                json!(["a", "b"]),
+                json!("x'); DROP TABLE orders; --"),
+                json!(false),
+                json!(null),
+            ],
+        );
+        assert_eq!(
+            result,
+            Ok(r#"SELECT * FROM orders as o WHERE o.id = 42 AND o.note != '$1?' AND o.data @> '{"kind":"it''s"}'::jsonb AND o.tags && ARRAY['a', 'b'] AND (o.owner = 'x''); DROP TABLE orders; --' OR o.editor = 'x''); DROP TABLE orders; --') AND o.closed = FALSE AND o.deleted_at IS NOT DISTINCT FROM NULL"#.to_string())
+        );
+
+        let result = interpolate(
+            "test",
+            "SELECT $body$ $1 ? $body$, ? FROM t WHERE a = ?",
+            &[json!(r"\'"), json!(1.5)],
+        );
+        assert_eq!(
+            result,
+            Ok(r"SELECT $body$ $1 ? $body$, E'\\''' FROM t WHERE a = 1.5".to_string())
+        );
+
+        let result = interpolate(
+            "test",
+            "WHERE o.data @> $1::jsonb AND o.id IN ($2) AND o.tags ??| $3::text[]",
+            &[json!(["a"]), json!([1, 2]), json!(["b"])],
+        );
+        assert_eq!(
+            result,
+            Ok(r#"WHERE o.data @> '["a"]'::jsonb AND o.id IN (1, 2) AND o.tags ?| ARRAY['b']::text[]"#.to_string())
+        );
+
+        let result = interpolate("test", "WHERE o.id NOT IN ($1)", &[json!([])]);
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "test",
+                field: "values".to_string(),
+            })
+        );
+
+        let result = interpolate("test", "WHERE a = $1 AND b = $2", &[json!(1)]);
+        assert_eq!(
+            result,
+            Err(Error::CountMismatch {
+                builder: "test",
+                field: "values".to_string(),
+                expected: 2,
+                found: 1,
+            })
+        );
+        let result = interpolate("test", "WHERE a = ?", &[json!(1), json!(2)]);
+        assert!(result.is_err(), "expecting an unused value to be rejected");
+    }
+}
@@ -0,0 +1,241 @@
+use crate::dialect::{Dialect, MySql};
+use crate::error::{Error, Result};
+use crate::mysql::identifier::{quote_identifier, quote_table};
+use crate::postgres::{ExpressionBuilder, JoinBuilder, JoinKind, Operator, WhereBuilder};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct DeleteBuilder {
+    pub table: String,
+    table_alias: Option<String>,
+    targets: Vec<String>,
+    join_values: Vec<Value>,
+    filter_values: Vec<Value>,
+    join_statement: Option<String>,
+    filter_statement: Option<String>,
+    limit: Option<usize>,
+    operators: Vec<Operator>, // Used to check the operators MySQL supports
+    filter_operators: Vec<Operator>, // The operators of the filter, replaced with it
+}
+
+impl DeleteBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn table(&mut self, table: &str, table_alias: Option<&str>) -> Result<&mut Self> {
+        self.table = quote_table("DeleteBuilder", table, table_alias)?;
+        self.table_alias = table_alias.map(|value| value.to_string());
+        Ok(self)
+    }
+
+    /// Sets the aliases of the tables rows are deleted from in a multi-table delete.
+    /// Defaults to the alias of the main table when there are joins.
+    pub fn targets(&mut self, table_aliases: Vec<&str>) -> &mut Self {
+        self.targets = table_aliases.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    pub fn join(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<&mut Self> {
+        if kind == JoinKind::Full {
+            return Err(Error::UnsupportedClause {
+                builder: "DeleteBuilder",
+                field: "FULL JOIN".to_string(),
+                target: "MySQL".to_string(),
+            });
+        }
+        let table = quote_table("DeleteBuilder", table, None)?;
+        self.operators
+            .extend(values.iter().flat_map(|item| item.operators.to_owned()));
+        let mut item = JoinBuilder::build(kind, &table, &quote_identifier(table_alias), values)?;
+        self.join_values.append(&mut item.values);
+        self.join_statement = if let Some(statement) = &self.join_statement {
+            Some(format!("{} {}", statement, item.statement))
+        } else {
+            Some(item.statement)
+        };
+        Ok(self)
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.filter_operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    /// Limits the number of deleted rows. Only supported by single-table deletes.
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.limit = Some(value);
+        self
+    }
+
+    /// The operators of the statement, checked against MySQL.
+    fn operators(&self) -> Vec<Operator> {
+        [self.operators.as_slice(), self.filter_operators.as_slice()].concat()
+    }
+
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = Vec::new();
+        values.append(&mut self.join_values.to_owned());
+        values.append(&mut self.filter_values.to_owned());
+        values
+    }
+
+    pub fn build(&self) -> Result<String> {
+        MySql.check_operators("DeleteBuilder", &self.operators())?;
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "DeleteBuilder",
+                field: "table".to_string(),
+            });
+        }
+        let mut targets: Vec<String> = self.targets.iter().map(|v| quote_identifier(v)).collect();
+        if targets.is_empty()
+            && self.join_statement.is_some()
+            && let Some(alias) = &self.table_alias
+        {
+            targets.push(quote_identifier(alias));
+        }
+        let mut statement = if targets.is_empty() {
+            if self.join_statement.is_some() {
+                return Err(Error::EmptyField {
+                    builder: "DeleteBuilder",
+                    field: "targets".to_string(),
+                });
+            }
+            format!("DELETE FROM {}", self.table)
+        } else {
+            format!("DELETE {} FROM {}", targets.join(", "), self.table)
+        };
+        if let Some(stmt) = &self.join_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        if let Some(stmt) = &self.filter_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        if let Some(limit) = self.limit {
+            if !targets.is_empty() {
+                return Err(Error::UnsupportedClause {
+                    builder: "DeleteBuilder",
+                    field: "limit".to_string(),
+                    target: "MySQL multi-table DELETE".to_string(),
+                });
+            }
+            statement.push_str(&format!(" LIMIT {limit}"));
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_delete_builder {
+    use super::*;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Operator};
+
+    #[tokio::test]
+    async fn test_delete_builder() {
+        let mut builder = DeleteBuilder::new();
+        let result = builder
+            .table("users", None)
+            .unwrap()
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: None,
+                        field: "email".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(Value::String(
+                            "test1@example.com".to_string(),
+                        ))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .limit(1)
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "DELETE FROM `users` WHERE email = ? LIMIT 1"
+        );
+        assert_eq!(builder.get_values().len(), 1);
+
+        let mut builder = DeleteBuilder::new();
+        let result = builder.table("users", Some("u")).unwrap().limit(1).build();
+        assert_eq!(result, Ok("DELETE FROM `users` as `u` LIMIT 1".to_string()));
+
+        let result = builder.targets(vec!["u"]).build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "DeleteBuilder",
+                field: "limit".to_string(),
+                target: "MySQL multi-table DELETE".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_builder_multi_table() {
+        let join_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("o".to_string()),
+                field: "user_id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Field("u".to_string(), "id".to_string())),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("u".to_string()),
+                field: "email".to_string(),
+                operator: Operator::Like,
+                value: Some(ConditionValue::Single(Value::String(
+                    "%@spam.com".to_string(),
+                ))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = DeleteBuilder::new();
+        let result = builder
+            .table("users", Some("u"))
+            .unwrap()
+            .join(JoinKind::Inner, "orders", "o", vec![join_clause])
+            .unwrap()
+            .targets(vec!["u", "o"])
+            .filter(vec![filter_clause])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "DELETE `u`, `o` FROM `users` as `u` INNER JOIN `orders` as `o` ON o.user_id = u.id WHERE u.email LIKE ?"
+        );
+        assert_eq!(builder.get_values().len(), 1);
+
+        let result = builder.limit(1).build();
+        assert!(
+            result.is_err(),
+            "expecting LIMIT on a multi-table delete to be rejected"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::error::{Error, Result};
+
+/// Quotes `value` with backticks, escaping embedded backticks by doubling them.
+pub fn quote_identifier(value: &str) -> String {
+    format!("`{}`", value.replace('`', "``"))
+}
+
+/// Quotes each part of a dot-separated name, e.g. `shop.users` becomes `` `shop`.`users` ``.
+pub fn quote_qualified(value: &str) -> String {
+    value
+        .split('.')
+        .map(quote_identifier)
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// Quotes a table with its alias, e.g. `` `users` as `u` ``.
+pub fn quote_table(
+    builder: &'static str,
+    table: &str,
+    table_alias: Option<&str>,
+) -> Result<String> {
+    if table.is_empty() {
+        return Err(Error::EmptyField {
+            builder,
+            field: "table".to_string(),
+        });
+    }
+    if let Some(alias) = table_alias {
+        Ok(format!(
+            "{} as {}",
+            quote_qualified(table),
+            quote_identifier(alias)
+        ))
+    } else {
+        Ok(quote_qualified(table))
+    }
+}
+
+#[cfg(test)]
+pub mod test_identifier {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_quote_identifier() {
+        assert_eq!(quote_identifier("users"), "`users`");
+        assert_eq!(quote_identifier("my`table"), "`my``table`");
+        assert_eq!(quote_qualified("shop.users"), "`shop`.`users`");
+        assert_eq!(
+            quote_table("SelectBuilder", "shop.users", Some("u")).unwrap(),
+            "`shop`.`users` as `u`"
+        );
+        assert!(quote_table("SelectBuilder", "", None).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+use crate::error::{Error, Result};
+use crate::mysql::identifier::{quote_identifier, quote_table};
+use serde_json::Value;
+
+/// The update applied to a column when the inserted row hits a duplicate key.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DuplicateKeyUpdate {
+    Inserted(String), // `column` = VALUES(`column`), the value the row tried to insert
+    Value(String, Value), // `column` = ?
+    Raw(String, String), // `column` = <expression>, e.g. `count` + 1
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InsertBuilder {
+    pub table: String,
+    pub fields: Vec<String>,
+    pub values: Vec<Vec<Value>>,
+    pub ignore: bool,
+    pub on_duplicate_key_update: Vec<DuplicateKeyUpdate>,
+}
+
+impl InsertBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    /// Renders `INSERT IGNORE`, which skips rows that would violate a unique key.
+    pub fn ignore(&mut self) -> &mut Self {
+        self.ignore = true;
+        self
+    }
+
+    pub fn columns(&mut self, values: Vec<&str>) -> &mut Self {
+        self.fields = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    pub fn values(&mut self, values: Vec<Value>) -> Result<&mut Self> {
+        if self.fields.len() != values.len() {
+            return Err(Error::CountMismatch {
+                builder: "InsertBuilder",
+                field: "values".to_string(),
+                expected: self.fields.len(),
+                found: values.len(),
+            });
+        }
+        self.values.push(values);
+        Ok(self)
+    }
+
+    /// Renders `ON DUPLICATE KEY UPDATE ...`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// on_duplicate_key_update(vec![DuplicateKeyUpdate::Inserted("name".to_string())]);
+    /// ```
+    pub fn on_duplicate_key_update(&mut self, values: Vec<DuplicateKeyUpdate>) -> &mut Self {
+        self.on_duplicate_key_update = values;
+        self
+    }
+
+    /// Returns the row values followed by the values of `ON DUPLICATE KEY UPDATE`.
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = self.values.iter().flatten().cloned().collect();
+        for item in &self.on_duplicate_key_update {
+            if let DuplicateKeyUpdate::Value(_, value) = item {
+                values.push(value.to_owned());
+            }
+        }
+        values
+    }
+
+    fn duplicate_key_update_statement(&self) -> Option<String> {
+        if self.on_duplicate_key_update.is_empty() {
+            return None;
+        }
+        let values: Vec<String> = self
+            .on_duplicate_key_update
+            .iter()
+            .map(|item| match item {
+                DuplicateKeyUpdate::Inserted(field) => {
+                    let field = quote_identifier(field);
+                    format!("{field} = VALUES({field})")
+                }
+                DuplicateKeyUpdate::Value(field, _) => format!("{} = ?", quote_identifier(field)),
+                DuplicateKeyUpdate::Raw(field, expression) => {
+                    format!("{} = {expression}", quote_identifier(field))
+                }
+            })
+            .collect();
+        Some(format!("ON DUPLICATE KEY UPDATE {}", values.join(", ")))
+    }
+
+    pub fn build(&self) -> Result<String> {
+        if self.values.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "InsertBuilder",
+                field: "values".to_string(),
+            });
+        }
+        let table = quote_table("InsertBuilder", &self.table, None)?;
+        let fields: Vec<String> = self.fields.iter().map(|v| quote_identifier(v)).collect();
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|items| format!("({})", vec!["?"; items.len()].join(", ")))
+            .collect();
+        let mut statement = format!(
+            "INSERT {}INTO {}({}) VALUES {}",
+            if self.ignore { "IGNORE " } else { "" },
+            table,
+            fields.join(", "),
+            values.join(", ")
+        );
+        if let Some(value) = self.duplicate_key_update_statement() {
+            statement = format!("{statement} {value}");
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_insert_builder {
+    use super::*;
+    use serde_json::Number;
+
+    #[tokio::test]
+    async fn test_insert_builder() {
+        let mut builder = InsertBuilder::new();
+        let _ = builder.table("users").columns(vec!["name", "email"]);
+        let result = builder.values(vec![
+            Value::String("Juan dela Cruz".to_string()),
+            Value::String("jdc@test.com".to_string()),
+        ]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = builder.values(vec![
+            Value::String("Jose Rizal".to_string()),
+            Value::String("jr@test.com".to_string()),
+        ]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = builder.build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "INSERT INTO `users`(`name`, `email`) VALUES (?, ?), (?, ?)"
+        );
+        assert_eq!(builder.get_values().len(), 4);
+
+        let mut builder = InsertBuilder::new();
+        let _ = builder.table("users").columns(vec!["name"]).ignore();
+        let _ = builder.values(vec![Value::String("Jose Rizal".to_string())]);
+        let result = builder.build();
+        assert_eq!(
+            result.unwrap(),
+            "INSERT IGNORE INTO `users`(`name`) VALUES (?)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_on_duplicate_key_update() {
+        let mut builder = InsertBuilder::new();
+        let _ = builder
+            .table("page_views")
+            .columns(vec!["page", "title", "views"])
+            .on_duplicate_key_update(vec![
+                DuplicateKeyUpdate::Inserted("title".to_string()),
+                DuplicateKeyUpdate::Raw("views".to_string(), "`views` + 1".to_string()),
+                DuplicateKeyUpdate::Value(
+                    "updated_by".to_string(),
+                    Value::String("importer".to_string()),
+                ),
+            ]);
+        let result = builder.values(vec![
+            Value::String("/home".to_string()),
+            Value::String("Home".to_string()),
+            Value::Number(Number::from(1)),
+        ]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = builder.build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "INSERT INTO `page_views`(`page`, `title`, `views`) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE `title` = VALUES(`title`), `views` = `views` + 1, `updated_by` = ?"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::String("/home".to_string()),
+                Value::String("Home".to_string()),
+                Value::Number(Number::from(1)),
+                Value::String("importer".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_errors() {
+        let mut builder = InsertBuilder::new();
+        let _ = builder.table("users").columns(vec!["name", "email"]);
+        let result = builder.values(vec![Value::Null]);
+        assert!(result.is_err(), "expecting mismatched values error");
+        assert!(builder.build().is_err(), "expecting empty values error");
+    }
+}
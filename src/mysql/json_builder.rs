@@ -0,0 +1,138 @@
+use crate::error::{Error, Result};
+use crate::mysql::identifier::{quote_identifier, quote_qualified};
+use crate::postgres::{ExpressionBuilder, Logic};
+use serde_json::Value;
+
+/// Helpers for MySQL JSON columns.
+///
+/// The extracted values can be used as a column with `SelectBuilder::columns_raw`, or as
+/// the `field` of a `ConditionBuilder` (without a `table_alias`) to filter on a JSON path.
+#[derive(Debug, Clone)]
+pub struct JsonBuilder;
+
+impl JsonBuilder {
+    /// Checks that the JSON path only has characters allowed in a path expression, since
+    /// the path is inlined in the statement.
+    fn path(path: &str) -> Result<String> {
+        let is_valid = path.starts_with('$')
+            && path
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "$._[]*".contains(c));
+        if !is_valid {
+            return Err(Error::InvalidIdentifier {
+                builder: "JsonBuilder",
+                field: "path".to_string(),
+                value: path.to_string(),
+            });
+        }
+        Ok(format!("'{path}'"))
+    }
+
+    fn column(table_alias: Option<&str>, field: &str) -> Result<String> {
+        if field.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "JsonBuilder",
+                field: "field".to_string(),
+            });
+        }
+        if let Some(alias) = table_alias {
+            Ok(format!(
+                "{}.{}",
+                quote_identifier(alias),
+                quote_qualified(field)
+            ))
+        } else {
+            Ok(quote_qualified(field))
+        }
+    }
+
+    /// Renders `` `t`.`field`->'$.path' ``, which returns a JSON value.
+    pub fn extract(table_alias: Option<&str>, field: &str, path: &str) -> Result<String> {
+        Ok(format!(
+            "{}->{}",
+            Self::column(table_alias, field)?,
+            Self::path(path)?
+        ))
+    }
+
+    /// Renders `` `t`.`field`->>'$.path' ``, which returns the unquoted text value.
+    pub fn extract_text(table_alias: Option<&str>, field: &str, path: &str) -> Result<String> {
+        Ok(format!(
+            "{}->>{}",
+            Self::column(table_alias, field)?,
+            Self::path(path)?
+        ))
+    }
+
+    /// Builds a `JSON_CONTAINS(target, ?[, path])` expression. The candidate is bound as
+    /// JSON text.
+    ///
+    /// # Example
+    /// ```ignore
+    /// JsonBuilder::contains(Some("u"), "tags", &json!(["admin"]), None, None);
+    /// ```
+    pub fn contains(
+        table_alias: Option<&str>,
+        field: &str,
+        value: &Value,
+        path: Option<&str>,
+        logic: Option<Logic>,
+    ) -> Result<ExpressionBuilder> {
+        let column = Self::column(table_alias, field)?;
+        let condition = if let Some(path) = path {
+            format!("JSON_CONTAINS({column}, ?, {})", Self::path(path)?)
+        } else {
+            format!("JSON_CONTAINS({column}, ?)")
+        };
+        Ok(ExpressionBuilder {
+            condition,
+            logic,
+            values: vec![Value::String(value.to_string())],
+            operators: vec![],
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod test_json_builder {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_json_builder() {
+        let result = JsonBuilder::extract(Some("u"), "profile", "$.address.city");
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "`u`.`profile`->'$.address.city'");
+
+        let result = JsonBuilder::extract_text(None, "profile", "$.tags[0]");
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "`profile`->>'$.tags[0]'");
+
+        let result = JsonBuilder::extract(Some("u"), "profile", "$.name' OR 1=1 --");
+        assert!(result.is_err(), "expecting invalid path error");
+
+        let result = JsonBuilder::contains(Some("u"), "tags", &json!(["admin"]), None, None);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(result.condition, "JSON_CONTAINS(`u`.`tags`, ?)");
+        assert_eq!(
+            result.values,
+            vec![Value::String("[\"admin\"]".to_string())]
+        );
+
+        let result = JsonBuilder::contains(
+            Some("u"),
+            "profile",
+            &json!("manila"),
+            Some("$.city"),
+            Some(Logic::And),
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(
+            result.condition,
+            "JSON_CONTAINS(`u`.`profile`, ?, '$.city')"
+        );
+        assert_eq!(result.logic, Some(Logic::And));
+    }
+}
@@ -0,0 +1,12 @@
+pub mod delete_builder;
+pub mod identifier;
+pub mod insert_builder;
+pub mod json_builder;
+pub mod select_builder;
+pub mod update_builder;
+
+pub use delete_builder::DeleteBuilder;
+pub use insert_builder::{DuplicateKeyUpdate, InsertBuilder};
+pub use json_builder::JsonBuilder;
+pub use select_builder::{IndexHint, SelectBuilder};
+pub use update_builder::UpdateBuilder;
@@ -0,0 +1,477 @@
+use crate::dialect::{Dialect, MySql};
+use crate::error::{Error, Result};
+use crate::mysql::identifier::{quote_identifier, quote_table};
+use crate::postgres::{
+    ExpressionBuilder, GroupByBuilder, GroupByItem, JoinBuilder, JoinKind, Operator,
+    OrderByBuilder, OrderByItem, Sequence, WhereBuilder,
+};
+use serde_json::Value;
+
+/// Index hints placed after the table name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexHint {
+    Use(Vec<String>),    // USE INDEX (...)
+    Force(Vec<String>),  // FORCE INDEX (...)
+    Ignore(Vec<String>), // IGNORE INDEX (...)
+}
+
+impl IndexHint {
+    fn build(&self) -> Result<String> {
+        let (hint, indexes) = match self {
+            Self::Use(indexes) => ("USE", indexes),
+            Self::Force(indexes) => ("FORCE", indexes),
+            Self::Ignore(indexes) => ("IGNORE", indexes),
+        };
+        if indexes.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "index_hint".to_string(),
+            });
+        }
+        let indexes: Vec<String> = indexes.iter().map(|v| quote_identifier(v)).collect();
+        Ok(format!("{hint} INDEX ({})", indexes.join(", ")))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SelectBuilder {
+    pub distinct: bool,
+    pub table: String,
+    fields: Vec<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    index_hints: Vec<String>,
+    join_values: Vec<Value>,
+    filter_values: Vec<Value>,
+    order_by_values: Vec<Value>,
+    filter_statement: Option<String>,
+    join_statement: Option<String>,
+    group_by_statement: Option<String>,
+    order_by_statement: Option<String>,
+    operators: Vec<Operator>, // Used to check the operators MySQL supports
+    filter_operators: Vec<Operator>, // The operators of the filter, replaced with it
+}
+
+impl SelectBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn distinct(&mut self) -> &mut Self {
+        self.distinct = true;
+        self
+    }
+
+    pub fn table(&mut self, table: &str, table_alias: &str) -> Result<&mut Self> {
+        self.table = quote_table("SelectBuilder", table, Some(table_alias))?;
+        Ok(self)
+    }
+
+    /// Adds an index hint for the table, e.g. `USE INDEX (idx_email)`.
+    pub fn index_hint(&mut self, hint: IndexHint) -> Result<&mut Self> {
+        self.index_hints.push(hint.build()?);
+        Ok(self)
+    }
+
+    fn append_join(&mut self, mut item: JoinBuilder) -> &mut Self {
+        self.join_values.append(&mut item.values);
+        self.join_statement = if let Some(statement) = &self.join_statement {
+            Some(format!("{} {}", statement, item.statement))
+        } else {
+            Some(item.statement)
+        };
+        self
+    }
+
+    fn join_kind(kind: &JoinKind) -> Result<()> {
+        if kind == &JoinKind::Full {
+            return Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "FULL JOIN".to_string(),
+                target: "MySQL".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn join(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<&mut Self> {
+        Self::join_kind(&kind)?;
+        let table = quote_table("SelectBuilder", table, None)?;
+        self.operators
+            .extend(values.iter().flat_map(|item| item.operators.to_owned()));
+        let item = JoinBuilder::build(kind, &table, &quote_identifier(table_alias), values)?;
+        Ok(self.append_join(item))
+    }
+
+    pub fn join_using(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        columns: Vec<&str>,
+    ) -> Result<&mut Self> {
+        Self::join_kind(&kind)?;
+        let table = quote_table("SelectBuilder", table, None)?;
+        let item = JoinBuilder::build_using(kind, &table, &quote_identifier(table_alias), columns)?;
+        Ok(self.append_join(item))
+    }
+
+    pub fn cross_join(&mut self, table: &str, table_alias: &str) -> Result<&mut Self> {
+        let table = quote_table("SelectBuilder", table, None)?;
+        let item = JoinBuilder::build(
+            JoinKind::Cross,
+            &table,
+            &quote_identifier(table_alias),
+            vec![],
+        )?;
+        Ok(self.append_join(item))
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.filter_operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    /// Defines columns for a query by combining a table alias with column names, both
+    /// quoted with backticks. If no columns are specified, it selects all columns.
+    ///
+    /// # Example
+    /// ```ignore
+    /// columns("u", vec!["id", "name"]); // `u`.`id`, `u`.`name`
+    /// ```
+    pub fn columns(&mut self, table_alias: &str, values: Vec<&str>) -> &mut Self {
+        let table_alias = quote_identifier(table_alias);
+        let mut fields = if values.is_empty() {
+            vec![format!("{table_alias}.*")]
+        } else {
+            values
+                .iter()
+                .map(|value| format!("{table_alias}.{}", quote_identifier(value)))
+                .collect()
+        };
+        self.fields.append(&mut fields);
+        self
+    }
+
+    /// Allows users to define columns with custom expressions or functions, such as the
+    /// JSON paths rendered by `JsonBuilder`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// columns_raw(vec!["CONCAT(first_name, ' ', last_name) as full_name"]);
+    /// ```
+    pub fn columns_raw(&mut self, values: Vec<&str>) -> &mut Self {
+        let mut fields = if values.is_empty() {
+            vec!["*".to_string()]
+        } else {
+            values.iter().map(|value| value.to_string()).collect()
+        };
+        self.fields.append(&mut fields);
+        self
+    }
+
+    /// MySQL has no NULLS FIRST/LAST or USING ordering, and collations are not
+    /// double-quoted, so those items are rejected.
+    pub fn order_by(&mut self, values: Vec<OrderByItem>) -> Result<&mut Self> {
+        for item in &values {
+            let field = if item.nulls.is_some() {
+                Some("nulls")
+            } else if item.collate.is_some() {
+                Some("collate")
+            } else if matches!(item.sequence, Sequence::Using(_)) {
+                Some("using")
+            } else {
+                None
+            };
+            if let Some(field) = field {
+                return Err(Error::UnsupportedClause {
+                    builder: "SelectBuilder",
+                    field: field.to_string(),
+                    target: "MySQL".to_string(),
+                });
+            }
+        }
+        if !values.is_empty() {
+            let result = OrderByBuilder::build_values(values)?;
+            self.order_by_statement = Some(result.statement);
+            self.order_by_values = result.values;
+        }
+        Ok(self)
+    }
+
+    pub fn group_by(&mut self, values: Vec<GroupByItem>) -> Result<&mut Self> {
+        if !values.is_empty() {
+            self.group_by_statement = Some(GroupByBuilder::build(values)?);
+        }
+        Ok(self)
+    }
+
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.limit = Some(value);
+        self
+    }
+
+    pub fn offset(&mut self, value: usize) -> &mut Self {
+        self.offset = Some(value);
+        self
+    }
+
+    /// The operators of the statement, checked against MySQL.
+    fn operators(&self) -> Vec<Operator> {
+        [self.operators.as_slice(), self.filter_operators.as_slice()].concat()
+    }
+
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = Vec::new();
+        values.append(&mut self.join_values.to_owned());
+        values.append(&mut self.filter_values.to_owned());
+        values.append(&mut self.order_by_values.to_owned());
+        values
+    }
+
+    /// Renders `LIMIT offset, count` as the [`MySql`] dialect does.
+    fn limit_statement(&self) -> Option<String> {
+        let limit = self.limit.map(|value| value.to_string());
+        let offset = self.offset.map(|value| value.to_string());
+        MySql.limit_offset(limit.as_deref(), offset.as_deref())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        MySql.check_operators("SelectBuilder", &self.operators())?;
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "table".to_string(),
+            });
+        }
+        let fields = self.fields.join(", ");
+        let mut statement: String = if self.distinct {
+            format!("SELECT DISTINCT {} FROM {}", fields, self.table)
+        } else {
+            format!("SELECT {} FROM {}", fields, self.table)
+        };
+        if !self.index_hints.is_empty() {
+            statement = format!("{statement} {}", self.index_hints.join(" "));
+        }
+        if let Some(value) = &self.join_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.filter_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.group_by_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.order_by_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = self.limit_statement() {
+            statement = format!("{statement} {value}");
+        }
+        Ok(statement.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+pub mod test_select_builder {
+    use super::*;
+    use crate::mysql::JsonBuilder;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Logic, Nulls, Operator, col};
+    use serde_json::{Number, json};
+
+    #[tokio::test]
+    async fn test_select_builder() {
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .table("users", "u")
+            .unwrap()
+            .columns("u", vec![])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "SELECT `u`.* FROM `users` as `u`");
+
+        let join_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("p".to_string()),
+                field: "id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Field(
+                    "o".to_string(),
+                    "product_id".to_string(),
+                )),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("o".to_string()),
+                field: "user_id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::Number(Number::from(1)))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .table("shop.orders", "o")
+            .unwrap()
+            .index_hint(IndexHint::Use(vec!["idx_user_id".to_string()]))
+            .unwrap()
+            .join(JoinKind::Left, "products", "p", vec![join_clause])
+            .unwrap()
+            .columns("o", vec!["id", "user_id"])
+            .columns("p", vec!["name"])
+            .filter(vec![filter_clause])
+            .order_by(vec![OrderByItem {
+                table_alias: Some("o".to_string()),
+                field: "id".to_string(),
+                sequence: Sequence::Desc,
+                ..Default::default()
+            }])
+            .unwrap()
+            .limit(10)
+            .offset(20)
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT `o`.`id`, `o`.`user_id`, `p`.`name` FROM `shop`.`orders` as `o` USE INDEX (`idx_user_id`) LEFT JOIN `products` as `p` ON p.id = o.product_id WHERE o.user_id = ? ORDER BY o.id DESC LIMIT 20, 10"
+        );
+        assert_eq!(builder.get_values().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_limit_offset() {
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .table("users", "u")
+            .unwrap()
+            .columns("u", vec!["id"])
+            .limit(5)
+            .build();
+        assert_eq!(
+            result.unwrap(),
+            "SELECT `u`.`id` FROM `users` as `u` LIMIT 5"
+        );
+
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .table("users", "u")
+            .unwrap()
+            .columns("u", vec!["id"])
+            .offset(5)
+            .build();
+        assert_eq!(
+            result.unwrap(),
+            "SELECT `u`.`id` FROM `users` as `u` LIMIT 5, 18446744073709551615"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_json() {
+        let city = JsonBuilder::extract_text(Some("u"), "profile", "$.city").unwrap();
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: None,
+                field: city.to_owned(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::String("Manila".to_string()))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let tags =
+            JsonBuilder::contains(Some("u"), "tags", &json!("admin"), None, Some(Logic::And))
+                .unwrap();
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .table("users", "u")
+            .unwrap()
+            .columns("u", vec!["id"])
+            .columns_raw(vec![&format!("{city} as city")])
+            .filter(vec![filter_clause, tags])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT `u`.`id`, `u`.`profile`->>'$.city' as city FROM `users` as `u` WHERE (`u`.`profile`->>'$.city' = ?) AND (JSON_CONTAINS(`u`.`tags`, ?))"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::String("Manila".to_string()),
+                Value::String("\"admin\"".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_unsupported() {
+        let mut builder = SelectBuilder::new();
+        let result =
+            builder
+                .table("users", "u")
+                .unwrap()
+                .join(JoinKind::Full, "orders", "o", vec![]);
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "FULL JOIN".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+
+        let mut builder = SelectBuilder::new();
+        let result = builder.order_by(vec![OrderByItem {
+            field: "id".to_string(),
+            nulls: Some(Nulls::Last),
+            ..Default::default()
+        }]);
+        assert!(result.is_err(), "expecting NULLS LAST to be rejected");
+
+        let builder = SelectBuilder::new();
+        assert!(builder.build().is_err(), "expecting missing table error");
+        // The PostgreSQL-only operators are refused, as by `build_with(&MySql)`.
+        let mut builder = SelectBuilder::new();
+        builder
+            .table("users", "u")
+            .unwrap()
+            .columns("u", vec!["id"])
+            .filter(vec![col("u", "name").ilike("%bob%").build().unwrap()]);
+        assert_eq!(
+            builder.build(),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "ILIKE".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+
+        // Replacing the filter drops its operators.
+        builder.filter(vec![col("u", "name").like("%bob%").build().unwrap()]);
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT `u`.`id` FROM `users` as `u` WHERE u.name LIKE ?".to_string())
+        );
+    }
+}
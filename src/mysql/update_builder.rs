@@ -0,0 +1,265 @@
+use crate::dialect::{Dialect, MySql};
+use crate::error::{Error, Result};
+use crate::mysql::identifier::{quote_identifier, quote_qualified, quote_table};
+use crate::postgres::{
+    ExpressionBuilder, JoinBuilder, JoinKind, Operator, SetFieldUpdate, SetValue, WhereBuilder,
+};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct UpdateBuilder {
+    pub table: String,
+    join_values: Vec<Value>,
+    set_values: Vec<Value>,
+    filter_values: Vec<Value>,
+    set_statement: String,
+    join_statement: Option<String>,
+    filter_statement: Option<String>,
+    limit: Option<usize>,
+    operators: Vec<Operator>, // Used to check the operators MySQL supports
+    filter_operators: Vec<Operator>, // The operators of the filter, replaced with it
+}
+
+impl UpdateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn table(&mut self, table: &str, table_alias: Option<&str>) -> Result<&mut Self> {
+        self.table = quote_table("UpdateBuilder", table, table_alias)?;
+        Ok(self)
+    }
+
+    /// Joins another table for a multi-table update, e.g.
+    /// ``UPDATE `users` as `u` INNER JOIN `orders` as `o` ON ... SET ...``.
+    pub fn join(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<&mut Self> {
+        if kind == JoinKind::Full {
+            return Err(Error::UnsupportedClause {
+                builder: "UpdateBuilder",
+                field: "FULL JOIN".to_string(),
+                target: "MySQL".to_string(),
+            });
+        }
+        let table = quote_table("UpdateBuilder", table, None)?;
+        self.operators
+            .extend(values.iter().flat_map(|item| item.operators.to_owned()));
+        let mut item = JoinBuilder::build(kind, &table, &quote_identifier(table_alias), values)?;
+        self.join_values.append(&mut item.values);
+        self.join_statement = if let Some(statement) = &self.join_statement {
+            Some(format!("{} {}", statement, item.statement))
+        } else {
+            Some(item.statement)
+        };
+        Ok(self)
+    }
+
+    /// Sets the columns to update. Fields may be qualified with a table alias, e.g. `u.name`.
+    /// Subqueries are not supported.
+    pub fn set(&mut self, values: Vec<SetFieldUpdate>) -> Result<&mut Self> {
+        if !self.set_statement.is_empty() {
+            return Err(Error::DuplicateClause {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        if values.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        let mut expressions: Vec<String> = Vec::new();
+        for item in values {
+            match item.value {
+                SetValue::Value(value) => {
+                    expressions.push(format!("{} = ?", quote_qualified(&item.field)));
+                    self.set_values.push(value);
+                }
+                // Casts are PostgreSQL syntax, the value is bound as is
+                SetValue::Typed(value) => {
+                    expressions.push(format!("{} = ?", quote_qualified(&item.field)));
+                    self.set_values.push(value.to_json()?);
+                }
+                SetValue::Query(_) => {
+                    return Err(Error::UnsupportedClause {
+                        builder: "UpdateBuilder",
+                        field: item.field,
+                        target: "MySQL".to_string(),
+                    });
+                }
+            }
+        }
+        self.set_statement = format!("SET {}", expressions.join(", "));
+        Ok(self)
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.filter_operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    /// Limits the number of updated rows. Only supported by single-table updates.
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.limit = Some(value);
+        self
+    }
+
+    /// The operators of the statement, checked against MySQL.
+    fn operators(&self) -> Vec<Operator> {
+        [self.operators.as_slice(), self.filter_operators.as_slice()].concat()
+    }
+
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = Vec::new();
+        values.append(&mut self.join_values.to_owned());
+        values.append(&mut self.set_values.to_owned());
+        values.append(&mut self.filter_values.to_owned());
+        values
+    }
+
+    pub fn build(&self) -> Result<String> {
+        MySql.check_operators("UpdateBuilder", &self.operators())?;
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.set_statement.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        let mut statement = format!("UPDATE {}", self.table);
+        if let Some(stmt) = &self.join_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        statement.push_str(&format!(" {}", self.set_statement));
+        if let Some(stmt) = &self.filter_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        if let Some(limit) = self.limit {
+            if self.join_statement.is_some() {
+                return Err(Error::UnsupportedClause {
+                    builder: "UpdateBuilder",
+                    field: "limit".to_string(),
+                    target: "MySQL multi-table UPDATE".to_string(),
+                });
+            }
+            statement.push_str(&format!(" LIMIT {limit}"));
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_update_builder {
+    use super::*;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Logic, Operator};
+    use serde_json::Number;
+
+    #[tokio::test]
+    async fn test_update_builder() {
+        let mut builder = UpdateBuilder::new();
+        let result = builder
+            .table("users", None)
+            .unwrap()
+            .set(vec![SetFieldUpdate {
+                field: "name".to_string(),
+                value: SetValue::Value(Value::String("Test Update 1".to_string())),
+            }])
+            .unwrap()
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: None,
+                        field: "email".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(Value::String(
+                            "test1@example.com".to_string(),
+                        ))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .limit(1)
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "UPDATE `users` SET `name` = ? WHERE email = ? LIMIT 1"
+        );
+        assert_eq!(builder.get_values().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_builder_multi_table() {
+        let join_clause = ExpressionBuilder::build(
+            vec![
+                ConditionBuilder {
+                    table_alias: Some("o".to_string()),
+                    field: "user_id".to_string(),
+                    operator: Operator::Eq,
+                    value: Some(ConditionValue::Field("u".to_string(), "id".to_string())),
+                    logic: None,
+                },
+                ConditionBuilder {
+                    table_alias: Some("o".to_string()),
+                    field: "quantity".to_string(),
+                    operator: Operator::Gt,
+                    value: Some(ConditionValue::Single(Value::Number(Number::from(5)))),
+                    logic: Some(Logic::And),
+                },
+            ],
+            None,
+        )
+        .unwrap();
+        let mut builder = UpdateBuilder::new();
+        let result = builder
+            .table("users", Some("u"))
+            .unwrap()
+            .join(JoinKind::Inner, "orders", "o", vec![join_clause])
+            .unwrap()
+            .set(vec![SetFieldUpdate {
+                field: "u.name".to_string(),
+                value: SetValue::Value(Value::String("Bulk Buyer".to_string())),
+            }])
+            .unwrap()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "UPDATE `users` as `u` INNER JOIN `orders` as `o` ON o.user_id = u.id AND o.quantity > ? SET `u`.`name` = ?"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::Number(Number::from(5)),
+                Value::String("Bulk Buyer".to_string()),
+            ]
+        );
+
+        let result = builder.limit(1).build();
+        assert!(
+            result.is_err(),
+            "expecting LIMIT on a multi-table update to be rejected"
+        );
+    }
+}
@@ -1,15 +1,625 @@
+use crate::dialect::Dialect;
+use crate::error::{Error, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+
 /// Represents the placeholder style used in value bindings for generated SQL.
 ///
 /// - `QuestionMark` produces `?` placeholders (used by SQLite, MySQL, etc.).
 /// - `DollarSequential` produces `$1`, `$2`, ... placeholders (used by PostgreSQL).
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum PlaceholderKind {
-    QuestionMark,     // Using the ? symbol as placeholder for values.
+    #[default]
+    QuestionMark, // Using the ? symbol as placeholder for values.
     DollarSequential, //Using $1, $2, $3... as placeholder for values.
 }
 
-impl Default for PlaceholderKind {
-    fn default() -> Self {
-        Self::QuestionMark
+impl PlaceholderKind {
+    /// Renders the n-th (starting at 1) placeholder.
+    pub fn placeholder(&self, index: usize) -> String {
+        match self {
+            Self::QuestionMark => "?".to_string(),
+            Self::DollarSequential => format!("${index}"),
+        }
+    }
+}
+
+/// Values of named parameters, keyed by name without the `:`, `@` or `$` prefix.
+pub type Params = HashMap<String, Value>;
+
+/// A placeholder found by [`split_placeholders`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Placeholder {
+    Positional,
+    Named { name: String, text: String }, // the name and the text it was read from, `:name`
+}
+
+/// Reads the name of a named parameter starting at `start`, the index after its prefix.
+fn parameter_name(chars: &[char], start: usize) -> Option<String> {
+    match chars.get(start) {
+        Some(c) if c.is_ascii_alphabetic() || *c == '_' => {}
+        _ => return None,
+    }
+    let name: String = chars[start..]
+        .iter()
+        .take_while(|c| c.is_ascii_alphanumeric() || **c == '_')
+        .collect();
+    Some(name)
+}
+
+/// Splits a statement at its placeholders: the positional `?` and the named parameters
+/// (`:name`, `@name` or `$name`). Returns the text around the placeholders, one more
+/// item than the placeholders.
+///
+/// Quoted text, `$tag$` dollar quotes and `::` casts are left untouched. A `??` is an
+/// escaped `?` that isn't a placeholder, such as the JSONB `?|` operator rendered as
+/// `??|` by [`ConditionBuilder`](crate::postgres::ConditionBuilder); it is written back
+/// as `?` when `unescape` is set.
+pub(crate) fn split_placeholders(
+    statement: &str,
+    unescape: bool,
+) -> (Vec<String>, Vec<Placeholder>) {
+    let chars: Vec<char> = statement.chars().collect();
+    let mut parts: Vec<String> = Vec::new();
+    let mut placeholders: Vec<Placeholder> = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut text = String::new();
+    let mut index: usize = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if let Some(value) = quote {
+            if c == value {
+                quote = None;
+            }
+            text.push(c);
+            index += 1;
+            continue;
+        }
+        if c == '\'' || c == '"' {
+            quote = Some(c);
+            text.push(c);
+            index += 1;
+            continue;
+        }
+        if c == '?' {
+            if chars.get(index + 1) == Some(&'?') {
+                text.push_str(if unescape { "?" } else { "??" });
+                index += 2;
+                continue;
+            }
+            parts.push(std::mem::take(&mut text));
+            placeholders.push(Placeholder::Positional);
+            index += 1;
+            continue;
+        }
+        // A prefix inside a word, such as the `$` of the `col$name` identifier, after the
+        // first `:` of a `::` cast, or an `@` ending an operator such as `@@` or `<@`
+        // doesn't start a parameter.
+        let previous = index.checked_sub(1).map(|previous| chars[previous]);
+        let is_prefix = !previous.is_some_and(is_identifier_char)
+            && match c {
+                ':' => previous != Some(':'),
+                '@' => !previous.is_some_and(is_operator_char),
+                '$' => true,
+                _ => false,
+            };
+        if !is_prefix {
+            text.push(c);
+            index += 1;
+            continue;
+        }
+        let tag = chars[index + 1..]
+            .iter()
+            .take_while(|c| is_identifier_char(**c))
+            .count();
+        let end = index + 1 + tag;
+        if c == '$' && chars.get(end) == Some(&'$') {
+            // A `$tag$ ... $tag$` dollar-quoted string, copied up to its closing tag.
+            let tag: Vec<char> = chars[index..=end].to_vec();
+            let close = (end + 1..chars.len())
+                .find(|start| chars[*start..].starts_with(&tag))
+                .map(|start| start + tag.len())
+                .unwrap_or(chars.len());
+            text.extend(&chars[index..close]);
+            index = close;
+            continue;
+        }
+        let Some(name) = parameter_name(&chars, index + 1) else {
+            text.push(c);
+            index += 1;
+            continue;
+        };
+        parts.push(std::mem::take(&mut text));
+        placeholders.push(Placeholder::Named {
+            name,
+            text: chars[index..end].iter().collect(),
+        });
+        index = end;
+    }
+    parts.push(text);
+    (parts, placeholders)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_operator_char(c: char) -> bool {
+    matches!(c, '@' | '<' | '>' | '#' | '-' | '|' | '&' | '?')
+}
+
+/// Checks that there is one positional value per `?` placeholder.
+pub(crate) fn check_positional(
+    builder: &'static str,
+    placeholders: &[Placeholder],
+    values: usize,
+) -> Result<()> {
+    let found = placeholders
+        .iter()
+        .filter(|placeholder| **placeholder == Placeholder::Positional)
+        .count();
+    if found != values {
+        return Err(Error::CountMismatch {
+            builder,
+            field: "values".to_string(),
+            expected: found,
+            found: values,
+        });
+    }
+    Ok(())
+}
+
+/// Replaces each `?` placeholder of the statement with `placeholder(n)`, starting at 1,
+/// dropping their `::type` casts unless `casts` is set. Escaped `??` are written back as
+/// `?` unless the placeholders are `?` too, and the named parameters are kept as is.
+pub(crate) fn render_placeholders(
+    statement: &str,
+    placeholder: &dyn Fn(usize) -> String,
+    casts: bool,
+) -> String {
+    let (parts, placeholders) = split_placeholders(statement, placeholder(1) != "?");
+    let mut output = parts[0].to_owned();
+    let mut counter: usize = 0;
+    for (item, part) in placeholders.iter().zip(&parts[1..]) {
+        let mut part = part.as_str();
+        match item {
+            Placeholder::Positional => {
+                counter += 1;
+                output.push_str(&placeholder(counter));
+                if !casts {
+                    part = split_cast(part).1;
+                }
+            }
+            Placeholder::Named { text, .. } => output.push_str(text),
+        }
+        output.push_str(part);
+    }
+    output
+}
+
+/// Expands the named parameters (`:name`, `@name` or `$name`) of a statement into
+/// positional placeholders, merging their values with the positional `?` values.
+///
+/// With `DollarSequential`, every occurrence of a name reuses the same `$N`, so the
+/// value is bound once. With `QuestionMark`, the value is repeated for each `?`.
+/// Quoted text, `::` casts and `$tag$` dollar quotes are left untouched.
+///
+/// # Example
+/// ```ignore
+/// let params = Params::from([("email".to_string(), json!("a@b.c"))]);
+/// bind_named("SelectBuilder", "... WHERE u.id = ? AND (u.email = :email OR u.alt = :email)",
+///     vec![json!(1)], &params, &PlaceholderKind::DollarSequential);
+/// // ("... WHERE u.id = $1 AND (u.email = $2 OR u.alt = $2)", [1, "a@b.c"])
+/// ```
+pub fn bind_named(
+    builder: &'static str,
+    statement: &str,
+    values: Vec<Value>,
+    params: &Params,
+    kind: &PlaceholderKind,
+) -> Result<(String, Vec<Value>)> {
+    let (parts, placeholders) =
+        split_placeholders(statement, kind != &PlaceholderKind::QuestionMark);
+    check_positional(builder, &placeholders, values.len())?;
+    let mut positional = values.into_iter();
+    let mut bound: Vec<Value> = Vec::new();
+    let mut indexes: HashMap<&str, usize> = HashMap::new();
+    let mut output = String::new();
+    for (part, item) in parts
+        .iter()
+        .zip(placeholders.iter().map(Some).chain([None]))
+    {
+        output.push_str(part);
+        match item {
+            Some(Placeholder::Positional) => {
+                bound.push(positional.next().unwrap_or_default());
+                output.push_str(&kind.placeholder(bound.len()));
+            }
+            Some(Placeholder::Named { name, .. }) => {
+                let Some(value) = params.get(name) else {
+                    return Err(Error::MissingParameter {
+                        builder,
+                        field: name.to_owned(),
+                    });
+                };
+                match (kind, indexes.get(name.as_str())) {
+                    (PlaceholderKind::DollarSequential, Some(position)) => {
+                        output.push_str(&kind.placeholder(*position));
+                    }
+                    _ => {
+                        bound.push(value.to_owned());
+                        output.push_str(&kind.placeholder(bound.len()));
+                        indexes.insert(name, bound.len());
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+    Ok((output, bound))
+}
+
+/// Refuses the named parameters of a statement, for the renderings that have no value
+/// for them.
+pub(crate) fn check_named(builder: &'static str, placeholders: &[Placeholder]) -> Result<()> {
+    for placeholder in placeholders {
+        if let Placeholder::Named { name, .. } = placeholder {
+            return Err(Error::MissingParameter {
+                builder,
+                field: name.to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Refuses the named parameters left in a rendered statement, for `build` and
+/// `build_with`, which bind positional values only.
+pub(crate) fn check_unnamed(builder: &'static str, statement: &str) -> Result<()> {
+    check_named(builder, &split_placeholders(statement, false).1)
+}
+
+/// Numbers the `?` placeholders of a statement with the placeholders of the dialect for
+/// the database drivers, expanding the arrays of the `IN (?)` lists rendered by
+/// [`ConditionBuilder`](crate::postgres::ConditionBuilder) into one placeholder per item.
+/// Arrays cast as `?::type[]` become PostgreSQL array literals, as JSONB has no cast to
+/// them, other arrays, such as the one of `unnest(?)`, are bound as is. Named parameters
+/// are refused as they have no value here.
+///
+/// # Example
+/// ```ignore
+/// number_placeholders("builder", "WHERE status IN (?) AND tags && ?::text[]", vec![json!(["a", "b"]), json!(["x"])], &Postgres);
+/// // ("WHERE status IN ($1, $2) AND tags && $3::text[]", ["a", "b", "{\"x\"}"])
+/// ```
+pub fn number_placeholders(
+    builder: &'static str,
+    statement: &str,
+    values: Vec<Value>,
+    dialect: &dyn Dialect,
+) -> Result<(String, Vec<Value>)> {
+    let (parts, placeholders) = split_placeholders(statement, dialect.placeholder(1) != "?");
+    check_named(builder, &placeholders)?;
+    check_positional(builder, &placeholders, values.len())?;
+    let mut bound: Vec<Value> = Vec::new();
+    let mut output = parts[0].to_owned();
+    for (value, part) in values.into_iter().zip(&parts[1..]) {
+        let (cast, rest) = split_cast(part);
+        let cast = cast.filter(|_| dialect.supports_casts());
+        match (value, cast) {
+            (Value::Array(items), None) if is_in_list(&output, rest) => {
+                if items.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder,
+                        field: "values".to_string(),
+                    });
+                }
+                let placeholders = items
+                    .into_iter()
+                    .map(|item| {
+                        bound.push(item);
+                        dialect.placeholder(bound.len())
+                    })
+                    .collect::<Vec<String>>();
+                output.push_str(&placeholders.join(", "));
+            }
+            (value, cast) => {
+                let value = match (value, cast) {
+                    (Value::Array(items), Some(cast)) if cast.ends_with("[]") => {
+                        Value::String(array_literal(&items))
+                    }
+                    (value, _) => value,
+                };
+                bound.push(value);
+                output.push_str(&dialect.placeholder(bound.len()));
+                if let Some(cast) = cast {
+                    output.push_str(&format!("::{cast}"));
+                }
+            }
+        }
+        output.push_str(rest);
+    }
+    Ok((output, bound))
+}
+
+/// Tells whether a placeholder is the only item of an `IN (?)` or `NOT IN (?)` list,
+/// from the text before and after it.
+pub(crate) fn is_in_list(before: &str, after: &str) -> bool {
+    let Some(before) = before.trim_end().strip_suffix('(') else {
+        return false;
+    };
+    let mut keyword = before.trim_end().chars().rev();
+    after.trim_start().starts_with(')')
+        && matches!(
+            (keyword.next(), keyword.next(), keyword.next()),
+            (Some('n' | 'N'), Some('i' | 'I'), Some(c)) if c.is_whitespace()
+        )
+}
+
+/// Splits the `::type` cast, e.g. `int4[]`, from the text following a placeholder.
+pub(crate) fn split_cast(text: &str) -> (Option<&str>, &str) {
+    let Some(rest) = text.strip_prefix("::") else {
+        return (None, text);
+    };
+    let mut end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+    while rest[end..].starts_with("[]") {
+        end += 2;
+    }
+    (Some(&rest[..end]), &rest[end..])
+}
+
+/// Renders a JSON array as a PostgreSQL array literal, e.g. `{"a","b\"c"}`.
+fn array_literal(items: &[Value]) -> String {
+    let items = items
+        .iter()
+        .map(|item| match item {
+            Value::Null => "NULL".to_string(),
+            Value::Array(items) => array_literal(items),
+            Value::String(text) => quote_element(text),
+            Value::Object(_) => quote_element(&item.to_string()),
+            _ => item.to_string(),
+        })
+        .collect::<Vec<String>>();
+    format!("{{{}}}", items.join(","))
+}
+
+fn quote_element(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+pub mod test_placeholder {
+    use super::*;
+    use crate::dialect::{Postgres, Sqlite};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_bind_named() {
+        let params = Params::from([
+            ("email".to_string(), json!("jdc@test.com")),
+            ("status".to_string(), json!("active")),
+        ]);
+        let statement = "SELECT * FROM users WHERE id = ? AND (email = :email OR backup_email = :email) AND status = @status";
+        let result = bind_named(
+            "SelectBuilder",
+            statement,
+            vec![json!(1)],
+            &params,
+            &PlaceholderKind::DollarSequential,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT * FROM users WHERE id = $1 AND (email = $2 OR backup_email = $2) AND status = $3".to_string(),
+                vec![json!(1), json!("jdc@test.com"), json!("active")]
+            ))
+        );
+
+        let result = bind_named(
+            "SelectBuilder",
+            statement,
+            vec![json!(1)],
+            &params,
+            &PlaceholderKind::QuestionMark,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT * FROM users WHERE id = ? AND (email = ? OR backup_email = ?) AND status = ?".to_string(),
+                vec![
+                    json!(1),
+                    json!("jdc@test.com"),
+                    json!("jdc@test.com"),
+                    json!("active")
+                ]
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_named_untouched() {
+        let params = Params::from([("name".to_string(), json!("x"))]);
+        let statement = "SELECT id::text, col$name, tags[1:name], ':name', \"@name\", $body$ $name $body$ FROM t WHERE name = $name";
+        let result = bind_named(
+            "SelectBuilder",
+            statement,
+            vec![],
+            &params,
+            &PlaceholderKind::DollarSequential,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT id::text, col$name, tags[1:name], ':name', \"@name\", $body$ $name $body$ FROM t WHERE name = $1"
+                    .to_string(),
+                vec![json!("x")]
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_named_operators() {
+        let params = Params::from([("q".to_string(), json!("cat"))]);
+        let statement =
+            "SELECT * FROM t WHERE doc @@to_tsquery(:q) AND tags@>'{a}' AND tags<@'{a,b}'";
+        let result = bind_named(
+            "SelectBuilder",
+            statement,
+            vec![],
+            &params,
+            &PlaceholderKind::DollarSequential,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT * FROM t WHERE doc @@to_tsquery($1) AND tags@>'{a}' AND tags<@'{a,b}'"
+                    .to_string(),
+                vec![json!("cat")]
+            ))
+        );
+        assert_eq!(
+            check_unnamed(
+                "SelectBuilder",
+                "SELECT * FROM t WHERE doc @@to_tsquery(?) AND tags@>? AND tags<@?"
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_named_escaped() {
+        let params = Params::from([("key".to_string(), json!("email"))]);
+        let statement = "SELECT * FROM users WHERE data ?? :key AND data ??| ? AND note = '??'";
+        let result = bind_named(
+            "SelectBuilder",
+            statement,
+            vec![json!(["a"])],
+            &params,
+            &PlaceholderKind::DollarSequential,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT * FROM users WHERE data ? $1 AND data ?| $2 AND note = '??'".to_string(),
+                vec![json!("email"), json!(["a"])]
+            ))
+        );
+        let result = bind_named(
+            "SelectBuilder",
+            statement,
+            vec![json!(["a"])],
+            &params,
+            &PlaceholderKind::QuestionMark,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT * FROM users WHERE data ?? ? AND data ??| ? AND note = '??'".to_string(),
+                vec![json!("email"), json!(["a"])]
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bind_named_errors() {
+        let result = bind_named(
+            "SelectBuilder",
+            "SELECT * FROM users WHERE email = :email",
+            vec![],
+            &Params::new(),
+            &PlaceholderKind::DollarSequential,
+        );
+        assert_eq!(
+            result,
+            Err(Error::MissingParameter {
+                builder: "SelectBuilder",
+                field: "email".to_string(),
+            })
+        );
+        let result = bind_named(
+            "SelectBuilder",
+            "SELECT * FROM users WHERE id = ? AND name = ?",
+            vec![json!(1)],
+            &Params::new(),
+            &PlaceholderKind::DollarSequential,
+        );
+        assert_eq!(
+            result,
+            Err(Error::CountMismatch {
+                builder: "SelectBuilder",
+                field: "values".to_string(),
+                expected: 2,
+                found: 1,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_number_placeholders() {
+        let result = number_placeholders(
+            "test",
+            "WHERE note = '?' AND status IN (?) AND tags && ?::text[] AND data @> ? AND ids = ANY(?) AND age > ?",
+            vec![
+                json!(["a", "b"]),
+                json!(["x\"y", null]),
+                json!(["z"]),
+                json!([1, 2]),
+                json!(18),
+            ],
+            &Postgres,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "WHERE note = '?' AND status IN ($1, $2) AND tags && $3::text[] AND data @> $4 AND ids = ANY($5) AND age > $6".to_string(),
+                vec![
+                    json!("a"),
+                    json!("b"),
+                    json!("{\"x\\\"y\",NULL}"),
+                    json!(["z"]),
+                    json!([1, 2]),
+                    json!(18),
+                ]
+            ))
+        );
+
+        // Only the `IN` lists are expanded, the arrays of raw functions are kept.
+        let result = number_placeholders(
+            "test",
+            "SELECT * FROM unnest(?::int[]) as t(id) WHERE t.id NOT IN (?) AND jsonb_array_length(?) > 1 AND t.id IN(?)",
+            vec![json!([1, 2]), json!([3, 4]), json!([5]), json!([6])],
+            &Postgres,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT * FROM unnest($1::int[]) as t(id) WHERE t.id NOT IN ($2, $3) AND jsonb_array_length($4) > 1 AND t.id IN($5)".to_string(),
+                vec![json!("{1,2}"), json!(3), json!(4), json!([5]), json!(6)]
+            ))
+        );
+
+        let result = number_placeholders("test", "WHERE status IN (?)", vec![json!([])], &Sqlite);
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "test",
+                field: "values".to_string(),
+            })
+        );
+
+        let result = number_placeholders(
+            "test",
+            "WHERE id = ?::uuid AND tags = ?::text[]",
+            vec![json!("67e55044-10b1-426f-9247-bb680e5fe0c8"), json!(["a"])],
+            &Sqlite,
+        );
+        assert_eq!(
+            result,
+            Ok((
+                "WHERE id = ? AND tags = ?".to_string(),
+                vec![json!("67e55044-10b1-426f-9247-bb680e5fe0c8"), json!(["a"])]
+            ))
+        );
     }
 }
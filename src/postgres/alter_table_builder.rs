@@ -0,0 +1,394 @@
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::{quote_identifier, validate_identifier};
+use crate::postgres::create_table_builder::{
+    ColumnDefinition, Constraint, DataType, TableConstraint,
+};
+
+/// One action of an `ALTER TABLE` statement. Expressions (`USING`, defaults) are inlined
+/// as is, so they must not come from user input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterAction {
+    AddColumn(ColumnDefinition),
+    DropColumn(String),
+    RenameColumn(String, String),                // (from, to)
+    AlterType(String, DataType, Option<String>), // (column, type, USING expression)
+    SetDefault(String, String),                  // (column, expression)
+    DropDefault(String),
+    SetNotNull(String),
+    DropNotNull(String),
+    AddConstraint(TableConstraint, bool), // (constraint, NOT VALID)
+    DropConstraint(String),
+    ValidateConstraint(String),
+    RenameTo(String),
+}
+
+impl AlterAction {
+    fn is_rename(&self) -> bool {
+        matches!(self, Self::RenameColumn(..) | Self::RenameTo(_))
+    }
+
+    pub fn build(&self) -> Result<String> {
+        let column = |value: &str| {
+            if value.is_empty() {
+                return Err(Error::EmptyField {
+                    builder: "AlterTableBuilder",
+                    field: "column".to_string(),
+                });
+            }
+            Ok(quote_identifier(value))
+        };
+        let constraint = |value: &str| {
+            validate_identifier("AlterTableBuilder", "constraint", value)?;
+            Ok::<String, Error>(quote_identifier(value))
+        };
+        let statement = match self {
+            Self::AddColumn(definition) => format!("ADD COLUMN {}", definition.build()?),
+            Self::DropColumn(name) => format!("DROP COLUMN {}", column(name)?),
+            Self::RenameColumn(from, to) => {
+                format!("RENAME COLUMN {} TO {}", column(from)?, column(to)?)
+            }
+            Self::AlterType(name, data_type, using) => {
+                let mut statement = format!("ALTER COLUMN {} TYPE {data_type}", column(name)?);
+                if let Some(expression) = using {
+                    statement = format!("{statement} USING {expression}");
+                }
+                statement
+            }
+            Self::SetDefault(name, expression) => {
+                format!("ALTER COLUMN {} SET DEFAULT {expression}", column(name)?)
+            }
+            Self::DropDefault(name) => format!("ALTER COLUMN {} DROP DEFAULT", column(name)?),
+            Self::SetNotNull(name) => format!("ALTER COLUMN {} SET NOT NULL", column(name)?),
+            Self::DropNotNull(name) => format!("ALTER COLUMN {} DROP NOT NULL", column(name)?),
+            Self::AddConstraint(value, not_valid) => {
+                if !*not_valid {
+                    format!("ADD {}", value.build()?)
+                } else if matches!(
+                    value.constraint,
+                    Constraint::Check(_) | Constraint::ForeignKey(..)
+                ) {
+                    format!("ADD {} NOT VALID", value.build()?)
+                } else {
+                    // Only CHECK and FOREIGN KEY constraints can skip validating existing rows
+                    return Err(Error::UnsupportedClause {
+                        builder: "AlterTableBuilder",
+                        field: "not_valid".to_string(),
+                        target: "PRIMARY KEY and UNIQUE constraints".to_string(),
+                    });
+                }
+            }
+            Self::DropConstraint(name) => format!("DROP CONSTRAINT {}", constraint(name)?),
+            Self::ValidateConstraint(name) => {
+                format!("VALIDATE CONSTRAINT {}", constraint(name)?)
+            }
+            Self::RenameTo(name) => {
+                validate_identifier("AlterTableBuilder", "rename_to", name)?;
+                format!("RENAME TO {}", quote_identifier(name))
+            }
+        };
+        Ok(statement)
+    }
+}
+
+/// Builds `ALTER TABLE` statements. Several actions are joined with commas into a single
+/// statement, except renames, which PostgreSQL only accepts on their own.
+#[derive(Clone, Debug, Default)]
+pub struct AlterTableBuilder {
+    pub table: String,
+    pub if_exists: bool,
+    pub actions: Vec<AlterAction>,
+}
+
+impl AlterTableBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the table name, optionally schema-qualified, e.g. `billing.invoices`.
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn action(&mut self, value: AlterAction) -> &mut Self {
+        self.actions.push(value);
+        self
+    }
+
+    pub fn add_column(&mut self, value: ColumnDefinition) -> &mut Self {
+        self.action(AlterAction::AddColumn(value))
+    }
+
+    pub fn drop_column(&mut self, column: &str) -> &mut Self {
+        self.action(AlterAction::DropColumn(column.to_string()))
+    }
+
+    pub fn rename_column(&mut self, from: &str, to: &str) -> &mut Self {
+        self.action(AlterAction::RenameColumn(from.to_string(), to.to_string()))
+    }
+
+    /// Changes the type of `column`, converting the existing rows with `using` when set.
+    ///
+    /// # Example
+    /// ```ignore
+    /// alter_type("price", DataType::Numeric(Some((12, 2))), Some("price::numeric"));
+    /// ```
+    pub fn alter_type(
+        &mut self,
+        column: &str,
+        data_type: DataType,
+        using: Option<&str>,
+    ) -> &mut Self {
+        self.action(AlterAction::AlterType(
+            column.to_string(),
+            data_type,
+            using.map(|v| v.to_string()),
+        ))
+    }
+
+    pub fn set_default(&mut self, column: &str, expression: &str) -> &mut Self {
+        self.action(AlterAction::SetDefault(
+            column.to_string(),
+            expression.to_string(),
+        ))
+    }
+
+    pub fn drop_default(&mut self, column: &str) -> &mut Self {
+        self.action(AlterAction::DropDefault(column.to_string()))
+    }
+
+    pub fn set_not_null(&mut self, column: &str) -> &mut Self {
+        self.action(AlterAction::SetNotNull(column.to_string()))
+    }
+
+    pub fn drop_not_null(&mut self, column: &str) -> &mut Self {
+        self.action(AlterAction::DropNotNull(column.to_string()))
+    }
+
+    pub fn add_constraint(&mut self, value: TableConstraint) -> &mut Self {
+        self.action(AlterAction::AddConstraint(value, false))
+    }
+
+    /// Adds a CHECK or FOREIGN KEY constraint without checking the existing rows, which can
+    /// be done later with [`validate_constraint`](Self::validate_constraint).
+    pub fn add_constraint_not_valid(&mut self, value: TableConstraint) -> &mut Self {
+        self.action(AlterAction::AddConstraint(value, true))
+    }
+
+    pub fn drop_constraint(&mut self, name: &str) -> &mut Self {
+        self.action(AlterAction::DropConstraint(name.to_string()))
+    }
+
+    pub fn validate_constraint(&mut self, name: &str) -> &mut Self {
+        self.action(AlterAction::ValidateConstraint(name.to_string()))
+    }
+
+    pub fn rename_to(&mut self, name: &str) -> &mut Self {
+        self.action(AlterAction::RenameTo(name.to_string()))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "AlterTableBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.actions.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "AlterTableBuilder",
+                field: "actions".to_string(),
+            });
+        }
+        if self.actions.len() > 1 && self.actions.iter().any(|v| v.is_rename()) {
+            return Err(Error::UnsupportedClause {
+                builder: "AlterTableBuilder",
+                field: "rename".to_string(),
+                target: "ALTER TABLE with several actions".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.validate()?;
+        let mut actions: Vec<String> = Vec::new();
+        for action in &self.actions {
+            actions.push(action.build()?);
+        }
+        Ok(format!(
+            "ALTER TABLE {}{} {}",
+            if self.if_exists { "IF EXISTS " } else { "" },
+            Postgres.quote_qualified(&self.table),
+            actions.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+pub mod test_alter_table_builder {
+    use super::*;
+    use crate::postgres::create_table_builder::{References, ReferentialAction};
+
+    #[tokio::test]
+    async fn test_alter_table_builder() {
+        let mut builder = AlterTableBuilder::new();
+        let result = builder
+            .table("orders")
+            .if_exists()
+            .add_column(ColumnDefinition {
+                name: "status".to_string(),
+                data_type: DataType::Text,
+                not_null: true,
+                default: Some("'pending'".to_string()),
+                ..Default::default()
+            })
+            .drop_column("legacy_total")
+            .alter_type(
+                "total",
+                DataType::Numeric(Some((12, 2))),
+                Some("total::numeric"),
+            )
+            .set_default("created_at", "now()")
+            .drop_default("updated_at")
+            .set_not_null("user_id")
+            .drop_not_null("note")
+            .build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE IF EXISTS orders ADD COLUMN status text NOT NULL DEFAULT 'pending', DROP COLUMN legacy_total, ALTER COLUMN total TYPE numeric(12, 2) USING total::numeric, ALTER COLUMN created_at SET DEFAULT now(), ALTER COLUMN updated_at DROP DEFAULT, ALTER COLUMN user_id SET NOT NULL, ALTER COLUMN note DROP NOT NULL".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_alter_table_builder_constraints() {
+        let mut builder = AlterTableBuilder::new();
+        let result = builder
+            .table("billing.orders")
+            .add_constraint_not_valid(TableConstraint {
+                name: Some("orders_user_id_fkey".to_string()),
+                constraint: Constraint::ForeignKey(
+                    vec!["user_id".to_string()],
+                    References {
+                        table: "users".to_string(),
+                        columns: vec!["id".to_string()],
+                        on_delete: Some(ReferentialAction::SetNull),
+                        on_update: None,
+                    },
+                ),
+            })
+            .add_constraint(TableConstraint {
+                name: Some("orders_number_key".to_string()),
+                constraint: Constraint::Unique(vec!["number".to_string()]),
+            })
+            .drop_constraint("orders_total_check")
+            .build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE billing.orders ADD CONSTRAINT orders_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL NOT VALID, ADD CONSTRAINT orders_number_key UNIQUE (number), DROP CONSTRAINT orders_total_check".to_string())
+        );
+
+        let mut builder = AlterTableBuilder::new();
+        let result = builder
+            .table("billing.orders")
+            .validate_constraint("orders_user_id_fkey")
+            .build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE billing.orders VALIDATE CONSTRAINT orders_user_id_fkey".to_string())
+        );
+
+        let mut builder = AlterTableBuilder::new();
+        let result = builder.table("orders").drop_constraint("check").build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE orders DROP CONSTRAINT \"check\"".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_alter_table_builder_rename() {
+        let mut builder = AlterTableBuilder::new();
+        let result = builder
+            .table("orders")
+            .rename_column("user", "customer_id")
+            .build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE orders RENAME COLUMN \"user\" TO customer_id".to_string())
+        );
+
+        let mut builder = AlterTableBuilder::new();
+        let result = builder.table("orders").rename_to("purchases").build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE orders RENAME TO purchases".to_string())
+        );
+
+        let mut builder = AlterTableBuilder::new();
+        let result = builder.table("accounts").rename_to("user").build();
+        assert_eq!(
+            result,
+            Ok("ALTER TABLE accounts RENAME TO \"user\"".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_alter_table_builder_errors() {
+        let mut builder = AlterTableBuilder::new();
+        assert_eq!(
+            builder.table("orders").build(),
+            Err(Error::EmptyField {
+                builder: "AlterTableBuilder",
+                field: "actions".to_string(),
+            })
+        );
+
+        let result = builder.drop_column("note").rename_to("purchases").build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "AlterTableBuilder",
+                field: "rename".to_string(),
+                target: "ALTER TABLE with several actions".to_string(),
+            })
+        );
+
+        let mut builder = AlterTableBuilder::new();
+        let result = builder
+            .table("orders")
+            .add_constraint_not_valid(TableConstraint {
+                name: None,
+                constraint: Constraint::Unique(vec!["number".to_string()]),
+            })
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "AlterTableBuilder",
+                field: "not_valid".to_string(),
+                target: "PRIMARY KEY and UNIQUE constraints".to_string(),
+            })
+        );
+
+        let mut builder = AlterTableBuilder::new();
+        let result = builder
+            .table("orders")
+            .drop_constraint("x; DROP TABLE users")
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::InvalidIdentifier {
+                builder: "AlterTableBuilder",
+                field: "constraint".to_string(),
+                value: "x; DROP TABLE users".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use crate::postgres::{
+    Col, ConditionBuilder, ConditionValue, GroupByItem, Operator, OrderByItem, Sequence,
+};
+use crate::value::{SqlType, SqlValue};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde_json::Value;
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+/// The Rust type of a column, mapping the values it is compared with to a [`SqlValue`].
+/// Nullable columns are `Option<T>` and compared with `T` values.
+pub trait ColumnType {
+    type Value;
+
+    fn sql_value(value: Self::Value) -> SqlValue;
+}
+
+/// A column of single values, compared with `=`, `<`, `IN`, `BETWEEN`...
+pub trait ScalarColumnType: ColumnType {}
+
+/// An array column, such as `int8[]` for `Vec<i64>`, compared by containment.
+pub trait ArrayColumnType: ColumnType {}
+
+impl<T: ColumnType> ColumnType for Option<T> {
+    type Value = T::Value;
+
+    fn sql_value(value: Self::Value) -> SqlValue {
+        T::sql_value(value)
+    }
+}
+
+impl<T: ScalarColumnType> ScalarColumnType for Option<T> {}
+
+impl<T: ArrayColumnType> ArrayColumnType for Option<T> {}
+
+/// A `jsonb` column.
+impl ColumnType for Value {
+    type Value = Value;
+
+    fn sql_value(value: Self::Value) -> SqlValue {
+        SqlValue::Json(value)
+    }
+}
+
+impl ScalarColumnType for Value {}
+
+macro_rules! impl_column_type {
+    ($($source:ty),*) => {
+        $(
+            impl ColumnType for $source {
+                type Value = $source;
+
+                fn sql_value(value: Self::Value) -> SqlValue {
+                    SqlValue::from(value)
+                }
+            }
+
+            impl ScalarColumnType for $source {}
+
+            impl ColumnType for Vec<$source> {
+                type Value = Vec<$source>;
+
+                fn sql_value(value: Self::Value) -> SqlValue {
+                    SqlValue::from(value)
+                }
+            }
+
+            impl ArrayColumnType for Vec<$source> {}
+        )*
+    };
+}
+
+impl_column_type!(
+    bool,
+    i32,
+    i64,
+    f64,
+    String,
+    Uuid,
+    NaiveDate,
+    NaiveDateTime,
+    DateTime<Utc>
+);
+
+/// A `bytea` column.
+impl ColumnType for Vec<u8> {
+    type Value = Vec<u8>;
+
+    fn sql_value(value: Self::Value) -> SqlValue {
+        SqlValue::from(value)
+    }
+}
+
+impl ScalarColumnType for Vec<u8> {}
+
+/// A typed reference to a column, carrying its table, table alias and name. The
+/// conditions it builds only take values of its Rust type, so comparing an `int4` column
+/// with a string fails to compile instead of at the database. Usually declared per table
+/// with [`columns!`](crate::columns).
+///
+/// # Example
+/// ```ignore
+/// let users = Users::alias("u");
+/// users.email.eq("jdc@test.com"); // u.email = ?
+/// users.age.gte(18); // u.age >= ?::int4
+/// users.age.eq("18"); // does not compile
+/// ```
+#[derive(Clone, Debug)]
+pub struct Column<T> {
+    pub table: String,
+    pub table_alias: Option<String>,
+    pub name: String,
+    column_type: PhantomData<fn() -> T>,
+}
+
+impl<T: ColumnType> Column<T> {
+    pub fn new(table: &str, table_alias: Option<&str>, name: &str) -> Self {
+        Self {
+            table: table.to_string(),
+            table_alias: table_alias.map(str::to_string),
+            name: name.to_string(),
+            column_type: PhantomData,
+        }
+    }
+
+    /// The column as written in a select list, e.g. `u.email`.
+    pub fn qualified(&self) -> String {
+        match &self.table_alias {
+            Some(table_alias) => format!("{table_alias}.{}", self.name),
+            None => self.name.to_owned(),
+        }
+    }
+
+    /// The untyped [`Col`] of the column, which builds its conditions.
+    pub fn col(&self) -> Col {
+        Col {
+            table_alias: self.table_alias.to_owned(),
+            field: self.name.to_owned(),
+        }
+    }
+
+    fn compare(&self, operator: Operator, value: impl Into<T::Value>) -> ConditionBuilder {
+        let value = T::sql_value(value.into());
+        self.col()
+            .condition(operator, Some(ConditionValue::Typed(value)))
+    }
+
+    pub fn is_null(&self) -> ConditionBuilder {
+        self.col().condition(Operator::IsNull, None)
+    }
+
+    pub fn not_null(&self) -> ConditionBuilder {
+        self.col().condition(Operator::NotNull, None)
+    }
+
+    /// Compares with a column of the same type, e.g. the `ON` of a join, as
+    /// [`Col::eq_col`]. A column without an alias is referred to by its table name.
+    ///
+    /// # Example
+    /// ```ignore
+    /// orders.user_id.eq_col(&users.id); // o.user_id = u.id
+    /// ```
+    pub fn eq_col(&self, other: &Column<T>) -> ConditionBuilder {
+        let mut other_col = other.col();
+        if other_col.table_alias.is_none() {
+            other_col.table_alias = other.table.rsplit('.').next().map(str::to_string);
+        }
+        self.col()
+            .condition(Operator::Eq, Some(other_col.reference()))
+    }
+
+    pub fn asc(&self) -> OrderByItem {
+        self.order_by(Sequence::Asc)
+    }
+
+    pub fn desc(&self) -> OrderByItem {
+        self.order_by(Sequence::Desc)
+    }
+
+    fn order_by(&self, sequence: Sequence) -> OrderByItem {
+        OrderByItem {
+            table_alias: self.table_alias.to_owned(),
+            field: self.name.to_owned(),
+            sequence,
+            ..Default::default()
+        }
+    }
+}
+
+/// The comparisons of the single-value columns.
+impl<T: ScalarColumnType> Column<T> {
+    pub fn eq(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::Eq, value)
+    }
+
+    pub fn neq(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::Neq, value)
+    }
+
+    pub fn gt(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::Gt, value)
+    }
+
+    pub fn gte(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::Gte, value)
+    }
+
+    pub fn lt(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::Lt, value)
+    }
+
+    pub fn lte(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::Lte, value)
+    }
+
+    /// `IN (?, ?)`, one placeholder per value with the cast of its type.
+    pub fn in_list<V: Into<T::Value>>(
+        &self,
+        values: impl IntoIterator<Item = V>,
+    ) -> ConditionBuilder {
+        self.col().condition(
+            Operator::In,
+            Some(ConditionValue::Typed(Self::array(values))),
+        )
+    }
+
+    pub fn not_in<V: Into<T::Value>>(
+        &self,
+        values: impl IntoIterator<Item = V>,
+    ) -> ConditionBuilder {
+        self.col().condition(
+            Operator::NotIn,
+            Some(ConditionValue::Typed(Self::array(values))),
+        )
+    }
+
+    pub fn between(&self, low: impl Into<T::Value>, high: impl Into<T::Value>) -> ConditionBuilder {
+        let low = T::sql_value(low.into());
+        let high = T::sql_value(high.into());
+        self.col().condition(
+            Operator::Between,
+            Some(ConditionValue::TypedRange(low, high)),
+        )
+    }
+
+    fn array<V: Into<T::Value>>(values: impl IntoIterator<Item = V>) -> SqlValue {
+        let items: Vec<SqlValue> = values
+            .into_iter()
+            .map(|value| T::sql_value(value.into()))
+            .collect();
+        // The element type is only read by array casts, an `IN` list casts each item.
+        let element = items
+            .iter()
+            .find_map(SqlValue::sql_type)
+            .unwrap_or(SqlType::Text);
+        SqlValue::Array(element, items)
+    }
+}
+
+/// The containment tests of the array columns, `@>` and `<@`.
+impl<T: ArrayColumnType> Column<T> {
+    /// `@>`, the column holds every item of `value`.
+    pub fn contains(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::JsonbContains, value)
+    }
+
+    /// `<@`, every item of the column is in `value`.
+    pub fn contained_by(&self, value: impl Into<T::Value>) -> ConditionBuilder {
+        self.compare(Operator::JsonbContained, value)
+    }
+}
+
+impl<T: ColumnType<Value = String>> Column<T> {
+    pub fn like(&self, pattern: impl Into<String>) -> ConditionBuilder {
+        self.pattern(Operator::Like, pattern)
+    }
+
+    pub fn ilike(&self, pattern: impl Into<String>) -> ConditionBuilder {
+        self.pattern(Operator::ILike, pattern)
+    }
+
+    fn pattern(&self, operator: Operator, pattern: impl Into<String>) -> ConditionBuilder {
+        let value = Value::String(pattern.into());
+        self.col()
+            .condition(operator, Some(ConditionValue::Single(value)))
+    }
+}
+
+impl<T> From<&Column<T>> for GroupByItem {
+    fn from(value: &Column<T>) -> Self {
+        GroupByItem {
+            table_alias: value.table_alias.to_owned(),
+            field: value.name.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Declares the typed [`Column`]s of a table, as a struct with a field per column. A
+/// column is named after its field unless given as `field: Type = "name"`.
+///
+/// # Example
+/// ```ignore
+/// columns! {
+///     pub struct Users("users") {
+///         id: i64,
+///         email: String = "email_address",
+///         nickname: Option<String>,
+///     }
+/// }
+///
+/// let users = Users::alias("u"); // or Users::new() without an alias
+/// builder.table(Users::TABLE, "u").columns("u", vec![users.email.name.as_str()]);
+/// ```
+#[macro_export]
+macro_rules! columns {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($table:literal) {
+            $($field:ident: $type:ty $(= $column:literal)?),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        $vis struct $name {
+            $(pub $field: $crate::postgres::Column<$type>,)*
+        }
+
+        impl $name {
+            pub const TABLE: &'static str = $table;
+
+            /// The columns without a table alias, e.g. for updates and deletes.
+            pub fn new() -> Self {
+                Self::with_alias(None)
+            }
+
+            /// The columns qualified by `table_alias`.
+            pub fn alias(table_alias: &str) -> Self {
+                Self::with_alias(Some(table_alias))
+            }
+
+            fn with_alias(table_alias: Option<&str>) -> Self {
+                Self {
+                    $($field: $crate::postgres::Column::new(
+                        $table,
+                        table_alias,
+                        $crate::columns!(@name $field $($column)?),
+                    ),)*
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+    (@name $field:ident $column:literal) => {
+        $column
+    };
+    (@name $field:ident) => {
+        stringify!($field)
+    };
+}
+
+#[cfg(test)]
+pub mod test_column {
+    use super::*;
+    use crate::placeholder::PlaceholderKind;
+    use crate::postgres::{ExpressionBuilder, JoinKind, Logic, SelectBuilder};
+    use serde_json::json;
+
+    columns! {
+        pub struct Users("users") {
+            id: i64,
+            email: String = "email_address",
+            nickname: Option<String>,
+            age: i32,
+            profile: Value,
+            tags: Option<Vec<String>>,
+        }
+    }
+
+    columns! {
+        pub struct Orders("app.orders") {
+            id: i64,
+            user_id: i64,
+            created_at: DateTime<Utc>,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_column() {
+        let users = Users::alias("u");
+        assert_eq!(Users::TABLE, "users");
+        assert_eq!(users.email.qualified(), "u.email_address");
+        assert_eq!(Users::new().email.qualified(), "email_address");
+
+        let cases: Vec<(ConditionBuilder, &str, Vec<Value>)> = vec![
+            (
+                users.email.eq("jdc@test.com"),
+                "u.email_address = ?",
+                vec![json!("jdc@test.com")],
+            ),
+            (
+                users.nickname.neq("jdc"),
+                "u.nickname != ?",
+                vec![json!("jdc")],
+            ),
+            (users.age.gte(18), "u.age >= ?::int4", vec![json!(18)]),
+            (users.id.lt(10), "u.id < ?", vec![json!(10)]),
+            (
+                users.nickname.ilike("%JDC%"),
+                "u.nickname ILIKE ?",
+                vec![json!("%JDC%")],
+            ),
+            (
+                users.email.like("%@test.com"),
+                "u.email_address LIKE ?",
+                vec![json!("%@test.com")],
+            ),
+            (
+                users.id.in_list([1, 2]),
+                "u.id IN (?, ?)",
+                vec![json!(1), json!(2)],
+            ),
+            (
+                users.age.not_in(vec![1]),
+                "u.age NOT IN (?::int4)",
+                vec![json!(1)],
+            ),
+            (
+                users.age.between(18, 65),
+                "u.age BETWEEN ?::int4 AND ?::int4",
+                vec![json!(18), json!(65)],
+            ),
+            (users.nickname.is_null(), "u.nickname IS NULL", vec![]),
+            (
+                users.tags.contains(vec!["admin".to_string()]),
+                "u.tags @> ?::text[]",
+                vec![json!(["admin"])],
+            ),
+            (
+                users
+                    .tags
+                    .contained_by(vec!["a".to_string(), "b".to_string()]),
+                "u.tags <@ ?::text[]",
+                vec![json!(["a", "b"])],
+            ),
+            (users.tags.is_null(), "u.tags IS NULL", vec![]),
+            (
+                users.profile.eq(json!({"a": 1})),
+                "u.profile = ?::jsonb",
+                vec![json!({"a": 1})],
+            ),
+            (
+                Orders::new().user_id.eq_col(&users.id),
+                "user_id = u.id",
+                vec![],
+            ),
+            (
+                users.id.eq_col(&Orders::new().user_id),
+                "u.id = orders.user_id",
+                vec![],
+            ),
+        ];
+        for (condition, statement, values) in cases {
+            assert_eq!(
+                ConditionBuilder::build(&condition),
+                Ok(statement.to_string())
+            );
+            assert_eq!(ConditionBuilder::values(&condition), values);
+        }
+
+        let item = users.age.desc();
+        assert_eq!(
+            (item.table_alias, item.field, item.sequence),
+            (Some("u".to_string()), "age".to_string(), Sequence::Desc)
+        );
+        let item = GroupByItem::from(&users.email);
+        assert_eq!(
+            (item.table_alias, item.field),
+            (Some("u".to_string()), "email_address".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_column_select() {
+        let users = Users::alias("u");
+        let orders = Orders::alias("o");
+        let created_at: DateTime<Utc> = "2025-03-01T08:30:00Z".parse().unwrap();
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table(Users::TABLE, "u")
+            .columns("u", vec![users.id.name.as_str(), users.email.name.as_str()])
+            .join(
+                JoinKind::Inner,
+                Orders::TABLE,
+                "o",
+                vec![
+                    ExpressionBuilder::build(vec![orders.user_id.eq_col(&users.id)], None).unwrap(),
+                ],
+            )
+            .unwrap()
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        orders.created_at.gt(created_at),
+                        ConditionBuilder {
+                            logic: Some(Logic::And),
+                            ..users.nickname.not_null()
+                        },
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .order_by(vec![orders.created_at.desc()])
+            .unwrap();
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id, u.email_address FROM users as u INNER JOIN app.orders as o ON o.user_id = u.id WHERE o.created_at > $1::timestamptz AND u.nickname IS NOT NULL ORDER BY o.created_at DESC".to_string())
+        );
+        assert_eq!(builder.get_values(), vec![json!("2025-03-01T08:30:00Z")]);
+    }
+}
@@ -1,6 +1,8 @@
 use super::Logic;
-use crate::postgres::Operator;
-use anyhow::anyhow;
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::postgres::{Conditions, Operator};
+use crate::value::SqlValue;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -9,6 +11,25 @@ pub enum ConditionValue {
     Field(String, String), //(String,String) - (table alias, table field)
     Single(Value),
     Range(Value, Value),
+    Named(String),   // A named parameter `:name`, bound by name with `build_named`
+    Typed(SqlValue), // A typed value, its placeholder may be cast such as `?::uuid`
+    TypedRange(SqlValue, SqlValue), // A typed range, `BETWEEN ?::date AND ?::date`
+}
+
+impl ConditionValue {
+    /// Describes the kind of value, used when reporting an invalid operator/value pairing.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Field(_, _) => "a field reference",
+            Self::Single(Value::Array(_)) => "an array value",
+            Self::Single(_) => "a single value",
+            Self::Range(_, _) => "a range value",
+            Self::Named(_) => "a named parameter",
+            Self::Typed(value) if value.is_array() => "an array value",
+            Self::Typed(_) => "a single value",
+            Self::TypedRange(_, _) => "a range value",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +42,16 @@ pub struct ConditionBuilder {
 }
 
 impl ConditionBuilder {
+    /// Chains `items` with `AND`, see [`col`](crate::postgres::col).
+    pub fn and(self, items: impl Into<Conditions>) -> Conditions {
+        Conditions::from(self).and(items)
+    }
+
+    /// Chains `items` with `OR`, see [`col`](crate::postgres::col).
+    pub fn or(self, items: impl Into<Conditions>) -> Conditions {
+        Conditions::from(self).or(items)
+    }
+
     pub fn bind_value(value: &Value) -> String {
         match value {
             Value::Array(_) => "(?)".to_string(),
@@ -39,30 +70,221 @@ impl ConditionBuilder {
                 Self::bind_value(value1),
                 Self::bind_value(value2)
             ),
+            ConditionValue::Named(name) => format!(":{name}"),
+            ConditionValue::Typed(value) => value.placeholder(),
+            ConditionValue::TypedRange(value1, value2) => {
+                format!("{} AND {}", value1.placeholder(), value2.placeholder())
+            }
         };
         Some(value)
     }
 
-    pub fn build(item: &ConditionBuilder) -> anyhow::Result<String> {
+    /// Checks that the operator can be rendered with the given value.
+    ///
+    /// - `IS NULL` / `IS NOT NULL` take no value.
+    /// - `BETWEEN` takes a range of non-array values.
+    /// - `IN` / `NOT IN` take an array.
+    /// - Comparison and `LIKE` operators take a single non-array value or a field reference.
+    /// - JSONB operators take a single value or a field reference: a non-empty array of
+    ///   keys for `?|` / `?&`, a non-array key for `?`, `->` and `->>`, any value for the
+    ///   others.
+    /// - Named parameters are accepted wherever a single value is, their value is only
+    ///   known when building. `IN` / `NOT IN` refuse them, as their list can't be
+    ///   expanded into one placeholder per item.
+    pub fn validate(item: &ConditionBuilder) -> Result<()> {
+        let is_valid = match (&item.operator, &item.value) {
+            (Operator::IsNull | Operator::NotNull, value) => value.is_none(),
+            (Operator::Between, Some(ConditionValue::Range(value1, value2))) => {
+                !value1.is_array() && !value2.is_array()
+            }
+            (Operator::Between, Some(ConditionValue::TypedRange(value1, value2))) => {
+                !value1.is_array() && !value2.is_array()
+            }
+            (Operator::In | Operator::NotIn, Some(ConditionValue::Single(Value::Array(_)))) => true,
+            (Operator::In | Operator::NotIn, Some(ConditionValue::Typed(value))) => {
+                value.is_array()
+            }
+            (Operator::Between | Operator::In | Operator::NotIn, _) => false,
+            (_, Some(ConditionValue::Named(_))) => true,
+            (
+                Operator::Eq
+                | Operator::Neq
+                | Operator::Gt
+                | Operator::Gte
+                | Operator::Lt
+                | Operator::Lte
+                | Operator::Like
+                | Operator::ILike,
+                Some(ConditionValue::Single(value)),
+            ) => !value.is_array(),
+            (
+                Operator::Eq
+                | Operator::Neq
+                | Operator::Gt
+                | Operator::Gte
+                | Operator::Lt
+                | Operator::Lte
+                | Operator::Like
+                | Operator::ILike,
+                Some(ConditionValue::Typed(value)),
+            ) => !value.is_array(),
+            (
+                Operator::Eq
+                | Operator::Neq
+                | Operator::Gt
+                | Operator::Gte
+                | Operator::Lt
+                | Operator::Lte
+                | Operator::Like
+                | Operator::ILike,
+                Some(ConditionValue::Field(_, _)),
+            ) => true,
+            (
+                Operator::JsonbHasAnyKeys | Operator::JsonbHasAllKeys,
+                Some(ConditionValue::Single(value)),
+            ) => value.as_array().is_some_and(|items| !items.is_empty()),
+            (
+                Operator::JsonbHasAnyKeys | Operator::JsonbHasAllKeys,
+                Some(ConditionValue::Typed(value)),
+            ) => value.items().is_some_and(|items| !items.is_empty()),
+            (
+                Operator::JsonbHasKey | Operator::JsonbValue | Operator::JsonbValueAsText,
+                Some(ConditionValue::Single(value)),
+            ) => !value.is_array(),
+            (
+                Operator::JsonbHasKey | Operator::JsonbValue | Operator::JsonbValueAsText,
+                Some(ConditionValue::Typed(value)),
+            ) => !value.is_array(),
+            (
+                Operator::JsonbValue
+                | Operator::JsonbValueAsText
+                | Operator::JsonbContains
+                | Operator::JsonbContained
+                | Operator::JsonbHasKey
+                | Operator::JsonbHasAnyKeys
+                | Operator::JsonbHasAllKeys
+                | Operator::JsonbConcatenate
+                | Operator::JsonbRemoveKey
+                | Operator::JsonbRemovePath
+                | Operator::JsonbHasPath
+                | Operator::JsonbPathExists,
+                Some(
+                    ConditionValue::Single(_)
+                    | ConditionValue::Typed(_)
+                    | ConditionValue::Field(_, _),
+                ),
+            ) => true,
+            _ => false,
+        };
+        if !is_valid {
+            return Err(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: item.field.to_owned(),
+                operator: item.operator.to_string(),
+                value: item
+                    .value
+                    .as_ref()
+                    .map(|value| value.kind())
+                    .unwrap_or("no value")
+                    .to_string(),
+            });
+        }
+        match &item.value {
+            Some(ConditionValue::Typed(value)) => value.to_json().map(|_| ()),
+            Some(ConditionValue::TypedRange(value1, value2)) => {
+                value1.to_json().and(value2.to_json()).map(|_| ())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the values to bind for the condition, in the same order as their placeholders.
+    pub fn values(item: &ConditionBuilder) -> Vec<Value> {
+        // Dont use _ => {} in match because we want to make sure that values are push correctly
+        match &item.value {
+            Some(ConditionValue::Single(value)) => vec![value.to_owned()],
+            Some(ConditionValue::Range(value1, value2)) => {
+                vec![value1.to_owned(), value2.to_owned()]
+            }
+            Some(ConditionValue::Field(_, _)) => vec![],
+            Some(ConditionValue::Named(_)) => vec![],
+            // validate has checked that the typed values convert
+            Some(ConditionValue::Typed(value)) => match (&item.operator, value.items()) {
+                (Operator::In | Operator::NotIn, Some(items)) => items
+                    .iter()
+                    .map(|item| item.to_json().unwrap_or_default())
+                    .collect(),
+                _ => vec![value.to_json().unwrap_or_default()],
+            },
+            Some(ConditionValue::TypedRange(value1, value2)) => vec![
+                value1.to_json().unwrap_or_default(),
+                value2.to_json().unwrap_or_default(),
+            ],
+            None => vec![],
+        }
+    }
+
+    /// Renders the placeholders of an `IN` list, `(?, ?)`.
+    fn bind_list(item: &ConditionBuilder, placeholders: Vec<String>) -> Result<String> {
+        if placeholders.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "ConditionBuilder",
+                field: item.field.to_owned(),
+            });
+        }
+        Ok(format!("({})", placeholders.join(", ")))
+    }
+
+    pub fn build(item: &ConditionBuilder) -> Result<String> {
         let field = &item.field;
+        if field.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "ConditionBuilder",
+                field: "field".to_string(),
+            });
+        }
         let table_alias = if let Some(value) = &item.table_alias {
+            validate_identifier("ConditionBuilder", "table_alias", value)?;
             &format!("{value}.")
         } else {
             ""
         };
-        let operator = &item.operator;
-        if field.is_empty() {
-            return Err(anyhow!("field is empty"));
+        if let Some(ConditionValue::Field(table_alias, _)) = &item.value {
+            validate_identifier("ConditionBuilder", "value", table_alias)?;
         }
-        let value: Option<String> = if let Some(value) = &item.value {
-            Self::bind(value)
-        } else {
-            None
+        if let Some(ConditionValue::Named(name)) = &item.value {
+            validate_identifier("ConditionBuilder", "value", name)?;
+        }
+        Self::validate(item)?;
+        let operator = &item.operator;
+        let value: Option<String> = match (operator, &item.value) {
+            // One placeholder per item, each with the cast of its type.
+            (Operator::In | Operator::NotIn, Some(ConditionValue::Typed(value))) => {
+                let items = value.items().unwrap_or_default();
+                Some(Self::bind_list(
+                    item,
+                    items.iter().map(SqlValue::placeholder).collect(),
+                )?)
+            }
+            // The keys and paths are `text[]`, bound as an array literal.
+            (
+                Operator::JsonbHasAnyKeys
+                | Operator::JsonbHasAllKeys
+                | Operator::JsonbRemoveKey
+                | Operator::JsonbRemovePath,
+                Some(ConditionValue::Single(Value::Array(_))),
+            ) => Some("?::text[]".to_string()),
+            // The JSON documents are `jsonb`, whatever the driver makes of a JSON array.
+            (
+                Operator::JsonbContains | Operator::JsonbContained | Operator::JsonbConcatenate,
+                Some(ConditionValue::Single(Value::Array(_) | Value::Object(_))),
+            ) => Some("?::jsonb".to_string()),
+            (_, Some(value)) => Self::bind(value),
+            (_, None) => None,
         };
-        let condition = if let Some(value) = value
-            && operator != &Operator::IsNull
-            && operator != &Operator::NotNull
-        {
+        // The `?` of the JSONB operators is escaped as `??` to tell it from the placeholders.
+        let operator = operator.to_string().replace('?', "??");
+        let condition = if let Some(value) = value {
             format!("{table_alias}{field} {operator} {value}")
         } else {
             format!("{table_alias}{field} {operator}")
@@ -132,4 +354,461 @@ pub mod test_condition_builder {
             "AND t.myfield1 BETWEEN ? AND ?".to_string()
         );
     }
+
+    #[tokio::test]
+    async fn test_condition_operator_value_combinations() {
+        let none: Option<ConditionValue> = None;
+        let field = Some(ConditionValue::Field(
+            "p".to_string(),
+            "myfield2".to_string(),
+        ));
+        let single = Some(ConditionValue::Single(Value::String("test".to_string())));
+        let array = Some(ConditionValue::Single(Value::Array(vec![
+            Value::Number(Number::from(1)),
+            Value::Number(Number::from(2)),
+        ])));
+        let range = Some(ConditionValue::Range(
+            Value::Number(Number::from(10)),
+            Value::Number(Number::from(20)),
+        ));
+
+        // (operator, [none, field, single, array, range])
+        let cases: Vec<(Operator, [Option<&str>; 5])> = vec![
+            (
+                Operator::Eq,
+                [None, Some("t.f = p.myfield2"), Some("t.f = ?"), None, None],
+            ),
+            (
+                Operator::Neq,
+                [
+                    None,
+                    Some("t.f != p.myfield2"),
+                    Some("t.f != ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::Gt,
+                [None, Some("t.f > p.myfield2"), Some("t.f > ?"), None, None],
+            ),
+            (
+                Operator::Gte,
+                [
+                    None,
+                    Some("t.f >= p.myfield2"),
+                    Some("t.f >= ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::Lt,
+                [None, Some("t.f < p.myfield2"), Some("t.f < ?"), None, None],
+            ),
+            (
+                Operator::Lte,
+                [
+                    None,
+                    Some("t.f <= p.myfield2"),
+                    Some("t.f <= ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::Like,
+                [
+                    None,
+                    Some("t.f LIKE p.myfield2"),
+                    Some("t.f LIKE ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::ILike,
+                [
+                    None,
+                    Some("t.f ILIKE p.myfield2"),
+                    Some("t.f ILIKE ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (Operator::In, [None, None, None, Some("t.f IN (?)"), None]),
+            (
+                Operator::NotIn,
+                [None, None, None, Some("t.f NOT IN (?)"), None],
+            ),
+            (
+                Operator::IsNull,
+                [Some("t.f IS NULL"), None, None, None, None],
+            ),
+            (
+                Operator::NotNull,
+                [Some("t.f IS NOT NULL"), None, None, None, None],
+            ),
+            (
+                Operator::Between,
+                [None, None, None, None, Some("t.f BETWEEN ? AND ?")],
+            ),
+            (
+                Operator::JsonbValue,
+                [
+                    None,
+                    Some("t.f -> p.myfield2"),
+                    Some("t.f -> ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbValueAsText,
+                [
+                    None,
+                    Some("t.f ->> p.myfield2"),
+                    Some("t.f ->> ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbContains,
+                [
+                    None,
+                    Some("t.f @> p.myfield2"),
+                    Some("t.f @> ?"),
+                    Some("t.f @> ?::jsonb"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbContained,
+                [
+                    None,
+                    Some("t.f <@ p.myfield2"),
+                    Some("t.f <@ ?"),
+                    Some("t.f <@ ?::jsonb"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbHasKey,
+                [
+                    None,
+                    Some("t.f ?? p.myfield2"),
+                    Some("t.f ?? ?"),
+                    None,
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbHasAnyKeys,
+                [
+                    None,
+                    Some("t.f ??| p.myfield2"),
+                    None,
+                    Some("t.f ??| ?::text[]"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbHasAllKeys,
+                [
+                    None,
+                    Some("t.f ??& p.myfield2"),
+                    None,
+                    Some("t.f ??& ?::text[]"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbConcatenate,
+                [
+                    None,
+                    Some("t.f || p.myfield2"),
+                    Some("t.f || ?"),
+                    Some("t.f || ?::jsonb"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbRemoveKey,
+                [
+                    None,
+                    Some("t.f - p.myfield2"),
+                    Some("t.f - ?"),
+                    Some("t.f - ?::text[]"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbRemovePath,
+                [
+                    None,
+                    Some("t.f #- p.myfield2"),
+                    Some("t.f #- ?"),
+                    Some("t.f #- ?::text[]"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbHasPath,
+                [
+                    None,
+                    Some("t.f @?? p.myfield2"),
+                    Some("t.f @?? ?"),
+                    Some("t.f @?? (?)"),
+                    None,
+                ],
+            ),
+            (
+                Operator::JsonbPathExists,
+                [
+                    None,
+                    Some("t.f @@ p.myfield2"),
+                    Some("t.f @@ ?"),
+                    Some("t.f @@ (?)"),
+                    None,
+                ],
+            ),
+        ];
+        let values = [&none, &field, &single, &array, &range];
+        let expected_values = [0, 0, 1, 1, 2];
+        for (operator, expected) in cases {
+            for (index, value) in values.iter().enumerate() {
+                let item = ConditionBuilder {
+                    table_alias: Some("t".to_string()),
+                    field: "f".to_string(),
+                    operator: operator.clone(),
+                    value: (*value).clone(),
+                    logic: None,
+                };
+                let result = ConditionBuilder::build(&item);
+                match expected[index] {
+                    Some(condition) => {
+                        assert_eq!(result, Ok(condition.to_string()), "{operator:?} {value:?}");
+                        assert_eq!(
+                            ConditionBuilder::values(&item).len(),
+                            expected_values[index],
+                            "{operator:?} {value:?}"
+                        );
+                    }
+                    None => {
+                        assert_eq!(
+                            result,
+                            Err(Error::UnsupportedOperatorValue {
+                                builder: "ConditionBuilder",
+                                field: "f".to_string(),
+                                operator: operator.to_string(),
+                                value: value
+                                    .as_ref()
+                                    .map(|value| value.kind())
+                                    .unwrap_or("no value")
+                                    .to_string(),
+                            }),
+                            "{operator:?} {value:?}"
+                        );
+                    }
+                }
+            }
+        }
+
+        // An empty list of keys and a range of arrays have nothing to compare.
+        let rejected = [
+            (
+                Operator::JsonbHasAnyKeys,
+                ConditionValue::Single(Value::Array(vec![])),
+            ),
+            (
+                Operator::JsonbHasAllKeys,
+                ConditionValue::Single(Value::Array(vec![])),
+            ),
+            (
+                Operator::Between,
+                ConditionValue::Range(
+                    Value::Array(vec![Value::Number(Number::from(1))]),
+                    Value::Number(Number::from(2)),
+                ),
+            ),
+        ];
+        for (operator, value) in rejected {
+            let result = ConditionBuilder::build(&ConditionBuilder {
+                table_alias: Some("t".to_string()),
+                field: "f".to_string(),
+                operator: operator.clone(),
+                value: Some(value.clone()),
+                logic: None,
+            });
+            assert_eq!(
+                result,
+                Err(Error::UnsupportedOperatorValue {
+                    builder: "ConditionBuilder",
+                    field: "f".to_string(),
+                    operator: operator.to_string(),
+                    value: value.kind().to_string(),
+                }),
+                "{operator:?} {value:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_condition_errors() {
+        let result = ConditionBuilder::build(&ConditionBuilder {
+            table_alias: Some("t".to_string()),
+            field: "".to_string(),
+            operator: Operator::Eq,
+            value: Some(ConditionValue::Single(Value::String("test".to_string()))),
+            logic: None,
+        });
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "ConditionBuilder",
+                field: "field".to_string(),
+            })
+        );
+
+        let result = ConditionBuilder::build(&ConditionBuilder {
+            table_alias: Some("t;".to_string()),
+            field: "myfield1".to_string(),
+            operator: Operator::Eq,
+            value: Some(ConditionValue::Single(Value::String("test".to_string()))),
+            logic: None,
+        });
+        assert_eq!(
+            result,
+            Err(Error::InvalidIdentifier {
+                builder: "ConditionBuilder",
+                field: "table_alias".to_string(),
+                value: "t;".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_condition_named() {
+        let condition = |operator: Operator, name: &str| ConditionBuilder {
+            table_alias: Some("t".to_string()),
+            field: "f".to_string(),
+            operator,
+            value: Some(ConditionValue::Named(name.to_string())),
+            logic: None,
+        };
+        let item = condition(Operator::Eq, "email");
+        assert_eq!(
+            ConditionBuilder::build(&item),
+            Ok("t.f = :email".to_string())
+        );
+        assert!(ConditionBuilder::values(&item).is_empty());
+        let result = ConditionBuilder::build(&condition(Operator::In, "ids"));
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: "f".to_string(),
+                operator: "IN".to_string(),
+                value: "a named parameter".to_string(),
+            })
+        );
+        let result = ConditionBuilder::build(&condition(Operator::NotIn, "ids"));
+        assert!(
+            result.is_err(),
+            "expecting NOT IN to refuse a named parameter"
+        );
+        let result = ConditionBuilder::build(&condition(Operator::Between, "range"));
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: "f".to_string(),
+                operator: "BETWEEN".to_string(),
+                value: "a named parameter".to_string(),
+            })
+        );
+        let result = ConditionBuilder::build(&condition(Operator::Eq, "a; DROP TABLE t"));
+        assert!(result.is_err(), "expecting invalid parameter name");
+    }
+
+    #[tokio::test]
+    async fn test_condition_typed() {
+        use crate::value::{SqlType, SqlValue};
+
+        let condition = |field: &str, operator: Operator, value: SqlValue| ConditionBuilder {
+            table_alias: Some("o".to_string()),
+            field: field.to_string(),
+            operator,
+            value: Some(ConditionValue::Typed(value)),
+            logic: None,
+        };
+        let result = ConditionBuilder::build(&condition(
+            "created_at",
+            Operator::Gte,
+            SqlValue::from("2025-01-01").cast(SqlType::Timestamptz),
+        ));
+        assert_eq!(result, Ok("o.created_at >= ?::timestamptz".to_string()));
+
+        let item = condition(
+            "total",
+            Operator::Lt,
+            SqlValue::Numeric("12.50".to_string()),
+        );
+        assert_eq!(
+            ConditionBuilder::build(&item),
+            Ok("o.total < ?::numeric".to_string())
+        );
+        assert_eq!(
+            ConditionBuilder::values(&item),
+            vec![Value::String("12.50".to_string())]
+        );
+
+        let item = condition("id", Operator::In, SqlValue::from(vec![1, 2]));
+        assert_eq!(
+            ConditionBuilder::build(&item),
+            Ok("o.id IN (?::int4, ?::int4)".to_string())
+        );
+        assert_eq!(
+            ConditionBuilder::values(&item),
+            vec![Value::from(1), Value::from(2)]
+        );
+        let item = condition(
+            "id",
+            Operator::NotIn,
+            SqlValue::array(SqlType::Text, vec!["67e55044-10b1-426f-9247-bb680e5fe0c8"])
+                .cast(SqlType::Array(Box::new(SqlType::Uuid))),
+        );
+        assert_eq!(
+            ConditionBuilder::build(&item),
+            Ok("o.id NOT IN (?::uuid)".to_string())
+        );
+        let result = ConditionBuilder::build(&condition(
+            "id",
+            Operator::In,
+            SqlValue::array(SqlType::Int4, Vec::<i32>::new()),
+        ));
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "ConditionBuilder",
+                field: "id".to_string(),
+            })
+        );
+        let result =
+            ConditionBuilder::build(&condition("total", Operator::Gt, SqlValue::from(f64::NAN)));
+        assert!(result.is_err(), "expecting NaN to be rejected");
+
+        let result =
+            ConditionBuilder::build(&condition("id", Operator::Eq, SqlValue::from(vec![1, 2])));
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: "id".to_string(),
+                operator: "=".to_string(),
+                value: "an array value".to_string(),
+            })
+        );
+    }
 }
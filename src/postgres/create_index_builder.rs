@@ -0,0 +1,429 @@
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::{quote_identifier, validate_identifier};
+use crate::literal::inline_values;
+use crate::postgres::{ExpressionBuilder, Nulls, Operator, Sequence, WhereBuilder};
+use serde_json::Value;
+
+/// The index access method, `USING method`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexMethod {
+    Btree,
+    Hash,
+    Gist,
+    Gin,
+    Brin,
+}
+
+impl std::fmt::Display for IndexMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::Btree => "btree",
+            Self::Hash => "hash",
+            Self::Gist => "gist",
+            Self::Gin => "gin",
+            Self::Brin => "brin",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// A key of the index, either a column or an expression such as `lower(email)` or
+/// `(data->>'sku')`. Expressions are inlined as is, so they must not come from user input.
+#[derive(Clone, Debug, Default)]
+pub struct IndexKey {
+    pub column: String,
+    pub expression: Option<String>, // Used instead of `column` when set
+    pub opclass: Option<String>,    // An operator class such as jsonb_path_ops
+    pub sequence: Option<Sequence>,
+    pub nulls: Option<Nulls>,
+}
+
+impl IndexKey {
+    pub fn build(&self) -> Result<String> {
+        let mut statement = match &self.expression {
+            Some(_) if !self.column.is_empty() => {
+                return Err(Error::DuplicateClause {
+                    builder: "CreateIndexBuilder",
+                    field: "expression".to_string(),
+                });
+            }
+            Some(expression) if expression.trim().is_empty() => {
+                return Err(Error::EmptyField {
+                    builder: "CreateIndexBuilder",
+                    field: "expression".to_string(),
+                });
+            }
+            Some(expression) => format!("({expression})"),
+            None if self.column.is_empty() => {
+                return Err(Error::EmptyField {
+                    builder: "CreateIndexBuilder",
+                    field: "column".to_string(),
+                });
+            }
+            None => quote_identifier(&self.column),
+        };
+        if let Some(opclass) = &self.opclass {
+            validate_identifier("CreateIndexBuilder", "opclass", opclass)?;
+            statement = format!("{statement} {opclass}");
+        }
+        match &self.sequence {
+            Some(Sequence::Using(_)) => {
+                return Err(Error::UnsupportedClause {
+                    builder: "CreateIndexBuilder",
+                    field: "sequence".to_string(),
+                    target: "CREATE INDEX".to_string(),
+                });
+            }
+            Some(sequence) => statement = format!("{statement} {sequence}"),
+            None => {}
+        }
+        if let Some(nulls) = &self.nulls {
+            statement = format!("{statement} {nulls}");
+        }
+        Ok(statement)
+    }
+}
+
+/// Builds `CREATE INDEX` statements.
+///
+/// # Example
+/// ```ignore
+/// let mut builder = CreateIndexBuilder::new();
+/// builder
+///     .name("users_active_email_idx")
+///     .table("users")
+///     .unique()
+///     .concurrently()
+///     .expression("lower(email)")
+///     .filter(vec![ExpressionBuilder::build(vec![ConditionBuilder { .. }], None)?]);
+/// // CREATE UNIQUE INDEX CONCURRENTLY users_active_email_idx ON users ((lower(email))) WHERE status = 'active'
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CreateIndexBuilder {
+    pub name: Option<String>,
+    pub table: String,
+    pub unique: bool,
+    pub concurrently: bool,
+    pub if_not_exists: bool,
+    pub method: Option<IndexMethod>,
+    pub keys: Vec<IndexKey>,
+    pub include: Vec<String>,
+    pub filter_statement: Option<String>,
+    pub filter_values: Vec<Value>,
+    operators: Vec<Operator>,
+}
+
+impl CreateIndexBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the index name. PostgreSQL picks one when it isn't set.
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the indexed table, optionally schema-qualified, e.g. `billing.invoices`.
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    pub fn unique(&mut self) -> &mut Self {
+        self.unique = true;
+        self
+    }
+
+    /// Builds the index without locking out writes on its table. The statement can't be
+    /// run inside a transaction.
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn method(&mut self, value: IndexMethod) -> &mut Self {
+        self.method = Some(value);
+        self
+    }
+
+    pub fn key(&mut self, value: IndexKey) -> &mut Self {
+        self.keys.push(value);
+        self
+    }
+
+    pub fn column(&mut self, column: &str) -> &mut Self {
+        self.key(IndexKey {
+            column: column.to_string(),
+            ..Default::default()
+        })
+    }
+
+    pub fn expression(&mut self, expression: &str) -> &mut Self {
+        self.key(IndexKey {
+            expression: Some(expression.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Adds non-key columns stored in the index, `INCLUDE (columns)`.
+    pub fn include(&mut self, columns: Vec<&str>) -> &mut Self {
+        self.include
+            .extend(columns.iter().map(|column| column.to_string()));
+        self
+    }
+
+    /// Makes a partial index, only covering the rows matching the expressions. DDL can't
+    /// take bind parameters, so the values are inlined as literals when building.
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.operators = values
+                .iter()
+                .flat_map(|value| value.operators.iter().cloned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateIndexBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.keys.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateIndexBuilder",
+                field: "keys".to_string(),
+            });
+        }
+        if self.if_not_exists && self.name.is_none() {
+            return Err(Error::EmptyField {
+                builder: "CreateIndexBuilder",
+                field: "name".to_string(),
+            });
+        }
+        if let Some(name) = &self.name {
+            validate_identifier("CreateIndexBuilder", "name", name)?;
+        }
+        // Only btree indexes can enforce uniqueness
+        if let Some(method) = &self.method
+            && self.unique
+            && *method != IndexMethod::Btree
+        {
+            return Err(Error::UnsupportedClause {
+                builder: "CreateIndexBuilder",
+                field: "unique".to_string(),
+                target: format!("{method} indexes"),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.validate()?;
+        let mut statement = "CREATE".to_string();
+        if self.unique {
+            statement = format!("{statement} UNIQUE");
+        }
+        statement = format!("{statement} INDEX");
+        if self.concurrently {
+            statement = format!("{statement} CONCURRENTLY");
+        }
+        if self.if_not_exists {
+            statement = format!("{statement} IF NOT EXISTS");
+        }
+        if let Some(name) = &self.name {
+            statement = format!("{statement} {name}");
+        }
+        statement = format!("{statement} ON {}", Postgres.quote_qualified(&self.table));
+        if let Some(method) = &self.method {
+            statement = format!("{statement} USING {method}");
+        }
+        let mut keys: Vec<String> = Vec::new();
+        for key in &self.keys {
+            keys.push(key.build()?);
+        }
+        statement = format!("{statement} ({})", keys.join(", "));
+        if !self.include.is_empty() {
+            let columns = self
+                .include
+                .iter()
+                .map(|column| quote_identifier(column))
+                .collect::<Vec<String>>();
+            statement = format!("{statement} INCLUDE ({})", columns.join(", "));
+        }
+        if let Some(filter) = &self.filter_statement {
+            let filter = inline_values("CreateIndexBuilder", filter, self.filter_values.clone())?;
+            statement = format!("{statement} {filter}");
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_create_index_builder {
+    use super::*;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Logic};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_create_index_builder() {
+        let mut builder = CreateIndexBuilder::new();
+        let result = builder
+            .name("users_email_idx")
+            .table("users")
+            .unique()
+            .concurrently()
+            .if_not_exists()
+            .expression("lower(email)")
+            .include(vec!["id", "name"])
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS users_email_idx ON users ((lower(email))) INCLUDE (id, name)".to_string())
+        );
+
+        let mut builder = CreateIndexBuilder::new();
+        let result = builder
+            .table("products")
+            .method(IndexMethod::Gin)
+            .key(IndexKey {
+                column: "metadata".to_string(),
+                opclass: Some("jsonb_path_ops".to_string()),
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE INDEX ON products USING gin (metadata jsonb_path_ops)".to_string())
+        );
+
+        let mut builder = CreateIndexBuilder::new();
+        let result = builder
+            .name("orders_recent_idx")
+            .table("billing.orders")
+            .column("user_id")
+            .key(IndexKey {
+                column: "created_at".to_string(),
+                sequence: Some(Sequence::Desc),
+                nulls: Some(Nulls::Last),
+                ..Default::default()
+            })
+            .expression("data->>'sku'")
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE INDEX orders_recent_idx ON billing.orders (user_id, created_at DESC NULLS LAST, (data->>'sku'))".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_index_builder_partial() {
+        let expression = ExpressionBuilder::build(
+            vec![
+                ConditionBuilder {
+                    table_alias: None,
+                    field: "status".to_string(),
+                    operator: Operator::In,
+                    value: Some(ConditionValue::Single(json!(["active", "it's pending"]))),
+                    logic: None,
+                },
+                ConditionBuilder {
+                    table_alias: None,
+                    field: "deleted_at".to_string(),
+                    operator: Operator::IsNull,
+                    value: None,
+                    logic: Some(Logic::And),
+                },
+                ConditionBuilder {
+                    table_alias: None,
+                    field: "score".to_string(),
+                    operator: Operator::Gte,
+                    value: Some(ConditionValue::Single(json!(10))),
+                    logic: Some(Logic::And),
+                },
+            ],
+            None,
+        );
+        assert!(expression.is_ok(), "{:?}", expression.err());
+        let mut builder = CreateIndexBuilder::new();
+        let result = builder
+            .name("users_active_idx")
+            .table("users")
+            .column("email")
+            .filter(vec![expression.unwrap()])
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE INDEX users_active_idx ON users (email) WHERE status IN ('active', 'it''s pending') AND deleted_at IS NULL AND score >= 10".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_index_builder_errors() {
+        let mut builder = CreateIndexBuilder::new();
+        assert_eq!(
+            builder.table("users").build(),
+            Err(Error::EmptyField {
+                builder: "CreateIndexBuilder",
+                field: "keys".to_string(),
+            })
+        );
+        assert_eq!(
+            builder.column("email").if_not_exists().build(),
+            Err(Error::EmptyField {
+                builder: "CreateIndexBuilder",
+                field: "name".to_string(),
+            })
+        );
+
+        let mut builder = CreateIndexBuilder::new();
+        let result = builder
+            .table("users")
+            .unique()
+            .method(IndexMethod::Gin)
+            .column("tags")
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "CreateIndexBuilder",
+                field: "unique".to_string(),
+                target: "gin indexes".to_string(),
+            })
+        );
+
+        let expression = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: None,
+                field: "metadata".to_string(),
+                operator: Operator::JsonbHasKey,
+                value: Some(ConditionValue::Single(json!("sku"))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = CreateIndexBuilder::new();
+        let result = builder
+            .table("products")
+            .column("id")
+            .filter(vec![expression])
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE INDEX ON products (id) WHERE metadata ? 'sku'".to_string())
+        );
+    }
+}
@@ -0,0 +1,664 @@
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::{quote_identifier, validate_identifier};
+
+/// PostgreSQL column types.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DataType {
+    SmallInt,
+    Integer,
+    BigInt,
+    Serial,
+    BigSerial,
+    Real,
+    DoublePrecision,
+    Numeric(Option<(u32, u32)>), // numeric(precision, scale)
+    Boolean,
+    #[default]
+    Text,
+    Varchar(Option<u32>),
+    Uuid,
+    Json,
+    Jsonb,
+    Bytea,
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    Interval,
+    Array(Box<DataType>), // e.g. text[]
+    Custom(String),       // Any other type, inlined as is, e.g. an enum type or citext
+}
+
+impl std::fmt::Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SmallInt => write!(f, "smallint"),
+            Self::Integer => write!(f, "integer"),
+            Self::BigInt => write!(f, "bigint"),
+            Self::Serial => write!(f, "serial"),
+            Self::BigSerial => write!(f, "bigserial"),
+            Self::Real => write!(f, "real"),
+            Self::DoublePrecision => write!(f, "double precision"),
+            Self::Numeric(None) => write!(f, "numeric"),
+            Self::Numeric(Some((precision, scale))) => write!(f, "numeric({precision}, {scale})"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Text => write!(f, "text"),
+            Self::Varchar(None) => write!(f, "varchar"),
+            Self::Varchar(Some(length)) => write!(f, "varchar({length})"),
+            Self::Uuid => write!(f, "uuid"),
+            Self::Json => write!(f, "json"),
+            Self::Jsonb => write!(f, "jsonb"),
+            Self::Bytea => write!(f, "bytea"),
+            Self::Date => write!(f, "date"),
+            Self::Time => write!(f, "time"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Timestamptz => write!(f, "timestamptz"),
+            Self::Interval => write!(f, "interval"),
+            Self::Array(value) => write!(f, "{value}[]"),
+            Self::Custom(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Identity {
+    Always,
+    ByDefault,
+}
+
+impl std::fmt::Display for Identity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => write!(f, "GENERATED ALWAYS AS IDENTITY"),
+            Self::ByDefault => write!(f, "GENERATED BY DEFAULT AS IDENTITY"),
+        }
+    }
+}
+
+/// The action taken on the referencing rows when the referenced row is deleted or updated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl std::fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::NoAction => "NO ACTION",
+            Self::Restrict => "RESTRICT",
+            Self::Cascade => "CASCADE",
+            Self::SetNull => "SET NULL",
+            Self::SetDefault => "SET DEFAULT",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// `REFERENCES table (columns) [ON DELETE action] [ON UPDATE action]`. When `columns` is
+/// empty, the primary key of the referenced table is used.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct References {
+    pub table: String,
+    pub columns: Vec<String>,
+    pub on_delete: Option<ReferentialAction>,
+    pub on_update: Option<ReferentialAction>,
+}
+
+impl References {
+    pub fn build(&self) -> Result<String> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "References",
+                field: "table".to_string(),
+            });
+        }
+        let mut statement = format!("REFERENCES {}", Postgres.quote_qualified(&self.table));
+        if !self.columns.is_empty() {
+            statement = format!("{statement} ({})", quote_columns(&self.columns));
+        }
+        if let Some(action) = &self.on_delete {
+            statement = format!("{statement} ON DELETE {action}");
+        }
+        if let Some(action) = &self.on_update {
+            statement = format!("{statement} ON UPDATE {action}");
+        }
+        Ok(statement)
+    }
+}
+
+/// A column of `CREATE TABLE`. `default` and `check` are SQL expressions inlined as is,
+/// e.g. `now()` or `price > 0`, so they must not come from user input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnDefinition {
+    pub name: String,
+    pub data_type: DataType,
+    pub identity: Option<Identity>,
+    pub not_null: bool,
+    pub default: Option<String>,
+    pub check: Option<String>,
+    pub unique: bool,
+    pub primary_key: bool,
+    pub references: Option<References>,
+}
+
+impl ColumnDefinition {
+    pub fn build(&self) -> Result<String> {
+        if self.name.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "ColumnDefinition",
+                field: "name".to_string(),
+            });
+        }
+        let mut statement = format!("{} {}", quote_identifier(&self.name), self.data_type);
+        if let Some(identity) = &self.identity {
+            if self.default.is_some() {
+                return Err(Error::DuplicateClause {
+                    builder: "ColumnDefinition",
+                    field: "default".to_string(),
+                });
+            }
+            statement = format!("{statement} {identity}");
+        }
+        if self.not_null {
+            statement = format!("{statement} NOT NULL");
+        }
+        if let Some(value) = &self.default {
+            statement = format!("{statement} DEFAULT {value}");
+        }
+        if let Some(value) = &self.check {
+            statement = format!("{statement} CHECK ({value})");
+        }
+        if self.unique {
+            statement = format!("{statement} UNIQUE");
+        }
+        if self.primary_key {
+            statement = format!("{statement} PRIMARY KEY");
+        }
+        if let Some(references) = &self.references {
+            statement = format!("{statement} {}", references.build()?);
+        }
+        Ok(statement)
+    }
+}
+
+/// A table-level constraint, for constraints over several columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    PrimaryKey(Vec<String>),
+    Unique(Vec<String>),
+    Check(String), // SQL expression, inlined as is
+    ForeignKey(Vec<String>, References),
+}
+
+/// `[CONSTRAINT name] constraint`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableConstraint {
+    pub name: Option<String>,
+    pub constraint: Constraint,
+}
+
+impl TableConstraint {
+    pub fn build(&self) -> Result<String> {
+        let columns = |columns: &[String]| {
+            if columns.is_empty() {
+                return Err(Error::EmptyField {
+                    builder: "TableConstraint",
+                    field: "columns".to_string(),
+                });
+            }
+            Ok(quote_columns(columns))
+        };
+        let constraint = match &self.constraint {
+            Constraint::PrimaryKey(values) => format!("PRIMARY KEY ({})", columns(values)?),
+            Constraint::Unique(values) => format!("UNIQUE ({})", columns(values)?),
+            Constraint::Check(expression) => format!("CHECK ({expression})"),
+            Constraint::ForeignKey(values, references) => {
+                format!("FOREIGN KEY ({}) {}", columns(values)?, references.build()?)
+            }
+        };
+        if let Some(name) = &self.name {
+            validate_identifier("TableConstraint", "name", name)?;
+            Ok(format!(
+                "CONSTRAINT {} {constraint}",
+                quote_identifier(name)
+            ))
+        } else {
+            Ok(constraint)
+        }
+    }
+}
+
+/// `PARTITION BY { RANGE | LIST | HASH } (columns)`, for partitioned tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartitionBy {
+    Range(Vec<String>),
+    List(Vec<String>),
+    Hash(Vec<String>),
+}
+
+/// The bounds of a partition created with `PARTITION OF`. The values are SQL literals
+/// inlined as is, e.g. `'2025-01-01'` or `MINVALUE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartitionBound {
+    Range(Vec<String>, Vec<String>), // FOR VALUES FROM (..) TO (..)
+    In(Vec<String>),                 // FOR VALUES IN (..)
+    Hash(u32, u32),                  // FOR VALUES WITH (MODULUS m, REMAINDER r)
+    Default,                         // DEFAULT
+}
+
+impl std::fmt::Display for PartitionBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Range(from, to) => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                from.join(", "),
+                to.join(", ")
+            ),
+            Self::In(values) => write!(f, "FOR VALUES IN ({})", values.join(", ")),
+            Self::Hash(modulus, remainder) => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})"
+            ),
+            Self::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
+
+fn quote_columns(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Builds `CREATE TABLE` statements. Columns and constraints are rendered in the order
+/// they were added, so the output is stable for snapshot tests.
+#[derive(Clone, Debug, Default)]
+pub struct CreateTableBuilder {
+    pub table: String,
+    pub if_not_exists: bool,
+    pub columns: Vec<ColumnDefinition>,
+    pub constraints: Vec<TableConstraint>,
+    pub partition_by: Option<PartitionBy>,
+    pub partition_of: Option<(String, PartitionBound)>,
+}
+
+impl CreateTableBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the table name, optionally schema-qualified, e.g. `billing.invoices`.
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn column(&mut self, value: ColumnDefinition) -> &mut Self {
+        self.columns.push(value);
+        self
+    }
+
+    pub fn constraint(&mut self, value: TableConstraint) -> &mut Self {
+        self.constraints.push(value);
+        self
+    }
+
+    /// Makes the table partitioned, e.g. `PARTITION BY RANGE (created_at)`.
+    pub fn partition_by(&mut self, value: PartitionBy) -> &mut Self {
+        self.partition_by = Some(value);
+        self
+    }
+
+    /// Creates the table as a partition of `parent`. Columns are inherited from the
+    /// parent, so only constraints may be added.
+    ///
+    /// # Example
+    /// ```ignore
+    /// partition_of("events", PartitionBound::Range(
+    ///     vec!["'2025-01-01'".to_string()],
+    ///     vec!["'2025-02-01'".to_string()],
+    /// ));
+    /// ```
+    pub fn partition_of(&mut self, parent: &str, bound: PartitionBound) -> &mut Self {
+        self.partition_of = Some((parent.to_string(), bound));
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.partition_of.is_some() && !self.columns.is_empty() {
+            return Err(Error::UnsupportedClause {
+                builder: "CreateTableBuilder",
+                field: "columns".to_string(),
+                target: "PARTITION OF".to_string(),
+            });
+        }
+        if self.partition_of.is_none() && self.columns.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "columns".to_string(),
+            });
+        }
+        let primary_keys = self.columns.iter().filter(|v| v.primary_key).count()
+            + self
+                .constraints
+                .iter()
+                .filter(|v| matches!(v.constraint, Constraint::PrimaryKey(_)))
+                .count();
+        if primary_keys > 1 {
+            return Err(Error::DuplicateClause {
+                builder: "CreateTableBuilder",
+                field: "primary_key".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.validate()?;
+        let mut definitions: Vec<String> = Vec::new();
+        for column in &self.columns {
+            definitions.push(column.build()?);
+        }
+        for constraint in &self.constraints {
+            definitions.push(constraint.build()?);
+        }
+        let mut statement = format!(
+            "CREATE TABLE {}{}",
+            if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            Postgres.quote_qualified(&self.table)
+        );
+        if let Some((parent, _)) = &self.partition_of {
+            statement = format!(
+                "{statement} PARTITION OF {}",
+                Postgres.quote_qualified(parent)
+            );
+        }
+        if !definitions.is_empty() {
+            statement = format!("{statement} ({})", definitions.join(", "));
+        }
+        if let Some((_, bound)) = &self.partition_of {
+            statement = format!("{statement} {bound}");
+        }
+        if let Some(partition_by) = &self.partition_by {
+            let (method, columns) = match partition_by {
+                PartitionBy::Range(columns) => ("RANGE", columns),
+                PartitionBy::List(columns) => ("LIST", columns),
+                PartitionBy::Hash(columns) => ("HASH", columns),
+            };
+            if columns.is_empty() {
+                return Err(Error::EmptyField {
+                    builder: "CreateTableBuilder",
+                    field: "partition_by".to_string(),
+                });
+            }
+            statement = format!(
+                "{statement} PARTITION BY {method} ({})",
+                quote_columns(columns)
+            );
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_create_table_builder {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_table_builder() {
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("orders")
+            .if_not_exists()
+            .column(ColumnDefinition {
+                name: "id".to_string(),
+                data_type: DataType::BigInt,
+                identity: Some(Identity::Always),
+                primary_key: true,
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "public_id".to_string(),
+                data_type: DataType::Uuid,
+                not_null: true,
+                default: Some("gen_random_uuid()".to_string()),
+                unique: true,
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "user_id".to_string(),
+                data_type: DataType::BigInt,
+                not_null: true,
+                references: Some(References {
+                    table: "users".to_string(),
+                    columns: vec!["id".to_string()],
+                    on_delete: Some(ReferentialAction::Cascade),
+                    on_update: None,
+                }),
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "total".to_string(),
+                data_type: DataType::Numeric(Some((12, 2))),
+                not_null: true,
+                check: Some("total >= 0".to_string()),
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "tags".to_string(),
+                data_type: DataType::Array(Box::new(DataType::Text)),
+                default: Some("'{}'".to_string()),
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "metadata".to_string(),
+                data_type: DataType::Jsonb,
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "created_at".to_string(),
+                data_type: DataType::Timestamptz,
+                not_null: true,
+                default: Some("now()".to_string()),
+                ..Default::default()
+            })
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "CREATE TABLE IF NOT EXISTS orders (id bigint GENERATED ALWAYS AS IDENTITY PRIMARY KEY, public_id uuid NOT NULL DEFAULT gen_random_uuid() UNIQUE, user_id bigint NOT NULL REFERENCES users (id) ON DELETE CASCADE, total numeric(12, 2) NOT NULL CHECK (total >= 0), tags text[] DEFAULT '{}', metadata jsonb, created_at timestamptz NOT NULL DEFAULT now())"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_table_builder_constraints() {
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("billing.order_items")
+            .column(ColumnDefinition {
+                name: "order_id".to_string(),
+                data_type: DataType::BigInt,
+                not_null: true,
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "line".to_string(),
+                data_type: DataType::Integer,
+                not_null: true,
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "quantity".to_string(),
+                data_type: DataType::Integer,
+                ..Default::default()
+            })
+            .constraint(TableConstraint {
+                name: Some("order_items_pkey".to_string()),
+                constraint: Constraint::PrimaryKey(vec![
+                    "order_id".to_string(),
+                    "line".to_string(),
+                ]),
+            })
+            .constraint(TableConstraint {
+                name: None,
+                constraint: Constraint::ForeignKey(
+                    vec!["order_id".to_string()],
+                    References {
+                        table: "billing.orders".to_string(),
+                        on_delete: Some(ReferentialAction::Restrict),
+                        on_update: Some(ReferentialAction::Cascade),
+                        ..Default::default()
+                    },
+                ),
+            })
+            .constraint(TableConstraint {
+                name: Some("quantity_positive".to_string()),
+                constraint: Constraint::Check("quantity > 0".to_string()),
+            })
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "CREATE TABLE billing.order_items (order_id bigint NOT NULL, line integer NOT NULL, quantity integer, CONSTRAINT order_items_pkey PRIMARY KEY (order_id, line), FOREIGN KEY (order_id) REFERENCES billing.orders ON DELETE RESTRICT ON UPDATE CASCADE, CONSTRAINT quantity_positive CHECK (quantity > 0))"
+        );
+
+        let constraint = TableConstraint {
+            name: Some("unique".to_string()),
+            constraint: Constraint::Unique(vec!["email".to_string()]),
+        };
+        assert_eq!(
+            constraint.build(),
+            Ok("CONSTRAINT \"unique\" UNIQUE (email)".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_table_builder_partitions() {
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("events")
+            .column(ColumnDefinition {
+                name: "id".to_string(),
+                data_type: DataType::BigSerial,
+                ..Default::default()
+            })
+            .column(ColumnDefinition {
+                name: "created_at".to_string(),
+                data_type: DataType::Timestamptz,
+                not_null: true,
+                ..Default::default()
+            })
+            .constraint(TableConstraint {
+                name: None,
+                constraint: Constraint::PrimaryKey(vec![
+                    "id".to_string(),
+                    "created_at".to_string(),
+                ]),
+            })
+            .partition_by(PartitionBy::Range(vec!["created_at".to_string()]))
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE TABLE events (id bigserial, created_at timestamptz NOT NULL, PRIMARY KEY (id, created_at)) PARTITION BY RANGE (created_at)".to_string())
+        );
+
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("events_2025_01")
+            .partition_of(
+                "events",
+                PartitionBound::Range(
+                    vec!["'2025-01-01'".to_string()],
+                    vec!["'2025-02-01'".to_string()],
+                ),
+            )
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE TABLE events_2025_01 PARTITION OF events FOR VALUES FROM ('2025-01-01') TO ('2025-02-01')".to_string())
+        );
+
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("accounts_0")
+            .partition_of("accounts", PartitionBound::Hash(4, 0))
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE TABLE accounts_0 PARTITION OF accounts FOR VALUES WITH (MODULUS 4, REMAINDER 0)".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_table_builder_errors() {
+        let mut builder = CreateTableBuilder::new();
+        assert_eq!(
+            builder.table("users").build(),
+            Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "columns".to_string(),
+            })
+        );
+        let id = ColumnDefinition {
+            name: "id".to_string(),
+            data_type: DataType::BigInt,
+            primary_key: true,
+            ..Default::default()
+        };
+        let result = builder
+            .column(id.clone())
+            .constraint(TableConstraint {
+                name: None,
+                constraint: Constraint::PrimaryKey(vec!["id".to_string()]),
+            })
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::DuplicateClause {
+                builder: "CreateTableBuilder",
+                field: "primary_key".to_string(),
+            })
+        );
+
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("users")
+            .column(ColumnDefinition {
+                identity: Some(Identity::ByDefault),
+                default: Some("0".to_string()),
+                ..id
+            })
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::DuplicateClause {
+                builder: "ColumnDefinition",
+                field: "default".to_string(),
+            })
+        );
+    }
+}
@@ -1,15 +1,20 @@
-use crate::placeholder::PlaceholderKind;
-use crate::postgres::{ExpressionBuilder, WhereBuilder};
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::literal::interpolate;
+use crate::placeholder::{Params, PlaceholderKind, bind_named, check_unnamed};
+use crate::postgres::{ExpressionBuilder, Operator, WhereBuilder};
 use serde_json::Value;
 
 #[derive(Clone, Debug, Default)]
 pub struct DeleteBuilder {
     pub table: String,
+    table_alias: Option<String>,
     pub set: Vec<String>,
     pub values: Vec<Value>,
     using_table: Option<String>,
     filter_statement: Option<String>,
     returning_statement: Option<String>,
+    pub(crate) operators: Vec<Operator>,
     pub placeholder_kind: PlaceholderKind,
 }
 
@@ -22,11 +27,8 @@ impl DeleteBuilder {
     }
 
     pub fn table(&mut self, table: &str, table_alias: Option<&str>) -> &mut Self {
-        self.table = if let Some(alias) = table_alias {
-            format!("{table} as {alias}")
-        } else {
-            table.to_string()
-        };
+        self.table = table.to_string();
+        self.table_alias = table_alias.map(|value| value.to_string());
         self
     }
 
@@ -41,6 +43,9 @@ impl DeleteBuilder {
 
     pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
         if !values.is_empty() {
+            for item in &values {
+                self.operators.append(&mut item.operators.to_owned());
+            }
             let mut result = WhereBuilder::build(values);
             self.filter_statement = Some(result.statement);
             if !result.values.is_empty() {
@@ -68,10 +73,19 @@ impl DeleteBuilder {
         self
     }
 
-    pub fn build(&self) -> anyhow::Result<String> {
-        let mut statement = format!("DELETE FROM {}", self.table);
-        let mut value_counter: usize = 0;
-
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self, dialect: &dyn Dialect) -> Result<String> {
+        if self.returning_statement.is_some() && !dialect.supports_returning() {
+            return Err(Error::UnsupportedClause {
+                builder: "DeleteBuilder",
+                field: "returning".to_string(),
+                target: dialect.name().to_string(),
+            });
+        }
+        let mut statement = format!("DELETE FROM {}", dialect.quote_qualified(&self.table));
+        if let Some(alias) = &self.table_alias {
+            statement.push_str(&format!(" as {alias}"));
+        }
         if let Some(stmt) = &self.using_table {
             statement.push_str(&format!(" {stmt}"));
         };
@@ -81,24 +95,51 @@ impl DeleteBuilder {
         if let Some(stmt) = &self.returning_statement {
             statement.push_str(&format!(" {stmt}"));
         }
+        Ok(statement.trim().to_string())
+    }
 
-        let values: Vec<String> = statement
-            .chars()
-            .map(|c| {
-                if Some(c) == char::from_u32(63) {
-                    match self.placeholder_kind {
-                        PlaceholderKind::QuestionMark => "?".to_string(),
-                        PlaceholderKind::DollarSequential => {
-                            value_counter += 1;
-                            format!("${value_counter}")
-                        }
-                    }
-                } else {
-                    c.to_string()
-                }
-            })
-            .collect();
-        Ok(values.join("").trim().to_string())
+    pub fn build(&self) -> Result<String> {
+        let statement = self.render(&Postgres)?;
+        check_unnamed("DeleteBuilder", &statement)?;
+        match self.placeholder_kind {
+            PlaceholderKind::QuestionMark => Ok(statement),
+            PlaceholderKind::DollarSequential => Ok(Postgres.render_placeholders(&statement)),
+        }
+    }
+
+    /// Renders the statement for the dialect, using its placeholders instead of the
+    /// `placeholder_kind`.
+    pub fn build_with(&self, dialect: &dyn Dialect) -> Result<String> {
+        dialect.check_operators("DeleteBuilder", &self.operators)?;
+        let statement = self.render(dialect)?;
+        check_unnamed("DeleteBuilder", &statement)?;
+        Ok(dialect.render_placeholders(&statement))
+    }
+
+    /// Builds the statement, binding its named parameters (`:name`, `@name`, `$name`)
+    /// from `params`. Returns the statement with the `placeholder_kind` placeholders and
+    /// all the values to bind, in order. Errors when a named parameter has no value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (statement, values) = builder.build_named(&Params::from([("email".to_string(), json!("x"))]))?;
+    /// ```
+    pub fn build_named(&self, params: &Params) -> Result<(String, Vec<Value>)> {
+        let statement = self.render(&Postgres)?;
+        bind_named(
+            "DeleteBuilder",
+            &statement,
+            self.get_values(),
+            params,
+            &self.placeholder_kind,
+        )
+    }
+
+    /// Renders the statement with its values interpolated as escaped literals, for logs
+    /// and `EXPLAIN` copy-paste. Not for execution with untrusted input, see
+    /// [`interpolate`].
+    pub fn build_interpolated(&self) -> Result<String> {
+        interpolate("DeleteBuilder", &self.render(&Postgres)?, &self.get_values())
     }
 }
 
@@ -191,4 +232,36 @@ pub mod test_delete_builder {
         );
         assert_eq!(builder.get_values().len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_delete_build_with() {
+        use crate::dialect::{MySql, Sqlite};
+
+        let mut builder = DeleteBuilder::new(PlaceholderKind::DollarSequential);
+        let _ = builder.table("users", Some("u")).filter(vec![
+            ExpressionBuilder::build(
+                vec![ConditionBuilder {
+                    table_alias: Some("u".to_string()),
+                    field: "settings".to_string(),
+                    operator: Operator::JsonbHasKey,
+                    value: Some(ConditionValue::Single(Value::String("theme".to_string()))),
+                    logic: None,
+                }],
+                None,
+            )
+            .unwrap(),
+        ]);
+        assert!(
+            builder.build_with(&Sqlite).is_err(),
+            "expecting JSONB error"
+        );
+        assert!(builder.build_with(&MySql).is_err(), "expecting JSONB error");
+
+        let mut builder = DeleteBuilder::new(PlaceholderKind::DollarSequential);
+        let _ = builder.table("users", Some("u"));
+        assert_eq!(
+            builder.build_with(&MySql),
+            Ok("DELETE FROM `users` as u".to_string())
+        );
+    }
 }
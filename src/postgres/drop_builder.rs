@@ -0,0 +1,203 @@
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+
+/// The kind of object a [`DropBuilder`] removes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DropObject {
+    Table,
+    Index,
+    View,
+    MaterializedView,
+}
+
+impl std::fmt::Display for DropObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::Table => "TABLE",
+            Self::Index => "INDEX",
+            Self::View => "VIEW",
+            Self::MaterializedView => "MATERIALIZED VIEW",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// Builds `DROP { TABLE | INDEX | VIEW | MATERIALIZED VIEW }` statements.
+///
+/// # Example
+/// ```ignore
+/// let mut builder = DropBuilder::new(DropObject::Table);
+/// builder.name("sessions").if_exists().cascade();
+/// // DROP TABLE IF EXISTS sessions CASCADE
+/// ```
+#[derive(Clone, Debug)]
+pub struct DropBuilder {
+    pub object: DropObject,
+    pub names: Vec<String>,
+    pub if_exists: bool,
+    pub cascade: bool,
+    pub concurrently: bool,
+}
+
+impl DropBuilder {
+    pub fn new(object: DropObject) -> Self {
+        Self {
+            object,
+            names: Vec::new(),
+            if_exists: false,
+            cascade: false,
+            concurrently: false,
+        }
+    }
+
+    /// Adds an object to drop, optionally schema-qualified. Several names are dropped in
+    /// one statement.
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.names.push(name.to_string());
+        self
+    }
+
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Also drops the objects that depend on the dropped ones.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+
+    /// Drops an index without locking out writes on its table, only for [`DropObject::Index`].
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.names.is_empty() || self.names.iter().any(|v| v.is_empty()) {
+            return Err(Error::EmptyField {
+                builder: "DropBuilder",
+                field: "names".to_string(),
+            });
+        }
+        if self.concurrently {
+            if self.object != DropObject::Index {
+                return Err(Error::UnsupportedClause {
+                    builder: "DropBuilder",
+                    field: "concurrently".to_string(),
+                    target: format!("DROP {}", self.object),
+                });
+            }
+            // PostgreSQL only drops one index at a time concurrently, and never with CASCADE
+            if self.names.len() > 1 || self.cascade {
+                return Err(Error::UnsupportedClause {
+                    builder: "DropBuilder",
+                    field: "concurrently".to_string(),
+                    target: "DROP INDEX with several names or CASCADE".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.validate()?;
+        let names = self
+            .names
+            .iter()
+            .map(|name| Postgres.quote_qualified(name))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let mut statement = format!("DROP {}", self.object);
+        if self.concurrently {
+            statement = format!("{statement} CONCURRENTLY");
+        }
+        if self.if_exists {
+            statement = format!("{statement} IF EXISTS");
+        }
+        statement = format!("{statement} {names}");
+        if self.cascade {
+            statement = format!("{statement} CASCADE");
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_drop_builder {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drop_builder() {
+        let mut builder = DropBuilder::new(DropObject::Table);
+        let result = builder
+            .name("sessions")
+            .name("audit.logs")
+            .if_exists()
+            .cascade()
+            .build();
+        assert_eq!(
+            result,
+            Ok("DROP TABLE IF EXISTS sessions, audit.logs CASCADE".to_string())
+        );
+
+        let mut builder = DropBuilder::new(DropObject::Index);
+        let result = builder
+            .name("users_email_idx")
+            .concurrently()
+            .if_exists()
+            .build();
+        assert_eq!(
+            result,
+            Ok("DROP INDEX CONCURRENTLY IF EXISTS users_email_idx".to_string())
+        );
+
+        let mut builder = DropBuilder::new(DropObject::View);
+        assert_eq!(
+            builder.name("active_users").build(),
+            Ok("DROP VIEW active_users".to_string())
+        );
+
+        let mut builder = DropBuilder::new(DropObject::MaterializedView);
+        assert_eq!(
+            builder.name("Daily Totals").if_exists().build(),
+            Ok("DROP MATERIALIZED VIEW IF EXISTS \"Daily Totals\"".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drop_builder_errors() {
+        let mut builder = DropBuilder::new(DropObject::Table);
+        assert_eq!(
+            builder.build(),
+            Err(Error::EmptyField {
+                builder: "DropBuilder",
+                field: "names".to_string(),
+            })
+        );
+        assert_eq!(
+            builder.name("sessions").concurrently().build(),
+            Err(Error::UnsupportedClause {
+                builder: "DropBuilder",
+                field: "concurrently".to_string(),
+                target: "DROP TABLE".to_string(),
+            })
+        );
+
+        let mut builder = DropBuilder::new(DropObject::Index);
+        let result = builder
+            .name("users_email_idx")
+            .concurrently()
+            .cascade()
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "DropBuilder",
+                field: "concurrently".to_string(),
+                target: "DROP INDEX with several names or CASCADE".to_string(),
+            })
+        );
+    }
+}
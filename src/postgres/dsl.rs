@@ -0,0 +1,433 @@
+use crate::postgres::{ConditionBuilder, ConditionValue, Conditions, Operator};
+use serde_json::Value;
+
+/// Starts a condition on `table_alias.field`, an empty `table_alias` leaves the field
+/// unqualified. Values are anything `Into<Value>`, and the [`Conditions`] are chained
+/// with [`and`](Conditions::and) / [`or`](Conditions::or), a single one builds as is.
+///
+/// # Example
+/// ```ignore
+/// let expression = col("t", "age")
+///     .gte(18)
+///     .and(col("t", "name").ilike("%bob%"))
+///     .build()?; // t.age >= ? AND t.name ILIKE ?
+/// builder.filter(vec![expression]);
+/// ```
+pub fn col(table_alias: &str, field: &str) -> Col {
+    Col {
+        table_alias: (!table_alias.is_empty()).then(|| table_alias.to_string()),
+        field: field.to_string(),
+    }
+}
+
+/// A field reference building [`Conditions`], with a method per [`Operator`].
+#[derive(Clone, Debug)]
+pub struct Col {
+    pub table_alias: Option<String>,
+    pub field: String,
+}
+
+impl Col {
+    /// The condition constructor shared with the typed [`Column`](crate::postgres::Column).
+    pub(crate) fn condition(
+        &self,
+        operator: Operator,
+        value: Option<ConditionValue>,
+    ) -> ConditionBuilder {
+        ConditionBuilder {
+            table_alias: self.table_alias.to_owned(),
+            field: self.field.to_owned(),
+            operator,
+            value,
+            logic: None,
+        }
+    }
+
+    fn single(&self, operator: Operator, value: impl Into<Value>) -> Conditions {
+        self.condition(operator, Some(ConditionValue::Single(value.into())))
+            .into()
+    }
+
+    fn list<V: Into<Value>>(
+        &self,
+        operator: Operator,
+        values: impl IntoIterator<Item = V>,
+    ) -> Conditions {
+        let values = values.into_iter().map(Into::into).collect();
+        self.single(operator, Value::Array(values))
+    }
+
+    pub fn eq(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::Eq, value)
+    }
+
+    pub fn neq(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::Neq, value)
+    }
+
+    pub fn gt(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::Gt, value)
+    }
+
+    pub fn gte(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::Gte, value)
+    }
+
+    pub fn lt(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::Lt, value)
+    }
+
+    pub fn lte(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::Lte, value)
+    }
+
+    pub fn like(&self, pattern: impl Into<String>) -> Conditions {
+        self.single(Operator::Like, pattern.into())
+    }
+
+    pub fn ilike(&self, pattern: impl Into<String>) -> Conditions {
+        self.single(Operator::ILike, pattern.into())
+    }
+
+    /// `IN (?)`, the values are bound as an array, expanded by the query helpers.
+    pub fn in_list<V: Into<Value>>(&self, values: impl IntoIterator<Item = V>) -> Conditions {
+        self.list(Operator::In, values)
+    }
+
+    pub fn not_in<V: Into<Value>>(&self, values: impl IntoIterator<Item = V>) -> Conditions {
+        self.list(Operator::NotIn, values)
+    }
+
+    pub fn is_null(&self) -> Conditions {
+        self.condition(Operator::IsNull, None).into()
+    }
+
+    pub fn not_null(&self) -> Conditions {
+        self.condition(Operator::NotNull, None).into()
+    }
+
+    pub fn between(&self, low: impl Into<Value>, high: impl Into<Value>) -> Conditions {
+        self.condition(
+            Operator::Between,
+            Some(ConditionValue::Range(low.into(), high.into())),
+        )
+        .into()
+    }
+
+    /// `->`
+    pub fn jsonb_value(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbValue, value)
+    }
+
+    /// `->>`
+    pub fn jsonb_value_as_text(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbValueAsText, value)
+    }
+
+    /// `@>`
+    pub fn jsonb_contains(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbContains, value)
+    }
+
+    /// `<@`
+    pub fn jsonb_contained(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbContained, value)
+    }
+
+    /// `?`
+    pub fn jsonb_has_key(&self, key: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbHasKey, key)
+    }
+
+    /// `?|`
+    pub fn jsonb_has_any_keys<V: Into<Value>>(
+        &self,
+        keys: impl IntoIterator<Item = V>,
+    ) -> Conditions {
+        self.list(Operator::JsonbHasAnyKeys, keys)
+    }
+
+    /// `?&`
+    pub fn jsonb_has_all_keys<V: Into<Value>>(
+        &self,
+        keys: impl IntoIterator<Item = V>,
+    ) -> Conditions {
+        self.list(Operator::JsonbHasAllKeys, keys)
+    }
+
+    /// `||`
+    pub fn jsonb_concatenate(&self, value: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbConcatenate, value)
+    }
+
+    /// `-`
+    pub fn jsonb_remove_key(&self, key: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbRemoveKey, key)
+    }
+
+    /// `#-`
+    pub fn jsonb_remove_path(&self, path: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbRemovePath, path)
+    }
+
+    /// `@?`
+    pub fn jsonb_has_path(&self, path: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbHasPath, path)
+    }
+
+    /// `@@`
+    pub fn jsonb_path_exists(&self, predicate: impl Into<Value>) -> Conditions {
+        self.single(Operator::JsonbPathExists, predicate)
+    }
+
+    /// Compares with another field, e.g. the `ON` of a join: `o.user_id = u.id`. The
+    /// other field needs a table alias.
+    pub fn eq_col(&self, other: &Col) -> Conditions {
+        self.condition(Operator::Eq, Some(other.reference())).into()
+    }
+
+    /// The field as the value of a condition on another field.
+    pub(crate) fn reference(&self) -> ConditionValue {
+        ConditionValue::Field(
+            self.table_alias.to_owned().unwrap_or_default(),
+            self.field.to_owned(),
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod test_dsl {
+    use super::*;
+    use crate::placeholder::PlaceholderKind;
+    use crate::postgres::{ExpressionBuilder, JoinKind, Logic, SelectBuilder};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_col() {
+        let cases: Vec<(Conditions, &str, Vec<Value>)> = vec![
+            (col("t", "name").eq("bob"), "t.name = ?", vec![json!("bob")]),
+            (col("t", "age").neq(18), "t.age != ?", vec![json!(18)]),
+            (col("t", "age").gt(18), "t.age > ?", vec![json!(18)]),
+            (col("t", "age").gte(18), "t.age >= ?", vec![json!(18)]),
+            (col("t", "score").lt(1.5), "t.score < ?", vec![json!(1.5)]),
+            (col("t", "age").lte(65_u8), "t.age <= ?", vec![json!(65)]),
+            (
+                col("t", "name").like("b%"),
+                "t.name LIKE ?",
+                vec![json!("b%")],
+            ),
+            (
+                col("", "name").ilike("%bob%"),
+                "name ILIKE ?",
+                vec![json!("%bob%")],
+            ),
+            (
+                col("t", "id").in_list([1, 2, 3]),
+                "t.id IN (?)",
+                vec![json!([1, 2, 3])],
+            ),
+            (
+                col("t", "role").not_in(vec!["admin"]),
+                "t.role NOT IN (?)",
+                vec![json!(["admin"])],
+            ),
+            (
+                col("t", "deleted_at").is_null(),
+                "t.deleted_at IS NULL",
+                vec![],
+            ),
+            (col("t", "email").not_null(), "t.email IS NOT NULL", vec![]),
+            (
+                col("t", "age").between(18, 65),
+                "t.age BETWEEN ? AND ?",
+                vec![json!(18), json!(65)],
+            ),
+            (
+                col("t", "data").jsonb_value("a"),
+                "t.data -> ?",
+                vec![json!("a")],
+            ),
+            (
+                col("t", "data").jsonb_value_as_text("a"),
+                "t.data ->> ?",
+                vec![json!("a")],
+            ),
+            (
+                col("t", "data").jsonb_contains(json!({"a": 1})),
+                "t.data @> ?::jsonb",
+                vec![json!({"a": 1})],
+            ),
+            (
+                col("t", "data").jsonb_contained(json!({"a": 1})),
+                "t.data <@ ?::jsonb",
+                vec![json!({"a": 1})],
+            ),
+            (
+                col("t", "data").jsonb_has_key("a"),
+                "t.data ?? ?",
+                vec![json!("a")],
+            ),
+            (
+                col("t", "data").jsonb_has_any_keys(["a", "b"]),
+                "t.data ??| ?::text[]",
+                vec![json!(["a", "b"])],
+            ),
+            (
+                col("t", "data").jsonb_has_all_keys(["a"]),
+                "t.data ??& ?::text[]",
+                vec![json!(["a"])],
+            ),
+            (
+                col("t", "data").jsonb_concatenate(json!({"b": 2})),
+                "t.data || ?::jsonb",
+                vec![json!({"b": 2})],
+            ),
+            (
+                col("t", "data").jsonb_remove_key("a"),
+                "t.data - ?",
+                vec![json!("a")],
+            ),
+            (
+                col("t", "data").jsonb_remove_path("{a,b}"),
+                "t.data #- ?",
+                vec![json!("{a,b}")],
+            ),
+            (
+                col("t", "data").jsonb_has_path("$.a"),
+                "t.data @?? ?",
+                vec![json!("$.a")],
+            ),
+            (
+                col("t", "data").jsonb_path_exists("$.a > 1"),
+                "t.data @@ ?",
+                vec![json!("$.a > 1")],
+            ),
+            (
+                col("o", "user_id").eq_col(&col("u", "id")),
+                "o.user_id = u.id",
+                vec![],
+            ),
+            (
+                col("t", "nickname").eq(None::<String>),
+                "t.nickname = ?",
+                vec![json!(null)],
+            ),
+        ];
+        // A single condition builds as is.
+        for (condition, statement, values) in cases {
+            let expression = condition.build().unwrap();
+            assert_eq!(
+                (expression.condition, expression.values, expression.logic),
+                (statement.to_string(), values, None)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_col_chain() {
+        let expression = col("t", "age")
+            .gte(18)
+            .and(col("t", "name").ilike("%bob%"))
+            .or(col("t", "role").eq("admin"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            expression.condition,
+            "t.age >= ? AND t.name ILIKE ? OR t.role = ?"
+        );
+        assert_eq!(
+            expression.values,
+            vec![json!(18), json!("%bob%"), json!("admin")]
+        );
+        assert_eq!(
+            expression.operators,
+            vec![Operator::Gte, Operator::ILike, Operator::Eq]
+        );
+
+        // The same structures as the literals.
+        let literal = ExpressionBuilder::build(
+            vec![
+                ConditionBuilder {
+                    table_alias: Some("t".to_string()),
+                    field: "age".to_string(),
+                    operator: Operator::Gte,
+                    value: Some(ConditionValue::Single(json!(18))),
+                    logic: None,
+                },
+                ConditionBuilder {
+                    table_alias: Some("t".to_string()),
+                    field: "name".to_string(),
+                    operator: Operator::ILike,
+                    value: Some(ConditionValue::Single(json!("%bob%"))),
+                    logic: Some(Logic::And),
+                },
+            ],
+            None,
+        )
+        .unwrap();
+        let expression = col("t", "age")
+            .gte(18)
+            .and(col("t", "name").ilike("%bob%"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            (expression.condition, expression.values, expression.logic),
+            (literal.condition, literal.values, literal.logic)
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id", "name"])
+            .join(
+                JoinKind::Left,
+                "orders",
+                "o",
+                vec![
+                    ExpressionBuilder::try_from(col("o", "user_id").eq_col(&col("u", "id")))
+                        .unwrap(),
+                ],
+            )
+            .unwrap()
+            .filter(vec![
+                col("u", "active")
+                    .eq(true)
+                    .and(col("u", "age").between(18, 65))
+                    .build()
+                    .unwrap(),
+                ExpressionBuilder {
+                    logic: Some(Logic::Or),
+                    ..col("u", "name")
+                        .ilike("%bob%")
+                        .and(col("o", "id").is_null())
+                        .build()
+                        .unwrap()
+                },
+            ]);
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id, u.name FROM users as u LEFT JOIN orders as o ON o.user_id = u.id WHERE (u.active = $1 AND u.age BETWEEN $2 AND $3) OR (u.name ILIKE $4 AND o.id IS NULL)".to_string())
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![json!(true), json!(18), json!(65), json!("%bob%")]
+        );
+
+        let result = col("t", "age")
+            .between(1, 2)
+            .and(col("t", "age").eq(json!([1])))
+            .build();
+        assert!(
+            result.is_err(),
+            "expecting an array value for `=` to be rejected"
+        );
+
+        // A chain is appended as is, without parentheses.
+        let expression = col("t", "a")
+            .eq(1)
+            .and(col("t", "b").eq(2).or(col("t", "c").eq(3)))
+            .build()
+            .unwrap();
+        assert_eq!(expression.condition, "t.a = ? AND t.b = ? OR t.c = ?");
+    }
+}
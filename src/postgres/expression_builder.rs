@@ -1,4 +1,5 @@
-use crate::postgres::{ConditionBuilder, ConditionValue, Logic};
+use crate::error::{Error, Result};
+use crate::postgres::{ConditionBuilder, Logic, Operator};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -7,13 +8,12 @@ pub struct ExpressionBuilder {
     pub condition: String,
     pub logic: Option<Logic>,
     pub values: Vec<Value>,
+    #[serde(default)]
+    pub operators: Vec<Operator>, // Used to check the operators a dialect supports
 }
 
 impl ExpressionBuilder {
-    pub fn build(
-        values: Vec<ConditionBuilder>,
-        logic: Option<Logic>,
-    ) -> anyhow::Result<ExpressionBuilder> {
+    pub fn build(values: Vec<ConditionBuilder>, logic: Option<Logic>) -> Result<ExpressionBuilder> {
         let mut data: ExpressionBuilder = ExpressionBuilder::default();
         for item in values {
             let condition = ConditionBuilder::build(&item)?;
@@ -22,26 +22,73 @@ impl ExpressionBuilder {
             } else {
                 data.condition = format!("{} {}", data.condition, condition)
             }
-            if let Some(condition_value) = item.value {
-                // Dont use _ => {} in match because we want to make sure that values are push correctly
-                match condition_value {
-                    ConditionValue::Single(value) => {
-                        data.values.push(value);
-                    }
-                    ConditionValue::Range(value1, value2) => {
-                        data.values.push(value1);
-                        data.values.push(value2);
-                    }
-                    ConditionValue::Field(_, _) => {}
-                }
-            }
+            data.values.append(&mut ConditionBuilder::values(&item));
+            data.operators.push(item.operator);
         }
         data.logic = logic;
         Ok(data)
     }
 }
 
+/// Conditions chained with [`ConditionBuilder::and`] and [`ConditionBuilder::or`], built
+/// into a single [`ExpressionBuilder`].
+#[derive(Debug, Clone, Default)]
+pub struct Conditions {
+    pub items: Vec<ConditionBuilder>,
+}
+
+impl Conditions {
+    /// Appends the items of a chain as they are, `a.and(b.or(c))` renders
+    /// `a AND b OR c`; parenthesized groups are separate expressions.
+    fn push(mut self, logic: Logic, items: impl Into<Conditions>) -> Self {
+        let mut items = items.into().items;
+        if let Some(item) = items.first_mut()
+            && !self.items.is_empty()
+        {
+            item.logic = Some(logic);
+        }
+        self.items.append(&mut items);
+        self
+    }
+
+    pub fn and(self, items: impl Into<Conditions>) -> Self {
+        self.push(Logic::And, items)
+    }
+
+    pub fn or(self, items: impl Into<Conditions>) -> Self {
+        self.push(Logic::Or, items)
+    }
+
+    /// Builds the conditions into an expression, validating each of them.
+    pub fn build(self) -> Result<ExpressionBuilder> {
+        ExpressionBuilder::build(self.items, None)
+    }
+}
+
+impl From<ConditionBuilder> for Conditions {
+    fn from(value: ConditionBuilder) -> Self {
+        Self { items: vec![value] }
+    }
+}
+
+impl TryFrom<Conditions> for ExpressionBuilder {
+    type Error = Error;
+
+    fn try_from(value: Conditions) -> Result<Self> {
+        value.build()
+    }
+}
+
+impl TryFrom<ConditionBuilder> for ExpressionBuilder {
+    type Error = Error;
+
+    fn try_from(value: ConditionBuilder) -> Result<Self> {
+        Conditions::from(value).build()
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::len_zero)]
 pub mod test_expression_builder {
     use serde_json::Number;
 
@@ -67,7 +114,7 @@ pub mod test_expression_builder {
             logic: Some(Logic::And),
         };
 
-        let result = ExpressionBuilder::build(vec![condition1, condition2], None);
+        let result = ExpressionBuilder::build(vec![condition1, condition2.clone()], None);
         assert!(result.is_ok(), "{:?}", result.err());
         let result = result.unwrap();
         assert_eq!(
@@ -96,5 +143,31 @@ pub mod test_expression_builder {
         );
         assert_eq!(result.logic, None);
         assert_eq!(result.values.len(), 2);
+
+        let condition1 = ConditionBuilder {
+            table_alias: Some("t".to_string()),
+            field: "myfield3".to_string(),
+            operator: Operator::IsNull,
+            value: Some(ConditionValue::Single(Value::String("test".to_string()))),
+            logic: None,
+        };
+        let result = ExpressionBuilder::build(vec![condition1], None);
+        assert!(
+            result.is_err(),
+            "expecting IS NULL with a value to be rejected"
+        );
+
+        let condition1 = ConditionBuilder {
+            table_alias: Some("t".to_string()),
+            field: "myfield3".to_string(),
+            operator: Operator::IsNull,
+            value: None,
+            logic: None,
+        };
+        let result = ExpressionBuilder::build(vec![condition1, condition2], None);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(result.condition, "t.myfield3 IS NULL AND t.myfield2 = ?");
+        assert_eq!(result.values.len(), 1);
     }
 }
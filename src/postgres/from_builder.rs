@@ -0,0 +1,460 @@
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::placeholder::PlaceholderKind;
+use crate::postgres::SelectBuilder;
+use serde_json::Value;
+
+/// Sampling method used by `TABLESAMPLE`, with the percentage of the table to sample.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableSample {
+    System(f64),    // TABLESAMPLE SYSTEM (n)
+    Bernoulli(f64), // TABLESAMPLE BERNOULLI (n)
+}
+
+impl TableSample {
+    /// Returns an [`Error::InvalidValue`] unless the percentage is between 0 and 100.
+    fn validate(&self) -> Result<()> {
+        let (Self::System(value) | Self::Bernoulli(value)) = self;
+        if !(0.0..=100.0).contains(value) {
+            return Err(Error::InvalidValue {
+                builder: "FromBuilder",
+                field: "tablesample".to_string(),
+                message: format!("the percentage {value} is not between 0 and 100"),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for TableSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::System(value) => write!(f, "TABLESAMPLE SYSTEM ({value})"),
+            Self::Bernoulli(value) => write!(f, "TABLESAMPLE BERNOULLI ({value})"),
+        }
+    }
+}
+
+/// A table, optionally schema-qualified. The schema and table names are quoted when needed,
+/// names with uppercase letters fold to lowercase unless `case_sensitive` is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableSource {
+    pub schema: Option<String>,
+    pub table: String,
+    pub table_alias: String,
+    pub only: bool, // ONLY table, excludes inheriting tables and partitions
+    pub tablesample: Option<TableSample>,
+    pub case_sensitive: bool, // Quotes the names with uppercase letters to keep their case
+}
+
+/// A source that rows can be selected from.
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum FromSource {
+    /// `[ONLY] schema.table as alias [TABLESAMPLE ...]`
+    Table(TableSource),
+    /// `(SELECT ...) as alias(columns)`, the query must use the question mark placeholder kind.
+    Query {
+        query: SelectBuilder,
+        table_alias: String,
+        columns: Vec<String>,
+    },
+    /// `(VALUES (?, ?), (?, ?)) as alias(columns)`
+    Values {
+        rows: Vec<Vec<Value>>,
+        table_alias: String,
+        columns: Vec<String>,
+    },
+    /// A set-returning function such as `generate_series(?, ?)` or `unnest(?::int[])`.
+    /// Each `?` in `function` is bound to the next item of `values`. `columns` may hold
+    /// a column definition list, e.g. `id int` for `jsonb_to_recordset`.
+    Function {
+        function: String,
+        values: Vec<Value>,
+        table_alias: String,
+        columns: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FromBuilder {
+    pub statement: String,
+    pub values: Vec<Value>, //For Binding
+}
+
+impl FromBuilder {
+    fn alias(table_alias: &str, columns: &[String]) -> Result<String> {
+        validate_identifier("FromBuilder", "table_alias", table_alias)?;
+        if columns.is_empty() {
+            Ok(table_alias.to_string())
+        } else {
+            Ok(format!("{table_alias}({})", columns.join(", ")))
+        }
+    }
+
+    fn validate_columns(columns: &[String]) -> Result<()> {
+        for column in columns {
+            validate_identifier("FromBuilder", "columns", column)?;
+        }
+        Ok(())
+    }
+
+    pub fn build(source: FromSource) -> Result<FromBuilder> {
+        Self::build_with(source, &Postgres)
+    }
+
+    /// Builds the source with the table names quoted and the nested query rendered for
+    /// the dialect.
+    pub fn build_with(source: FromSource, dialect: &dyn Dialect) -> Result<FromBuilder> {
+        match source {
+            FromSource::Table(source) => {
+                if source.table.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "FromBuilder",
+                        field: "table".to_string(),
+                    });
+                }
+                let quote = |value: &str| {
+                    if source.case_sensitive {
+                        dialect.quote_exact_identifier(value)
+                    } else {
+                        dialect.quote_identifier(value)
+                    }
+                };
+                let mut statement = quote(&source.table);
+                if let Some(schema) = &source.schema {
+                    if schema.is_empty() {
+                        return Err(Error::EmptyField {
+                            builder: "FromBuilder",
+                            field: "schema".to_string(),
+                        });
+                    }
+                    statement = format!("{}.{statement}", quote(schema));
+                }
+                if source.only {
+                    statement = format!("ONLY {statement}");
+                }
+                statement = format!("{statement} as {}", Self::alias(&source.table_alias, &[])?);
+                if let Some(tablesample) = &source.tablesample {
+                    tablesample.validate()?;
+                    statement = format!("{statement} {tablesample}");
+                }
+                Ok(FromBuilder {
+                    statement,
+                    values: vec![],
+                })
+            }
+            FromSource::Query {
+                query,
+                table_alias,
+                columns,
+            } => {
+                if query.placeholder_kind != PlaceholderKind::QuestionMark {
+                    return Err(Error::PlaceholderKindConflict {
+                        builder: "FromBuilder",
+                        field: table_alias,
+                        expected: PlaceholderKind::QuestionMark,
+                        found: query.placeholder_kind,
+                    });
+                }
+                Self::validate_columns(&columns)?;
+                Ok(FromBuilder {
+                    statement: format!(
+                        "({}) as {}",
+                        query.render(dialect)?,
+                        Self::alias(&table_alias, &columns)?
+                    ),
+                    values: query.get_values(),
+                })
+            }
+            FromSource::Values {
+                rows,
+                table_alias,
+                columns,
+            } => {
+                let Some(first) = rows.first() else {
+                    return Err(Error::EmptyField {
+                        builder: "FromBuilder",
+                        field: "rows".to_string(),
+                    });
+                };
+                let expected = if columns.is_empty() {
+                    first.len()
+                } else {
+                    columns.len()
+                };
+                if expected == 0 {
+                    return Err(Error::EmptyField {
+                        builder: "FromBuilder",
+                        field: "rows".to_string(),
+                    });
+                }
+                Self::validate_columns(&columns)?;
+                let mut values: Vec<Value> = Vec::new();
+                let mut placeholders: Vec<String> = Vec::new();
+                for row in rows {
+                    if row.len() != expected {
+                        return Err(Error::CountMismatch {
+                            builder: "FromBuilder",
+                            field: "rows".to_string(),
+                            expected,
+                            found: row.len(),
+                        });
+                    }
+                    placeholders.push(format!("({})", vec!["?"; row.len()].join(", ")));
+                    values.extend(row);
+                }
+                Ok(FromBuilder {
+                    statement: format!(
+                        "(VALUES {}) as {}",
+                        placeholders.join(", "),
+                        Self::alias(&table_alias, &columns)?
+                    ),
+                    values,
+                })
+            }
+            FromSource::Function {
+                function,
+                values,
+                table_alias,
+                columns,
+            } => {
+                if function.trim().is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "FromBuilder",
+                        field: "function".to_string(),
+                    });
+                }
+                let placeholders = function.matches('?').count();
+                if placeholders != values.len() {
+                    return Err(Error::CountMismatch {
+                        builder: "FromBuilder",
+                        field: "values".to_string(),
+                        expected: placeholders,
+                        found: values.len(),
+                    });
+                }
+                Ok(FromBuilder {
+                    statement: format!("{function} as {}", Self::alias(&table_alias, &columns)?),
+                    values,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_from_builder {
+    use super::*;
+    use crate::postgres::{
+        ConditionBuilder, ConditionValue, ExpressionBuilder, GroupByItem, Operator,
+    };
+    use serde_json::Number;
+
+    #[tokio::test]
+    async fn test_from_builder() {
+        let result = FromBuilder::build(FromSource::Table(TableSource {
+            table: "users".to_string(),
+            table_alias: "u".to_string(),
+            ..Default::default()
+        }));
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap().statement, "users as u");
+
+        let result = FromBuilder::build(FromSource::Table(TableSource {
+            schema: Some("Billing".to_string()),
+            table: "user".to_string(),
+            table_alias: "u".to_string(),
+            ..Default::default()
+        }));
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap().statement, "Billing.\"user\" as u");
+
+        let result = FromBuilder::build(FromSource::Table(TableSource {
+            schema: Some("Billing".to_string()),
+            table: "Invoices".to_string(),
+            table_alias: "i".to_string(),
+            case_sensitive: true,
+            ..Default::default()
+        }));
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap().statement, "\"Billing\".\"Invoices\" as i");
+
+        let result = FromBuilder::build(FromSource::Table(TableSource {
+            schema: Some("public".to_string()),
+            table: "measurements".to_string(),
+            table_alias: "m".to_string(),
+            only: true,
+            tablesample: Some(TableSample::System(10.0)),
+            ..Default::default()
+        }));
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap().statement,
+            "ONLY public.measurements as m TABLESAMPLE SYSTEM (10)"
+        );
+
+        let result = FromBuilder::build(FromSource::Table(TableSource {
+            table: "measurements".to_string(),
+            table_alias: "m".to_string(),
+            tablesample: Some(TableSample::Bernoulli(0.5)),
+            ..Default::default()
+        }));
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap().statement,
+            "measurements as m TABLESAMPLE BERNOULLI (0.5)"
+        );
+
+        for percentage in [f64::NAN, -1.0, 100.5] {
+            let result = FromBuilder::build(FromSource::Table(TableSource {
+                table: "measurements".to_string(),
+                table_alias: "m".to_string(),
+                tablesample: Some(TableSample::System(percentage)),
+                ..Default::default()
+            }));
+            assert_eq!(
+                result.err(),
+                Some(Error::InvalidValue {
+                    builder: "FromBuilder",
+                    field: "tablesample".to_string(),
+                    message: format!("the percentage {percentage} is not between 0 and 100"),
+                })
+            );
+        }
+
+        let mut query = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        query
+            .table("orders", "o")
+            .columns("o", vec!["user_id"])
+            .columns_raw(vec!["count(*)"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: Some("o".to_string()),
+                        field: "quantity".to_string(),
+                        operator: Operator::Gt,
+                        value: Some(ConditionValue::Single(Value::Number(Number::from(1)))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .group_by(vec![GroupByItem {
+                table_alias: Some("o".to_string()),
+                field: "user_id".to_string(),
+                ..Default::default()
+            }])
+            .unwrap();
+        let result = FromBuilder::build(FromSource::Query {
+            query,
+            table_alias: "sub".to_string(),
+            columns: vec!["user_id".to_string(), "total".to_string()],
+        });
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(
+            result.statement,
+            "(SELECT o.user_id, count(*) FROM orders as o WHERE o.quantity > ? GROUP BY o.user_id) as sub(user_id, total)"
+        );
+        assert_eq!(result.values, vec![Value::Number(Number::from(1))]);
+
+        let result = FromBuilder::build(FromSource::Values {
+            rows: vec![
+                vec![
+                    Value::Number(Number::from(1)),
+                    Value::String("Alice".to_string()),
+                ],
+                vec![
+                    Value::Number(Number::from(2)),
+                    Value::String("Bob".to_string()),
+                ],
+            ],
+            table_alias: "v".to_string(),
+            columns: vec!["id".to_string(), "name".to_string()],
+        });
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(result.statement, "(VALUES (?, ?), (?, ?)) as v(id, name)");
+        assert_eq!(result.values.len(), 4);
+
+        let result = FromBuilder::build(FromSource::Function {
+            function: "generate_series(?, ?)".to_string(),
+            values: vec![
+                Value::Number(Number::from(1)),
+                Value::Number(Number::from(10)),
+            ],
+            table_alias: "g".to_string(),
+            columns: vec!["n".to_string()],
+        });
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap().statement, "generate_series(?, ?) as g(n)");
+
+        let result = FromBuilder::build(FromSource::Function {
+            function: "jsonb_to_recordset(?)".to_string(),
+            values: vec![serde_json::json!([{"id": 1, "name": "Alice"}])],
+            table_alias: "r".to_string(),
+            columns: vec!["id int".to_string(), "name text".to_string()],
+        });
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap().statement,
+            "jsonb_to_recordset(?) as r(id int, name text)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_builder_errors() {
+        let result = FromBuilder::build(FromSource::Values {
+            rows: vec![
+                vec![Value::Number(Number::from(1)), Value::Null],
+                vec![Value::Number(Number::from(2))],
+            ],
+            table_alias: "v".to_string(),
+            columns: vec![],
+        });
+        assert_eq!(
+            result,
+            Err(Error::CountMismatch {
+                builder: "FromBuilder",
+                field: "rows".to_string(),
+                expected: 2,
+                found: 1,
+            })
+        );
+
+        let result = FromBuilder::build(FromSource::Values {
+            rows: vec![],
+            table_alias: "v".to_string(),
+            columns: vec![],
+        });
+        assert!(result.is_err(), "expecting empty rows error");
+
+        let result = FromBuilder::build(FromSource::Function {
+            function: "unnest(?::int[])".to_string(),
+            values: vec![],
+            table_alias: "x".to_string(),
+            columns: vec![],
+        });
+        assert_eq!(
+            result,
+            Err(Error::CountMismatch {
+                builder: "FromBuilder",
+                field: "values".to_string(),
+                expected: 1,
+                found: 0,
+            })
+        );
+
+        let mut query = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        query.table("orders", "o").columns("o", vec![]);
+        let result = FromBuilder::build(FromSource::Query {
+            query,
+            table_alias: "sub".to_string(),
+            columns: vec![],
+        });
+        assert!(result.is_err(), "expecting placeholder kind conflict");
+    }
+}
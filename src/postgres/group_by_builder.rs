@@ -1,36 +1,119 @@
-use anyhow::anyhow;
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct GroupByItem {
     pub table_alias: Option<String>,
     pub field: String,
+    pub expression: Option<String>, // e.g. date_trunc('day', t.created_at), used instead of `field`
+}
+
+/// A single element of the GROUP BY clause.
+#[derive(Clone, Debug)]
+pub enum GroupingElement {
+    Item(GroupByItem),
+    Rollup(Vec<GroupByItem>),            // ROLLUP (a, b)
+    Cube(Vec<GroupByItem>),              // CUBE (a, b)
+    GroupingSets(Vec<Vec<GroupByItem>>), // GROUPING SETS ((a, b), (a), ())
 }
 
 #[derive(Clone, Debug)]
 pub struct GroupByBuilder;
 
 impl GroupByBuilder {
-    pub fn build(values: Vec<GroupByItem>) -> anyhow::Result<String> {
+    fn item(item: &GroupByItem) -> Result<String> {
+        if let Some(expression) = &item.expression {
+            if !item.field.is_empty() || item.table_alias.is_some() {
+                return Err(Error::DuplicateClause {
+                    builder: "GroupByBuilder",
+                    field: "expression".to_string(),
+                });
+            }
+            if expression.trim().is_empty() {
+                return Err(Error::EmptyField {
+                    builder: "GroupByBuilder",
+                    field: "expression".to_string(),
+                });
+            }
+            return Ok(expression.to_owned());
+        }
+        let table_alias = if let Some(value) = &item.table_alias {
+            validate_identifier("GroupByBuilder", "table_alias", value)?;
+            &format!("{value}.")
+        } else {
+            ""
+        };
+        if item.field.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "GroupByBuilder",
+                field: "field".to_string(),
+            });
+        }
+        Ok(format!("{}{}", table_alias, item.field))
+    }
+
+    fn items(values: &[GroupByItem], field: &str) -> Result<String> {
+        if values.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "GroupByBuilder",
+                field: field.to_string(),
+            });
+        }
+        let items: Vec<String> = values.iter().map(Self::item).collect::<Result<_>>()?;
+        Ok(items.join(", "))
+    }
+
+    /// Renders the `GROUPING(a, b)` function used in select columns to tell subtotal
+    /// rows produced by ROLLUP, CUBE or GROUPING SETS apart.
+    pub fn grouping(values: &[GroupByItem]) -> Result<String> {
+        Ok(format!("GROUPING({})", Self::items(values, "grouping")?))
+    }
+
+    pub fn build_elements(values: Vec<GroupingElement>) -> Result<String> {
         if values.is_empty() {
-            return Err(anyhow!("group by item is empty"));
+            return Err(Error::EmptyField {
+                builder: "GroupByBuilder",
+                field: "values".to_string(),
+            });
         }
         let mut group_by: Vec<String> = Vec::new();
-        for item in values.into_iter() {
-            let table_alias = if let Some(value) = item.table_alias {
-                &format!("{value}.")
-            } else {
-                ""
+        for element in values.iter() {
+            let value = match element {
+                GroupingElement::Item(item) => Self::item(item)?,
+                GroupingElement::Rollup(items) => {
+                    format!("ROLLUP ({})", Self::items(items, "rollup")?)
+                }
+                GroupingElement::Cube(items) => format!("CUBE ({})", Self::items(items, "cube")?),
+                GroupingElement::GroupingSets(sets) => {
+                    if sets.is_empty() {
+                        return Err(Error::EmptyField {
+                            builder: "GroupByBuilder",
+                            field: "grouping_sets".to_string(),
+                        });
+                    }
+                    let sets: Vec<String> = sets
+                        .iter()
+                        .map(|items| {
+                            if items.is_empty() {
+                                Ok("()".to_string())
+                            } else {
+                                Ok(format!("({})", Self::items(items, "grouping_sets")?))
+                            }
+                        })
+                        .collect::<Result<_>>()?;
+                    format!("GROUPING SETS ({})", sets.join(", "))
+                }
             };
-            if item.field.is_empty() {
-                return Err(anyhow!("group by field is empty"));
-            }
-            let value = format!("{}{}", table_alias, item.field);
             if !group_by.contains(&value) {
                 group_by.push(value);
             }
         }
         Ok(format!("GROUP BY {}", group_by.join(", ").trim()))
     }
+
+    pub fn build(values: Vec<GroupByItem>) -> Result<String> {
+        Self::build_elements(values.into_iter().map(GroupingElement::Item).collect())
+    }
 }
 
 #[cfg(test)]
@@ -42,13 +125,22 @@ pub mod test_group_by_builder {
         let group_by = GroupByItem {
             table_alias: None,
             field: "".to_string(),
+            ..Default::default()
         };
         let result = GroupByBuilder::build(vec![group_by]);
         assert!(result.is_err(), "expected error");
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "GroupByBuilder",
+                field: "field".to_string(),
+            })
+        );
 
         let group_by_items = vec![GroupByItem {
             table_alias: None,
             field: "myfield1".to_string(),
+            ..Default::default()
         }];
         let result = GroupByBuilder::build(group_by_items);
         assert!(result.is_ok(), "{:?}", result.err());
@@ -59,10 +151,12 @@ pub mod test_group_by_builder {
             GroupByItem {
                 table_alias: Some("t".to_string()),
                 field: "myfield1".to_string(),
+                ..Default::default()
             },
             GroupByItem {
                 table_alias: Some("t".to_string()),
                 field: "myfield2".to_string(),
+                ..Default::default()
             },
         ];
         let result = GroupByBuilder::build(group_by_items);
@@ -70,4 +164,96 @@ pub mod test_group_by_builder {
         let result = result.unwrap();
         assert_eq!(result, "GROUP BY t.myfield1, t.myfield2");
     }
+
+    #[tokio::test]
+    async fn test_group_by_builder_expressions() {
+        let group_by_items = vec![
+            GroupByItem {
+                expression: Some("date_trunc('day', t.created_at)".to_string()),
+                ..Default::default()
+            },
+            GroupByItem {
+                table_alias: Some("t".to_string()),
+                field: "region".to_string(),
+                ..Default::default()
+            },
+        ];
+        let result = GroupByBuilder::build(group_by_items);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "GROUP BY date_trunc('day', t.created_at), t.region"
+        );
+
+        let result = GroupByBuilder::build(vec![GroupByItem {
+            field: "region".to_string(),
+            expression: Some("lower(region)".to_string()),
+            ..Default::default()
+        }]);
+        assert_eq!(
+            result,
+            Err(Error::DuplicateClause {
+                builder: "GroupByBuilder",
+                field: "expression".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_group_by_builder_grouping_elements() {
+        let region = GroupByItem {
+            table_alias: Some("t".to_string()),
+            field: "region".to_string(),
+            ..Default::default()
+        };
+        let country = GroupByItem {
+            table_alias: Some("t".to_string()),
+            field: "country".to_string(),
+            ..Default::default()
+        };
+
+        let result = GroupByBuilder::build_elements(vec![GroupingElement::Rollup(vec![
+            region.clone(),
+            country.clone(),
+        ])]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "GROUP BY ROLLUP (t.region, t.country)");
+
+        let result = GroupByBuilder::build_elements(vec![
+            GroupingElement::Item(GroupByItem {
+                expression: Some("date_trunc('day', t.created_at)".to_string()),
+                ..Default::default()
+            }),
+            GroupingElement::Cube(vec![region.clone(), country.clone()]),
+        ]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "GROUP BY date_trunc('day', t.created_at), CUBE (t.region, t.country)"
+        );
+
+        let result = GroupByBuilder::build_elements(vec![GroupingElement::GroupingSets(vec![
+            vec![region.clone(), country.clone()],
+            vec![region.clone()],
+            vec![],
+        ])]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "GROUP BY GROUPING SETS ((t.region, t.country), (t.region), ())"
+        );
+
+        let result = GroupByBuilder::build_elements(vec![GroupingElement::Rollup(vec![])]);
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "GroupByBuilder",
+                field: "rollup".to_string(),
+            })
+        );
+
+        let result = GroupByBuilder::grouping(&[region, country]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "GROUPING(t.region, t.country)");
+    }
 }
@@ -1,5 +1,9 @@
+use crate::dialect::{Dialect, Postgres, Upsert};
+use crate::error::{Error, Result};
+use crate::literal::interpolate;
 use crate::placeholder::PlaceholderKind;
-use anyhow::anyhow;
+use crate::table::Table;
+use crate::value::{SqlType, SqlValue};
 use serde_json::Value;
 
 #[derive(Clone, Debug, Default)]
@@ -8,7 +12,9 @@ pub struct InsertBuilder {
     pub fields: Vec<String>,
     pub values: Vec<Vec<Value>>,
     pub returning_statement: Option<String>,
+    pub upsert: Option<Upsert>,
     pub placeholder_kind: PlaceholderKind,
+    casts: Vec<Vec<Option<SqlType>>>, // The placeholder casts of the typed values, by row
 }
 
 impl InsertBuilder {
@@ -29,14 +35,64 @@ impl InsertBuilder {
         self
     }
 
-    pub fn values(&mut self, values: Vec<Value>) -> anyhow::Result<&mut Self> {
+    pub fn values(&mut self, values: Vec<Value>) -> Result<&mut Self> {
         if self.fields.len() != values.len() {
-            return Err(anyhow!("mistched number of fields and values"));
+            return Err(Error::CountMismatch {
+                builder: "InsertBuilder",
+                field: "values".to_string(),
+                expected: self.fields.len(),
+                found: values.len(),
+            });
         }
+        self.casts.push(vec![None; values.len()]);
         self.values.append(&mut vec![values]);
         Ok(self)
     }
 
+    /// Adds a row of typed values, casting their placeholders when needed, see [`SqlValue`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// values_typed(vec![SqlValue::from(user_id), SqlValue::from(Utc::now())]);
+    /// // INSERT INTO sessions(user_id, created_at) VALUES ($1::uuid, $2::timestamptz)
+    /// ```
+    pub fn values_typed(&mut self, values: Vec<SqlValue>) -> Result<&mut Self> {
+        let casts = values.iter().map(SqlValue::placeholder_cast).collect();
+        self.values(
+            values
+                .iter()
+                .map(SqlValue::to_json)
+                .collect::<Result<Vec<Value>>>()?,
+        )?;
+        if let Some(last) = self.casts.last_mut() {
+            *last = casts;
+        }
+        Ok(self)
+    }
+
+    /// Builds the insert of `rows` into their [`Table`], with its columns and one row of
+    /// typed values per item. Errors when `rows` is empty.
+    ///
+    /// # Example
+    /// ```ignore
+    /// InsertBuilder::from_rows(&[alice, bob])?.returning(vec!["id"]);
+    /// // INSERT INTO "user"(name, email) VALUES (?, ?), (?, ?) RETURNING id
+    /// ```
+    pub fn from_rows<T: Table>(rows: &[T]) -> Result<Self> {
+        if rows.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "InsertBuilder",
+                field: "rows".to_string(),
+            });
+        }
+        let mut builder = Self::default();
+        builder.table(T::table_name()).columns(T::columns());
+        for row in rows {
+            builder.values_typed(row.values()?)?;
+        }
+        Ok(builder)
+    }
+
     pub fn get_values(&self) -> Vec<Vec<Value>> {
         self.values.to_owned()
     }
@@ -54,36 +110,88 @@ impl InsertBuilder {
         }
         self
     }
-    pub fn build(&self) -> anyhow::Result<String> {
-        let fields = self.fields.join(", ");
-        let mut value_counter: usize = 0;
+
+    /// Adds an upsert clause, rendered as `ON CONFLICT` or `ON DUPLICATE KEY UPDATE`
+    /// depending on the dialect.
+    ///
+    /// # Example
+    /// ```ignore
+    /// on_conflict(Upsert::DoUpdate(vec!["email".to_string()], vec!["name".to_string()]));
+    /// ```
+    pub fn on_conflict(&mut self, value: Upsert) -> &mut Self {
+        self.upsert = Some(value);
+        self
+    }
+
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self, dialect: &dyn Dialect) -> Result<String> {
+        if self.returning_statement.is_some() && !dialect.supports_returning() {
+            return Err(Error::UnsupportedClause {
+                builder: "InsertBuilder",
+                field: "returning".to_string(),
+                target: dialect.name().to_string(),
+            });
+        }
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|field| dialect.quote_identifier(field))
+            .collect();
         let values: Vec<String> = self
             .values
             .iter()
-            .map(|items| {
-                let placeholders: Vec<String> = items
-                    .iter()
-                    .map(|_| match self.placeholder_kind {
-                        PlaceholderKind::QuestionMark => "?".to_string(),
-                        PlaceholderKind::DollarSequential => {
-                            value_counter += 1;
-                            format!("${value_counter}")
-                        }
-                    })
+            .enumerate()
+            .map(|(row, items)| {
+                let placeholders: Vec<String> = (0..items.len())
+                    .map(
+                        |column| match self.casts.get(row).and_then(|casts| casts.get(column)) {
+                            Some(Some(cast)) => format!("?::{cast}"),
+                            _ => "?".to_string(),
+                        },
+                    )
                     .collect();
                 format!("({})", placeholders.join(", "))
             })
             .collect();
-        let values: String = values.join(", ");
-        let returning_statement: String = self
-            .returning_statement
-            .to_owned()
-            .unwrap_or("".to_string());
-        let statement = format!(
-            "INSERT INTO {}({}) VALUES {} {}",
-            self.table, fields, values, returning_statement
+        let mut statement = format!(
+            "INSERT INTO {}({}) VALUES {}",
+            dialect.quote_qualified(&self.table),
+            fields.join(", "),
+            values.join(", ")
         );
-        Ok(statement.trim().to_string())
+        if let Some(upsert) = &self.upsert {
+            statement = format!("{statement} {}", dialect.upsert(upsert)?);
+        }
+        if let Some(returning) = &self.returning_statement {
+            statement = format!("{statement} {returning}");
+        }
+        Ok(statement)
+    }
+
+    pub fn build(&self) -> Result<String> {
+        let statement = self.render(&Postgres)?;
+        match self.placeholder_kind {
+            PlaceholderKind::QuestionMark => Ok(statement),
+            PlaceholderKind::DollarSequential => Ok(Postgres.render_placeholders(&statement)),
+        }
+    }
+
+    /// Renders the statement for the dialect, using its placeholders instead of the
+    /// `placeholder_kind`.
+    pub fn build_with(&self, dialect: &dyn Dialect) -> Result<String> {
+        let statement = self.render(dialect)?;
+        Ok(dialect.render_placeholders(&statement))
+    }
+
+    /// Renders the statement with its values interpolated as escaped literals, for logs
+    /// and `EXPLAIN` copy-paste. Not for execution with untrusted input, see
+    /// [`interpolate`].
+    pub fn build_interpolated(&self) -> Result<String> {
+        interpolate(
+            "InsertBuilder",
+            &self.render(&Postgres)?,
+            &self.get_values().concat(),
+        )
     }
 }
 
@@ -133,4 +241,177 @@ pub mod test_insert_builder {
                 .to_string()
         );
     }
+
+    #[tokio::test]
+    async fn test_insert_mismatched_values() {
+        let mut builder = InsertBuilder::new(PlaceholderKind::QuestionMark);
+        let _ = builder.table("users").columns(vec!["name", "email"]);
+        let result = builder
+            .values(vec![Value::String("Juan dela Cruz".to_string())])
+            .map(|_| ());
+        assert_eq!(
+            result,
+            Err(Error::CountMismatch {
+                builder: "InsertBuilder",
+                field: "values".to_string(),
+                expected: 2,
+                found: 1,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_build_with() {
+        use crate::dialect::{MySql, Sqlite};
+
+        let mut builder = InsertBuilder::new(PlaceholderKind::DollarSequential);
+        let _ = builder
+            .table("users")
+            .columns(vec!["name", "email"])
+            .values(vec![
+                Value::String("Juan dela Cruz".to_string()),
+                Value::String("jdc@test.com".to_string()),
+            ])
+            .unwrap()
+            .on_conflict(Upsert::DoUpdate(
+                vec!["email".to_string()],
+                vec!["name".to_string()],
+            ));
+        assert_eq!(
+            builder.build(),
+            Ok("INSERT INTO users(name, email) VALUES ($1, $2) ON CONFLICT (email) DO UPDATE SET name = excluded.name".to_string())
+        );
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Ok("INSERT INTO users(name, email) VALUES (?, ?) ON CONFLICT (email) DO UPDATE SET name = excluded.name".to_string())
+        );
+        assert_eq!(
+            builder.build_with(&MySql),
+            Ok("INSERT INTO `users`(`name`, `email`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)".to_string())
+        );
+
+        let result = builder.returning(vec!["id"]).build_with(&MySql);
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "InsertBuilder",
+                field: "returning".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_build_interpolated() {
+        use serde_json::json;
+
+        let mut builder = InsertBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users")
+            .columns(vec!["name", "tags", "profile", "active"])
+            .values(vec![
+                json!("O'Brien"),
+                json!(["a", "b"]),
+                json!({"bio": "it's me"}),
+                json!(true),
+            ])
+            .unwrap()
+            .values(vec![
+                json!(r"C:\path"),
+                json!([]),
+                json!(null),
+                json!(false),
+            ])
+            .unwrap();
+        assert_eq!(
+            builder.build_interpolated(),
+            Ok(r#"INSERT INTO users(name, tags, profile, active) VALUES ('O''Brien', ARRAY['a', 'b'], '{"bio":"it''s me"}'::jsonb, TRUE), (E'C:\\path', '{}', NULL, FALSE)"#.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_values_typed() {
+        use chrono::{DateTime, Utc};
+        use serde_json::json;
+        use uuid::Uuid;
+
+        let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let created_at: DateTime<Utc> = "2025-03-01T08:30:00Z".parse().unwrap();
+        let mut builder = InsertBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("sessions")
+            .columns(vec!["id", "user_id", "created_at", "scopes"])
+            .values_typed(vec![
+                SqlValue::from(id),
+                SqlValue::from(42_i64),
+                SqlValue::from(created_at),
+                SqlValue::from(vec!["read".to_string()]),
+            ])
+            .unwrap()
+            .values(vec![json!(null), json!(7), json!(null), json!(null)])
+            .unwrap();
+        assert_eq!(
+            builder.build(),
+            Ok("INSERT INTO sessions(id, user_id, created_at, scopes) VALUES ($1::uuid, $2, $3::timestamptz, $4::text[]), ($5, $6, $7, $8)".to_string())
+        );
+        assert_eq!(
+            builder.get_values()[0],
+            vec![
+                json!("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                json!(42),
+                json!("2025-03-01T08:30:00Z"),
+                json!(["read"]),
+            ]
+        );
+        assert_eq!(
+            builder.build_interpolated(),
+            Ok("INSERT INTO sessions(id, user_id, created_at, scopes) VALUES ('67e55044-10b1-426f-9247-bb680e5fe0c8'::uuid, 42, '2025-03-01T08:30:00Z'::timestamptz, ARRAY['read']::text[]), (NULL, 7, NULL, NULL)".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_from_rows() {
+        use crate::table::test_table::{Profile, User};
+        use serde_json::json;
+
+        let users: Vec<User> = ["Juan", "Jose"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| User {
+                id: index as i64 + 1,
+                name: name.to_string(),
+                email: format!("{}@test.com", name.to_lowercase()),
+                nickname: None,
+                profile: Profile {
+                    city: "Manila".to_string(),
+                },
+                order_count: 0,
+            })
+            .collect();
+        let mut builder = InsertBuilder::from_rows(&users).unwrap();
+        builder.returning(vec!["id"]);
+        assert_eq!(
+            builder.build(),
+            Ok("INSERT INTO \"user\"(id, name, email_address, nickname, profile) VALUES (?, ?, ?, ?, ?::jsonb), (?, ?, ?, ?, ?::jsonb) RETURNING id".to_string())
+        );
+        assert_eq!(
+            builder.get_values()[1],
+            vec![
+                json!(2),
+                json!("Jose"),
+                json!("jose@test.com"),
+                json!(null),
+                json!({"city": "Manila"}),
+            ]
+        );
+
+        let result = InsertBuilder::from_rows::<User>(&[]).map(|_| ());
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "InsertBuilder",
+                field: "rows".to_string(),
+            })
+        );
+    }
 }
@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::{ExpressionBuilder, Logic};
+use super::{ExpressionBuilder, Logic, SelectBuilder};
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::placeholder::PlaceholderKind;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum JoinKind {
     Inner,
     Left,
@@ -44,13 +48,33 @@ impl JoinBuilder {
         }
     }
 
-    pub fn build(
-        kind: JoinKind,
-        table: &str,
-        table_alias: &str,
+    /// Renders the ON conditions. An empty list is only accepted when `always` is set,
+    /// the true literal of the dialect rendered as `ON TRUE` by lateral joins.
+    fn on(
+        data: &mut JoinBuilder,
+        kind: &JoinKind,
         values: Vec<ExpressionBuilder>,
-    ) -> JoinBuilder {
-        let mut data: JoinBuilder = JoinBuilder::default();
+        always: Option<&str>,
+    ) -> Result<Option<String>> {
+        if kind == &JoinKind::Cross {
+            if !values.is_empty() {
+                return Err(Error::UnsupportedClause {
+                    builder: "JoinBuilder",
+                    field: "on".to_string(),
+                    target: "CROSS JOIN".to_string(),
+                });
+            }
+            return Ok(None);
+        }
+        if values.is_empty() {
+            if let Some(value) = always {
+                return Ok(Some(format!("ON {value}")));
+            }
+            return Err(Error::EmptyField {
+                builder: "JoinBuilder",
+                field: "values".to_string(),
+            });
+        }
         let mut expressions: Vec<String> = Vec::new();
         let do_grouping = values.len() > 1;
         for mut item in values {
@@ -60,14 +84,128 @@ impl JoinBuilder {
             }
             expressions.push(expression);
         }
-        data.statement = format!(
-            "{} JOIN {} as {} ON {}",
+        Ok(Some(format!("ON {}", expressions.join(" ").trim())))
+    }
+
+    fn statement(kind: &JoinKind, source: String, constraint: Option<String>) -> String {
+        if let Some(constraint) = constraint {
+            format!("{kind} JOIN {source} {constraint}")
+        } else {
+            format!("{kind} JOIN {source}")
+        }
+    }
+
+    /// Builds a `JOIN ... ON ...` clause. `JoinKind::Cross` takes no conditions.
+    pub fn build(
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<JoinBuilder> {
+        let mut data: JoinBuilder = JoinBuilder::default();
+        let constraint = Self::on(&mut data, &kind, values, None)?;
+        data.statement = Self::statement(&kind, format!("{table} as {table_alias}"), constraint);
+        Ok(data)
+    }
+
+    /// Builds a `JOIN ... USING (col, ...)` clause.
+    ///
+    /// # Example
+    /// ```ignore
+    /// JoinBuilder::build_using(JoinKind::Inner, "products", "p", vec!["product_id"]);
+    /// ```
+    pub fn build_using(
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        columns: Vec<&str>,
+    ) -> Result<JoinBuilder> {
+        if kind == JoinKind::Cross {
+            return Err(Error::UnsupportedClause {
+                builder: "JoinBuilder",
+                field: "using".to_string(),
+                target: "CROSS JOIN".to_string(),
+            });
+        }
+        if columns.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "JoinBuilder",
+                field: "using".to_string(),
+            });
+        }
+        for column in &columns {
+            validate_identifier("JoinBuilder", "using", column)?;
+        }
+        Ok(JoinBuilder {
+            statement: Self::statement(
+                &kind,
+                format!("{table} as {table_alias}"),
+                Some(format!("USING ({})", columns.join(", "))),
+            ),
+            values: vec![],
+        })
+    }
+
+    /// Builds a `NATURAL ... JOIN` clause, which joins on all columns sharing a name.
+    pub fn build_natural(kind: JoinKind, table: &str, table_alias: &str) -> Result<JoinBuilder> {
+        if kind == JoinKind::Cross {
+            return Err(Error::UnsupportedClause {
+                builder: "JoinBuilder",
+                field: "natural".to_string(),
+                target: "CROSS JOIN".to_string(),
+            });
+        }
+        Ok(JoinBuilder {
+            statement: format!("NATURAL {kind} JOIN {table} as {table_alias}"),
+            values: vec![],
+        })
+    }
+
+    /// Builds a `JOIN LATERAL (subquery) as alias ON ...` clause. Without conditions it
+    /// renders `ON TRUE`, or nothing for `JoinKind::Cross`.
+    /// The subquery values come before the ON condition values.
+    ///
+    /// # Example
+    /// ```ignore
+    /// JoinBuilder::build_lateral(JoinKind::Left, &latest_orders, "lo", vec![]);
+    /// ```
+    pub fn build_lateral(
+        kind: JoinKind,
+        query: &SelectBuilder,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<JoinBuilder> {
+        Self::render_lateral(kind, query, table_alias, values, &Postgres)
+    }
+
+    /// Builds the lateral join for the dialect, see [`build_lateral`](Self::build_lateral).
+    pub(crate) fn render_lateral(
+        kind: JoinKind,
+        query: &SelectBuilder,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+        dialect: &dyn Dialect,
+    ) -> Result<JoinBuilder> {
+        if query.placeholder_kind != PlaceholderKind::QuestionMark {
+            return Err(Error::PlaceholderKindConflict {
+                builder: "JoinBuilder",
+                field: table_alias.to_string(),
+                expected: PlaceholderKind::QuestionMark,
+                found: query.placeholder_kind.to_owned(),
+            });
+        }
+        let mut data: JoinBuilder = JoinBuilder {
+            statement: String::new(),
+            values: query.get_values(),
+        };
+        let subquery = query.render(dialect)?;
+        let constraint = Self::on(&mut data, &kind, values, Some(dialect.boolean(true)))?;
+        data.statement = Self::statement(
             &kind,
-            table,
-            table_alias,
-            expressions.join(" ").trim()
+            format!("LATERAL ({subquery}) as {table_alias}"),
+            constraint,
         );
-        data
+        Ok(data)
     }
 }
 
@@ -95,7 +233,8 @@ pub mod test_join_builder {
         let expression1 = ExpressionBuilder::build(vec![condition1], None);
         assert!(expression1.is_ok(), "{:?}", expression1.err());
         let expression1 = expression1.unwrap();
-        let result = JoinBuilder::build(JoinKind::Left, "products", "p", vec![expression1]);
+        let result =
+            JoinBuilder::build(JoinKind::Left, "products", "p", vec![expression1]).unwrap();
         assert_eq!(
             result.statement,
             "LEFT JOIN products as p ON p.id = o.product_id"
@@ -126,7 +265,8 @@ pub mod test_join_builder {
         let expression1 = ExpressionBuilder::build(vec![condition1, condition2], None);
         assert!(expression1.is_ok(), "{:?}", expression1.err());
         let expression1 = expression1.unwrap();
-        let result = JoinBuilder::build(JoinKind::Left, "products", "p", vec![expression1]);
+        let result =
+            JoinBuilder::build(JoinKind::Left, "products", "p", vec![expression1]).unwrap();
         assert_eq!(
             result.statement,
             "LEFT JOIN products as p ON p.id = o.product_id AND p.user_id = ?"
@@ -166,11 +306,145 @@ pub mod test_join_builder {
             "products",
             "p",
             vec![expression1, expression2],
-        );
+        )
+        .unwrap();
         assert_eq!(
             result.statement,
             "LEFT JOIN products as p ON (p.id = o.product_id AND p.user_id = ?) AND (p.id = o.product_id AND p.user_id = ?)"
         );
         assert_eq!(result.values.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_join_cross_using_natural() {
+        let result = JoinBuilder::build(JoinKind::Cross, "products", "p", vec![]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap().statement, "CROSS JOIN products as p");
+
+        let condition1 = ConditionBuilder {
+            table_alias: Some("p".to_string()),
+            field: "id".to_string(),
+            operator: Operator::Eq,
+            value: Some(ConditionValue::Field(
+                "o".to_string(),
+                "product_id".to_string(),
+            )),
+            logic: None,
+        };
+        let expression1 = ExpressionBuilder::build(vec![condition1], None).unwrap();
+        let result = JoinBuilder::build(JoinKind::Cross, "products", "p", vec![expression1]);
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "JoinBuilder",
+                field: "on".to_string(),
+                target: "CROSS JOIN".to_string(),
+            })
+        );
+
+        let result = JoinBuilder::build(JoinKind::Left, "products", "p", vec![]);
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "JoinBuilder",
+                field: "values".to_string(),
+            })
+        );
+
+        let result =
+            JoinBuilder::build_using(JoinKind::Inner, "products", "p", vec!["product_id", "sku"]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap().statement,
+            "INNER JOIN products as p USING (product_id, sku)"
+        );
+
+        let result = JoinBuilder::build_using(JoinKind::Inner, "products", "p", vec![]);
+        assert!(result.is_err(), "expecting empty USING error");
+
+        let result = JoinBuilder::build_natural(JoinKind::Left, "products", "p");
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap().statement, "NATURAL LEFT JOIN products as p");
+
+        let result = JoinBuilder::build_natural(JoinKind::Cross, "products", "p");
+        assert!(result.is_err(), "expecting NATURAL CROSS JOIN error");
+    }
+
+    #[tokio::test]
+    async fn test_join_lateral() {
+        let mut query = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        query
+            .table("orders", "o")
+            .columns("o", vec!["id", "quantity"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        ConditionBuilder {
+                            table_alias: Some("o".to_string()),
+                            field: "user_id".to_string(),
+                            operator: Operator::Eq,
+                            value: Some(ConditionValue::Field("u".to_string(), "id".to_string())),
+                            logic: None,
+                        },
+                        ConditionBuilder {
+                            table_alias: Some("o".to_string()),
+                            field: "quantity".to_string(),
+                            operator: Operator::Gt,
+                            value: Some(ConditionValue::Single(Value::Number(Number::from(1)))),
+                            logic: Some(Logic::And),
+                        },
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .limit(3);
+
+        let result = JoinBuilder::build_lateral(JoinKind::Left, &query, "lo", vec![]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(
+            result.statement,
+            "LEFT JOIN LATERAL (SELECT o.id, o.quantity FROM orders as o WHERE o.user_id = u.id AND o.quantity > ? LIMIT 3) as lo ON TRUE"
+        );
+        assert_eq!(result.values, vec![Value::Number(Number::from(1))]);
+
+        let on = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("lo".to_string()),
+                field: "quantity".to_string(),
+                operator: Operator::Lt,
+                value: Some(ConditionValue::Single(Value::Number(Number::from(10)))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let result = JoinBuilder::build_lateral(JoinKind::Inner, &query, "lo", vec![on]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(
+            result.statement,
+            "INNER JOIN LATERAL (SELECT o.id, o.quantity FROM orders as o WHERE o.user_id = u.id AND o.quantity > ? LIMIT 3) as lo ON lo.quantity < ?"
+        );
+        assert_eq!(
+            result.values,
+            vec![
+                Value::Number(Number::from(1)),
+                Value::Number(Number::from(10))
+            ]
+        );
+
+        let result = JoinBuilder::build_lateral(JoinKind::Cross, &query, "lo", vec![]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap().statement,
+            "CROSS JOIN LATERAL (SELECT o.id, o.quantity FROM orders as o WHERE o.user_id = u.id AND o.quantity > ? LIMIT 3) as lo"
+        );
+
+        let mut query = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        query.table("orders", "o").columns("o", vec![]);
+        let result = JoinBuilder::build_lateral(JoinKind::Left, &query, "lo", vec![]);
+        assert!(result.is_err(), "expecting placeholder kind conflict");
+    }
 }
@@ -1,6 +1,13 @@
+pub mod alter_table_builder;
+pub mod column;
 pub mod condition_builder;
+pub mod create_index_builder;
+pub mod create_table_builder;
 pub mod delete_builder;
+pub mod drop_builder;
+pub mod dsl;
 pub mod expression_builder;
+pub mod from_builder;
 pub mod group_by_builder;
 pub mod insert_builder;
 pub mod join_builder;
@@ -9,19 +16,38 @@ pub mod operator;
 pub mod order_by_builder;
 pub mod select_builder;
 pub mod set_builder;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
 pub mod table_columns_builder;
+#[cfg(feature = "tokio-postgres")]
+pub mod tokio_postgres;
 pub mod update_builder;
+pub mod view_builder;
 pub mod where_builder;
 
+pub use alter_table_builder::{AlterAction, AlterTableBuilder};
+pub use column::{ArrayColumnType, Column, ColumnType, ScalarColumnType};
 pub use condition_builder::{ConditionBuilder, ConditionValue};
-pub use expression_builder::ExpressionBuilder;
-pub use group_by_builder::{GroupByBuilder, GroupByItem};
+pub use create_index_builder::{CreateIndexBuilder, IndexKey, IndexMethod};
+pub use create_table_builder::{
+    ColumnDefinition, Constraint, CreateTableBuilder, DataType, Identity, PartitionBound,
+    PartitionBy, ReferentialAction, References, TableConstraint,
+};
+pub use dsl::{Col, col};
+pub use expression_builder::{Conditions, ExpressionBuilder};
+pub use from_builder::{FromBuilder, FromSource, TableSample, TableSource};
+pub use group_by_builder::{GroupByBuilder, GroupByItem, GroupingElement};
 pub use join_builder::{JoinBuilder, JoinKind};
 pub use logic::Logic;
 pub use operator::Operator;
-pub use order_by_builder::{OrderByBuilder, OrderByItem, Sequence};
-pub use select_builder::SelectBuilder;
+pub use order_by_builder::{Nulls, OrderByBuilder, OrderByExpression, OrderByItem, Sequence};
+pub use select_builder::{FetchKind, SelectBuilder};
 pub use set_builder::{SetBuilder, SetFieldUpdate, SetValue};
+#[cfg(feature = "sqlx")]
+pub use sqlx::SqlxQuery;
 pub use table_columns_builder::TableColumnsBuilder;
+#[cfg(feature = "tokio-postgres")]
+pub use tokio_postgres::{QueryError, StatementCache, TokioPostgresQuery};
 pub use update_builder::UpdateBuilder;
+pub use view_builder::{CreateViewBuilder, RefreshViewBuilder};
 pub use where_builder::WhereBuilder;
@@ -14,7 +14,8 @@ pub enum Operator {
     Lte, // Less Than or Equal (<=)
 
     // Pattern Matching
-    Like, // Case-sensitive pattern match (LIKE)
+    Like,  // Case-sensitive pattern match (LIKE)
+    ILike, // Case-insensitive pattern match (ILIKE), PostgreSQL only
 
     // List/Array Operations
     In,    // Value is in a list of items (IN)
@@ -52,6 +53,7 @@ impl std::fmt::Display for Operator {
             Self::Lt => "<",
             Self::Lte => "<=",
             Self::Like => "LIKE",
+            Self::ILike => "ILIKE",
             Self::In => "IN",
             Self::NotIn => "NOT IN",
             Self::IsNull => "IS NULL",
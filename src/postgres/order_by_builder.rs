@@ -1,60 +1,191 @@
-use anyhow::anyhow;
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::postgres::ConditionBuilder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Sequence {
+    #[default]
     Asc,
     Desc,
+    Using(String), // USING <operator>, e.g. USING >
 }
 
 impl std::fmt::Display for Sequence {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let sequence = match self {
-            Self::Asc => "ASC",
-            Self::Desc => "DESC",
+        match self {
+            Self::Asc => write!(f, "ASC"),
+            Self::Desc => write!(f, "DESC"),
+            Self::Using(operator) => write!(f, "USING {operator}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Nulls {
+    First, // NULLS FIRST
+    Last,  // NULLS LAST
+}
+
+impl std::fmt::Display for Nulls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nulls = match self {
+            Self::First => "NULLS FIRST",
+            Self::Last => "NULLS LAST",
         };
-        write!(f, "{sequence}")
+        write!(f, "{nulls}")
     }
 }
 
+/// Sources other than a plain `table_alias.field` column that an item can be ordered by.
 #[derive(Clone, Debug)]
+pub enum OrderByExpression {
+    Raw(String),                 // An expression such as lower(t.name) or t.data->>'due_date'
+    Alias(String),               // A select-list alias
+    Condition(ConditionBuilder), // A condition with bound values such as t.id = ?
+}
+
+#[derive(Clone, Debug, Default)]
 pub struct OrderByItem {
     pub table_alias: Option<String>,
     pub field: String,
     pub sequence: Sequence,
+    pub nulls: Option<Nulls>,
+    pub collate: Option<String>,
+    pub expression: Option<OrderByExpression>, // Used instead of `table_alias` and `field` when set
 }
 
-#[derive(Clone, Debug)]
-pub struct OrderByBuilder;
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct OrderByBuilder {
+    pub statement: String,
+    pub values: Vec<Value>, //For Binding
+}
 
 impl OrderByBuilder {
-    pub fn build(values: Vec<OrderByItem>) -> anyhow::Result<String> {
-        if values.is_empty() {
-            return Err(anyhow!("order by item is empty"));
-        }
-        let mut order_by: Vec<String> = Vec::new();
-        for item in values.into_iter() {
-            let table_alias = if let Some(value) = item.table_alias {
+    fn is_valid_operator(value: &str) -> bool {
+        !value.is_empty() && value.chars().all(|c| "+-*/<>=~!@#%^&|`".contains(c))
+    }
+
+    /// Renders the ordered value of an item along with the values it binds.
+    fn source(item: &OrderByItem) -> Result<(String, Vec<Value>)> {
+        let Some(expression) = &item.expression else {
+            if item.field.is_empty() {
+                return Err(Error::EmptyField {
+                    builder: "OrderByBuilder",
+                    field: "field".to_string(),
+                });
+            }
+            let table_alias = if let Some(value) = &item.table_alias {
+                validate_identifier("OrderByBuilder", "table_alias", value)?;
                 &format!("{value}.")
             } else {
                 ""
             };
-            if item.field.is_empty() {
-                return Err(anyhow!("order by field is empty"));
+            return Ok((format!("{}{}", table_alias, item.field), vec![]));
+        };
+        if !item.field.is_empty() || item.table_alias.is_some() {
+            return Err(Error::DuplicateClause {
+                builder: "OrderByBuilder",
+                field: "expression".to_string(),
+            });
+        }
+        match expression {
+            OrderByExpression::Raw(value) => {
+                if value.trim().is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "OrderByBuilder",
+                        field: "expression".to_string(),
+                    });
+                }
+                Ok((value.to_owned(), vec![]))
+            }
+            OrderByExpression::Alias(value) => {
+                validate_identifier("OrderByBuilder", "expression", value)?;
+                Ok((value.to_owned(), vec![]))
             }
-            let value = format!("{}{} {}", table_alias, item.field, item.sequence);
-            if !order_by.contains(&value) {
-                order_by.push(value);
+            OrderByExpression::Condition(condition) => {
+                let condition = ConditionBuilder {
+                    logic: None,
+                    ..condition.to_owned()
+                };
+                Ok((
+                    ConditionBuilder::build(&condition)?,
+                    ConditionBuilder::values(&condition),
+                ))
             }
         }
-        Ok(format!("ORDER BY {}", order_by.join(", ").trim()))
+    }
+
+    /// Renders the `ORDER BY` clause of items that bind no values. An item ordered by an
+    /// [`OrderByExpression::Condition`] binds values, use [`build_values`](Self::build_values).
+    pub fn build(values: Vec<OrderByItem>) -> Result<String> {
+        let data = Self::build_values(values)?;
+        if !data.values.is_empty() {
+            return Err(Error::CountMismatch {
+                builder: "OrderByBuilder",
+                field: "values".to_string(),
+                expected: 0,
+                found: data.values.len(),
+            });
+        }
+        Ok(data.statement)
+    }
+
+    /// Renders the `ORDER BY` clause along with the values its items bind.
+    pub fn build_values(values: Vec<OrderByItem>) -> Result<OrderByBuilder> {
+        if values.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "OrderByBuilder",
+                field: "values".to_string(),
+            });
+        }
+        let mut data: OrderByBuilder = OrderByBuilder::default();
+        let mut order_by: Vec<(String, Vec<Value>)> = Vec::new();
+        for item in values.into_iter() {
+            let (source, values) = Self::source(&item)?;
+            let mut value = source;
+            if let Some(collate) = &item.collate {
+                value = format!("{value} COLLATE \"{}\"", collate.replace('"', "\"\""));
+            }
+            if let Sequence::Using(operator) = &item.sequence
+                && !Self::is_valid_operator(operator)
+            {
+                return Err(Error::InvalidIdentifier {
+                    builder: "OrderByBuilder",
+                    field: "sequence".to_string(),
+                    value: operator.to_owned(),
+                });
+            }
+            value = format!("{value} {}", item.sequence);
+            if let Some(nulls) = &item.nulls {
+                value = format!("{value} {nulls}");
+            }
+            // The same placeholders with other values are another item.
+            let item = (value, values);
+            if !order_by.contains(&item) {
+                order_by.push(item);
+            }
+        }
+        let statement: Vec<String> = order_by
+            .into_iter()
+            .map(|(value, mut values)| {
+                data.values.append(&mut values);
+                value
+            })
+            .collect();
+        data.statement = format!("ORDER BY {}", statement.join(", ").trim());
+        Ok(data)
     }
 }
 
 #[cfg(test)]
 pub mod test_order_by_builder {
     use super::*;
+    use crate::postgres::{ConditionValue, Operator};
+    use serde_json::Number;
 
     #[tokio::test]
     async fn test_order_by_builder() {
@@ -62,14 +193,23 @@ pub mod test_order_by_builder {
             table_alias: None,
             field: "".to_string(),
             sequence: Sequence::Asc,
+            ..Default::default()
         };
         let result = OrderByBuilder::build(vec![order_by]);
         assert!(result.is_err(), "expected error");
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "OrderByBuilder",
+                field: "field".to_string(),
+            })
+        );
 
         let order_by_items = vec![OrderByItem {
             table_alias: None,
             field: "myfield1".to_string(),
             sequence: Sequence::Asc,
+            ..Default::default()
         }];
         let result = OrderByBuilder::build(order_by_items);
         assert!(result.is_ok(), "{:?}", result.err());
@@ -81,11 +221,13 @@ pub mod test_order_by_builder {
                 table_alias: Some("t".to_string()),
                 field: "myfield1".to_string(),
                 sequence: Sequence::Asc,
+                ..Default::default()
             },
             OrderByItem {
                 table_alias: Some("t".to_string()),
                 field: "myfield2".to_string(),
                 sequence: Sequence::Desc,
+                ..Default::default()
             },
         ];
         let result = OrderByBuilder::build(order_by_items);
@@ -93,4 +235,149 @@ pub mod test_order_by_builder {
         let result = result.unwrap();
         assert_eq!(result, "ORDER BY t.myfield1 ASC, t.myfield2 DESC");
     }
+
+    #[tokio::test]
+    async fn test_order_by_builder_nulls_collate_using() {
+        let order_by_items = vec![
+            OrderByItem {
+                table_alias: Some("t".to_string()),
+                field: "due_date".to_string(),
+                sequence: Sequence::Asc,
+                nulls: Some(Nulls::Last),
+                ..Default::default()
+            },
+            OrderByItem {
+                table_alias: Some("t".to_string()),
+                field: "name".to_string(),
+                sequence: Sequence::Desc,
+                nulls: Some(Nulls::First),
+                collate: Some("C".to_string()),
+                ..Default::default()
+            },
+            OrderByItem {
+                table_alias: Some("t".to_string()),
+                field: "priority".to_string(),
+                sequence: Sequence::Using(">".to_string()),
+                ..Default::default()
+            },
+        ];
+        let result = OrderByBuilder::build(order_by_items);
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "ORDER BY t.due_date ASC NULLS LAST, t.name COLLATE \"C\" DESC NULLS FIRST, t.priority USING >"
+        );
+
+        let result = OrderByBuilder::build(vec![OrderByItem {
+            field: "priority".to_string(),
+            sequence: Sequence::Using("> 1; --".to_string()),
+            ..Default::default()
+        }]);
+        assert_eq!(
+            result,
+            Err(Error::InvalidIdentifier {
+                builder: "OrderByBuilder",
+                field: "sequence".to_string(),
+                value: "> 1; --".to_string(),
+            })
+        );
+
+        let result = OrderByBuilder::build(vec![OrderByItem {
+            field: "tags".to_string(),
+            sequence: Sequence::Using("?".to_string()),
+            ..Default::default()
+        }]);
+        assert!(result.is_err(), "expected an invalid operator error");
+    }
+
+    #[tokio::test]
+    async fn test_order_by_builder_expressions() {
+        let order_by_items = vec![
+            OrderByItem {
+                expression: Some(OrderByExpression::Condition(ConditionBuilder {
+                    table_alias: Some("t".to_string()),
+                    field: "id".to_string(),
+                    operator: Operator::Eq,
+                    value: Some(ConditionValue::Single(Value::Number(Number::from(42)))),
+                    logic: None,
+                })),
+                sequence: Sequence::Desc,
+                ..Default::default()
+            },
+            OrderByItem {
+                expression: Some(OrderByExpression::Raw("lower(t.name)".to_string())),
+                sequence: Sequence::Asc,
+                ..Default::default()
+            },
+            OrderByItem {
+                expression: Some(OrderByExpression::Raw("t.data->>'due_date'".to_string())),
+                sequence: Sequence::Asc,
+                nulls: Some(Nulls::Last),
+                ..Default::default()
+            },
+            OrderByItem {
+                expression: Some(OrderByExpression::Alias("total".to_string())),
+                sequence: Sequence::Desc,
+                ..Default::default()
+            },
+        ];
+        let result = OrderByBuilder::build_values(order_by_items.to_owned());
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(
+            result.statement,
+            "ORDER BY t.id = ? DESC, lower(t.name) ASC, t.data->>'due_date' ASC NULLS LAST, total DESC"
+        );
+        assert_eq!(result.values, vec![Value::Number(Number::from(42))]);
+        assert_eq!(
+            OrderByBuilder::build(order_by_items.to_owned()),
+            Err(Error::CountMismatch {
+                builder: "OrderByBuilder",
+                field: "values".to_string(),
+                expected: 0,
+                found: 1,
+            })
+        );
+
+        // The same condition with another value is kept, with its value.
+        let mut other = order_by_items[0].to_owned();
+        if let Some(OrderByExpression::Condition(condition)) = &mut other.expression {
+            condition.value = Some(ConditionValue::Single(Value::Number(Number::from(7))));
+        }
+        let items = vec![
+            order_by_items[0].to_owned(),
+            other,
+            order_by_items[0].to_owned(),
+        ];
+        let result = OrderByBuilder::build_values(items);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = result.unwrap();
+        assert_eq!(result.statement, "ORDER BY t.id = ? DESC, t.id = ? DESC");
+        assert_eq!(
+            result.values,
+            vec![
+                Value::Number(Number::from(42)),
+                Value::Number(Number::from(7))
+            ]
+        );
+
+        let result = OrderByBuilder::build(vec![OrderByItem {
+            field: "myfield1".to_string(),
+            expression: Some(OrderByExpression::Alias("total".to_string())),
+            ..Default::default()
+        }]);
+        assert_eq!(
+            result,
+            Err(Error::DuplicateClause {
+                builder: "OrderByBuilder",
+                field: "expression".to_string(),
+            })
+        );
+
+        let result = OrderByBuilder::build(vec![OrderByItem {
+            expression: Some(OrderByExpression::Alias("total amount".to_string())),
+            ..Default::default()
+        }]);
+        assert!(result.is_err(), "expected an invalid alias error");
+    }
 }
@@ -1,22 +1,119 @@
-use crate::placeholder::PlaceholderKind;
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::{quote_identifier, validate_identifier};
+use crate::literal::{inline_values, interpolate};
+use crate::placeholder::{Params, PlaceholderKind, bind_named, check_unnamed};
 use crate::postgres::{
-    ExpressionBuilder, GroupByBuilder, GroupByItem, JoinBuilder, JoinKind, Operator,
-    OrderByBuilder, OrderByItem, WhereBuilder,
+    ExpressionBuilder, FromBuilder, FromSource, GroupByBuilder, GroupByItem, GroupingElement,
+    JoinBuilder, JoinKind, Operator, OrderByBuilder, OrderByItem, WhereBuilder,
 };
+use crate::table::Table;
 use serde_json::Value;
 
+/// Controls whether `FETCH FIRST` keeps rows that tie with the last row in the ORDER BY.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FetchKind {
+    Only,     // FETCH FIRST n ROWS ONLY
+    WithTies, // FETCH FIRST n ROWS WITH TIES
+}
+
+impl std::fmt::Display for FetchKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::Only => "ONLY",
+            Self::WithTies => "WITH TIES",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// A join, kept as given so that its table and subquery are rendered for the dialect.
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+enum Join {
+    On(JoinKind, String, String, Vec<ExpressionBuilder>),
+    Using(JoinKind, String, String, Vec<String>),
+    Natural(JoinKind, String, String),
+    Lateral(JoinKind, SelectBuilder, String, Vec<ExpressionBuilder>),
+}
+
+impl Join {
+    /// Refuses the `FULL` and `LATERAL` joins of the dialects that don't support them.
+    fn check(&self, dialect: &dyn Dialect) -> Result<()> {
+        let (kind, lateral) = match self {
+            Self::On(kind, ..) | Self::Using(kind, ..) | Self::Natural(kind, ..) => (kind, false),
+            Self::Lateral(kind, ..) => (kind, true),
+        };
+        let field = if lateral && !dialect.supports_lateral() {
+            "LATERAL"
+        } else if *kind == JoinKind::Full && !dialect.supports_full_join() {
+            "FULL JOIN"
+        } else {
+            return Ok(());
+        };
+        Err(Error::UnsupportedClause {
+            builder: "SelectBuilder",
+            field: field.to_string(),
+            target: dialect.name().to_string(),
+        })
+    }
+
+    fn build(&self, dialect: &dyn Dialect) -> Result<JoinBuilder> {
+        self.check(dialect)?;
+        match self {
+            Self::On(kind, table, table_alias, values) => JoinBuilder::build(
+                kind.to_owned(),
+                &dialect.quote_qualified(table),
+                table_alias,
+                values.to_owned(),
+            ),
+            Self::Using(kind, table, table_alias, columns) => JoinBuilder::build_using(
+                kind.to_owned(),
+                &dialect.quote_qualified(table),
+                table_alias,
+                columns.iter().map(String::as_str).collect(),
+            ),
+            Self::Natural(kind, table, table_alias) => JoinBuilder::build_natural(
+                kind.to_owned(),
+                &dialect.quote_qualified(table),
+                table_alias,
+            ),
+            Self::Lateral(kind, query, table_alias, values) => JoinBuilder::render_lateral(
+                kind.to_owned(),
+                query,
+                table_alias,
+                values.to_owned(),
+                dialect,
+            ),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct SelectBuilder {
     pub distinct: bool,
-    pub table: String,
+    pub table: String, // `table as alias`, used as written when set directly
+    #[deprecated(
+        note = "read the values with `get_values`; writes are ignored and overwritten by the next builder call"
+    )]
+    pub values: Vec<Value>, // A read-only copy of `get_values()`, rewritten by the builder methods
+    table_source: Option<(String, String)>, // The table and alias of table(), quoted per dialect
+    sources: Vec<FromSource>,
     fields: Vec<String>,
     limit: Option<usize>,
     offset: Option<usize>,
-    pub values: Vec<Value>,
+    fetch_first: Option<(usize, FetchKind)>,
+    bind_limit_offset: bool,
+    from_values: Vec<Value>,
+    join_values: Vec<Value>,
+    filter_values: Vec<Value>,
+    order_by_values: Vec<Value>,
     filter_statement: Option<String>,
-    join_statement: Option<String>,
+    joins: Vec<Join>,
     group_by_statement: Option<String>,
     order_by_statement: Option<String>,
+    operators: Vec<Operator>, // The operators of the sources, joins and columns
+    filter_operators: Vec<Operator>, // The operators of the filter, replaced with it
     pub placeholder_kind: PlaceholderKind,
 }
 
@@ -33,9 +130,47 @@ impl SelectBuilder {
         self
     }
 
+    /// Sets the table to select from, replacing any previous sources. The table name is
+    /// quoted by the dialect when needed.
     pub fn table(&mut self, table: &str, table_alias: &str) -> &mut Self {
         self.table = format!("{table} as {table_alias}");
-        self
+        self.table_source = Some((table.to_string(), table_alias.to_string()));
+        self.sources = Vec::new();
+        self.from_values = Vec::new();
+        self.sync_values()
+    }
+
+    /// Selects the columns of a [`Table`] from its table, see [`table`](Self::table).
+    ///
+    /// # Example
+    /// ```ignore
+    /// table_of::<User>("u"); // SELECT u.id, u.name, u.email FROM "user" as u
+    /// ```
+    pub fn table_of<T: Table>(&mut self, table_alias: &str) -> &mut Self {
+        self.table(T::table_name(), table_alias)
+            .columns(table_alias, T::columns())
+    }
+
+    /// Adds a table, a nested query, a VALUES list or a set-returning function to the
+    /// FROM list. Several sources are comma-separated and their values kept in order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// from(FromSource::Function {
+    ///     function: "generate_series(?, ?)".to_string(),
+    ///     values: vec![json!(1), json!(10)],
+    ///     table_alias: "g".to_string(),
+    ///     columns: vec!["n".to_string()],
+    /// });
+    /// ```
+    pub fn from(&mut self, source: FromSource) -> Result<&mut Self> {
+        if let FromSource::Query { query, .. } = &source {
+            self.operators.append(&mut query.operators());
+        }
+        let mut result = FromBuilder::build(source.to_owned())?;
+        self.sources.push(source);
+        self.from_values.append(&mut result.values);
+        Ok(self.sync_values())
     }
 
     pub fn join(
@@ -44,30 +179,112 @@ impl SelectBuilder {
         table: &str,
         table_alias: &str,
         values: Vec<ExpressionBuilder>,
-    ) -> &mut Self {
-        if !values.is_empty() {
-            let mut item = JoinBuilder::build(kind, table, table_alias, values);
-            if !item.values.is_empty() {
-                self.values.append(&mut item.values);
-            }
-            self.join_statement = if let Some(statement) = &self.join_statement {
-                Some(format!("{} {}", statement, item.statement))
-            } else {
-                Some(item.statement)
-            };
+    ) -> Result<&mut Self> {
+        self.append_operators(&values);
+        self.append_join(Join::On(
+            kind,
+            table.to_string(),
+            table_alias.to_string(),
+            values,
+        ))
+    }
+
+    /// Joins a table using the columns both tables share, e.g. `JOIN products as p USING (product_id)`.
+    pub fn join_using(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        columns: Vec<&str>,
+    ) -> Result<&mut Self> {
+        self.append_join(Join::Using(
+            kind,
+            table.to_string(),
+            table_alias.to_string(),
+            columns.into_iter().map(String::from).collect(),
+        ))
+    }
+
+    pub fn join_natural(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+    ) -> Result<&mut Self> {
+        self.append_join(Join::Natural(
+            kind,
+            table.to_string(),
+            table_alias.to_string(),
+        ))
+    }
+
+    pub fn cross_join(&mut self, table: &str, table_alias: &str) -> Result<&mut Self> {
+        self.append_join(Join::On(
+            JoinKind::Cross,
+            table.to_string(),
+            table_alias.to_string(),
+            vec![],
+        ))
+    }
+
+    /// Joins a subquery that can reference the preceding tables, such as a top-N-per-group
+    /// query. The subquery must use the question mark placeholder kind; its values are
+    /// merged in order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// join_lateral(JoinKind::Left, &latest_orders, "lo", vec![]); // LEFT JOIN LATERAL (...) as lo ON TRUE
+    /// ```
+    pub fn join_lateral(
+        &mut self,
+        kind: JoinKind,
+        query: &SelectBuilder,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<&mut Self> {
+        self.operators.append(&mut query.operators());
+        self.append_operators(&values);
+        self.append_join(Join::Lateral(
+            kind,
+            query.to_owned(),
+            table_alias.to_string(),
+            values,
+        ))
+    }
+
+    fn append_operators(&mut self, values: &[ExpressionBuilder]) {
+        for item in values {
+            self.operators.append(&mut item.operators.to_owned());
         }
-        self
+    }
+
+    /// The operators of the query, checked against the dialect.
+    pub(crate) fn operators(&self) -> Vec<Operator> {
+        [self.operators.as_slice(), self.filter_operators.as_slice()].concat()
+    }
+
+    /// Checks the join and keeps its values, the statement is rendered by `build`.
+    fn append_join(&mut self, join: Join) -> Result<&mut Self> {
+        let mut item = join.build(&Postgres)?;
+        self.join_values.append(&mut item.values);
+        self.joins.push(join);
+        Ok(self.sync_values())
     }
 
     pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
         if !values.is_empty() {
+            self.filter_operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
             let mut result = WhereBuilder::build(values);
             self.filter_statement = Some(result.statement);
+            self.filter_values = Vec::new();
             if !result.values.is_empty() {
-                self.values.append(&mut result.values);
+                self.filter_values.append(&mut result.values);
             }
         }
-        self
+        self.sync_values()
     }
 
     /// Defines columns for a query by combining a table alias with column names.
@@ -101,11 +318,15 @@ impl SelectBuilder {
     // TODO! JSONB
     pub fn columns_jsonb(
         &mut self,
-        jsonb_field_a: &str,
-        operator: Operator,
-        jsonb_field_b: &str,
-    ) -> &mut Self {
-        todo!("code here the logic for handling jsonb columns");
+        _jsonb_field_a: &str,
+        _operator: Operator,
+        _jsonb_field_b: &str,
+    ) -> Result<&mut Self> {
+        Err(Error::UnsupportedClause {
+            builder: "SelectBuilder",
+            field: "columns_jsonb".to_string(),
+            target: "SelectBuilder".to_string(),
+        })
     }
 
     /// Allows users to define columns with custom expressions or functions, such as CONCAT,
@@ -132,43 +353,189 @@ impl SelectBuilder {
         self
     }
 
-    pub fn order_by(&mut self, values: Vec<OrderByItem>) -> anyhow::Result<&mut Self> {
+    pub fn order_by(&mut self, values: Vec<OrderByItem>) -> Result<&mut Self> {
         if !values.is_empty() {
-            self.order_by_statement = Some(OrderByBuilder::build(values)?);
+            let result = OrderByBuilder::build_values(values)?;
+            self.order_by_statement = Some(result.statement);
+            self.order_by_values = result.values;
         }
-        Ok(self)
+        Ok(self.sync_values())
     }
 
-    pub fn group_by(&mut self, values: Vec<GroupByItem>) -> anyhow::Result<&mut Self> {
+    pub fn group_by(&mut self, values: Vec<GroupByItem>) -> Result<&mut Self> {
         if !values.is_empty() {
             self.group_by_statement = Some(GroupByBuilder::build(values)?);
         }
         Ok(self)
     }
 
+    /// Groups by ROLLUP, CUBE, GROUPING SETS or plain items.
+    ///
+    /// # Example
+    /// ```ignore
+    /// group_by_elements(vec![GroupingElement::Rollup(vec![region, country])]);
+    /// ```
+    pub fn group_by_elements(&mut self, values: Vec<GroupingElement>) -> Result<&mut Self> {
+        if !values.is_empty() {
+            self.group_by_statement = Some(GroupByBuilder::build_elements(values)?);
+        }
+        Ok(self)
+    }
+
+    /// Adds a `GROUPING(...) as alias` column used to tell subtotal rows apart.
+    ///
+    /// # Example
+    /// ```ignore
+    /// columns_grouping(vec![region, country], "grouping_level");
+    /// ```
+    pub fn columns_grouping(&mut self, values: Vec<GroupByItem>, alias: &str) -> Result<&mut Self> {
+        validate_identifier("SelectBuilder", "columns_grouping", alias)?;
+        let grouping = GroupByBuilder::grouping(&values)?;
+        self.fields.push(format!("{grouping} as {}", quote_identifier(alias)));
+        Ok(self)
+    }
+
     pub fn limit(&mut self, value: usize) -> &mut Self {
         self.limit = Some(value);
-        self
+        self.sync_values()
     }
 
     pub fn offset(&mut self, value: usize) -> &mut Self {
         self.offset = Some(value);
+        self.sync_values()
+    }
+
+    /// Limits the result using the ANSI `FETCH FIRST n ROWS ONLY` / `WITH TIES` form
+    /// instead of `LIMIT`. `WITH TIES` requires an ORDER BY clause.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fetch_first(10, FetchKind::WithTies);
+    /// ```
+    pub fn fetch_first(&mut self, value: usize, kind: FetchKind) -> &mut Self {
+        self.fetch_first = Some((value, kind));
+        self.sync_values()
+    }
+
+    /// Binds the LIMIT/OFFSET (or FETCH FIRST) row counts as placeholders instead of
+    /// inlining them, so every page of a listing shares the same statement text.
+    /// The row counts are appended to the values after the other clauses.
+    pub fn bind_limit_offset(&mut self) -> &mut Self {
+        self.bind_limit_offset = true;
+        self.sync_values()
+    }
+
+    /// Keeps the deprecated `values` field equal to [`get_values`](Self::get_values).
+    #[allow(deprecated)]
+    fn sync_values(&mut self) -> &mut Self {
+        self.values = self.get_values();
         self
     }
 
+    /// Returns the row counts in the order they appear in the statement.
+    fn pagination_values(&self) -> Vec<usize> {
+        let mut values: Vec<usize> = Vec::new();
+        if let Some((value, _)) = &self.fetch_first {
+            if let Some(offset) = self.offset {
+                values.push(offset);
+            }
+            values.push(*value);
+        } else {
+            if let Some(limit) = self.limit {
+                values.push(limit);
+            }
+            if let Some(offset) = self.offset {
+                values.push(offset);
+            }
+        }
+        values
+    }
+
     pub fn get_values(&self) -> Vec<Value> {
-        self.values.to_owned()
+        let mut values: Vec<Value> = Vec::new();
+        values.append(&mut self.from_values.to_owned());
+        values.append(&mut self.join_values.to_owned());
+        values.append(&mut self.filter_values.to_owned());
+        values.append(&mut self.order_by_values.to_owned());
+        if self.bind_limit_offset {
+            values.append(
+                &mut self
+                    .pagination_values()
+                    .into_iter()
+                    .map(|value| Value::Number(value.into()))
+                    .collect(),
+            );
+        }
+        values
+    }
+
+    fn pagination_statement(&self, dialect: &dyn Dialect) -> Result<Option<String>> {
+        let row_count = |value: usize| {
+            if self.bind_limit_offset {
+                "?".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        let Some((value, kind)) = &self.fetch_first else {
+            let limit = self.limit.map(row_count);
+            let offset = self.offset.map(row_count);
+            return Ok(dialect.limit_offset(limit.as_deref(), offset.as_deref()));
+        };
+        if !dialect.supports_fetch_first() {
+            return Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "fetch_first".to_string(),
+                target: dialect.name().to_string(),
+            });
+        }
+        if self.limit.is_some() {
+            return Err(Error::DuplicateClause {
+                builder: "SelectBuilder",
+                field: "limit".to_string(),
+            });
+        }
+        if kind == &FetchKind::WithTies && self.order_by_statement.is_none() {
+            return Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "order_by".to_string(),
+            });
+        }
+        let mut statement: Vec<String> = Vec::new();
+        if let Some(value) = self.offset {
+            statement.push(format!("OFFSET {} ROWS", row_count(value)));
+        }
+        statement.push(format!("FETCH FIRST {} ROWS {}", row_count(*value), kind));
+        Ok(Some(statement.join(" ")))
     }
 
-    pub fn build(&self) -> anyhow::Result<String> {
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self, dialect: &dyn Dialect) -> Result<String> {
         let fields = self.fields.join(", ");
         let mut statement: String = if self.distinct {
-            format!("SELECT DISTINCT {} FROM {}", fields, self.table)
+            format!("SELECT DISTINCT {fields}")
         } else {
-            format!("SELECT {} FROM {}", fields, self.table)
+            format!("SELECT {fields}")
         };
-        if let Some(value) = &self.join_statement {
-            statement = format!("{statement} {value}");
+        let mut tables: Vec<String> = Vec::new();
+        match &self.table_source {
+            Some((table, table_alias)) if self.table == format!("{table} as {table_alias}") => {
+                tables.push(format!(
+                    "{} as {table_alias}",
+                    dialect.quote_qualified(table)
+                ));
+            }
+            _ if !self.table.is_empty() => tables.push(self.table.to_owned()),
+            _ => {}
+        }
+        for source in &self.sources {
+            tables.push(FromBuilder::build_with(source.to_owned(), dialect)?.statement);
+        }
+        if !tables.is_empty() {
+            statement = format!("{statement} FROM {}", tables.join(", "));
+        }
+        for join in &self.joins {
+            statement = format!("{statement} {}", join.build(dialect)?.statement);
         }
         if let Some(value) = &self.filter_statement {
             statement = format!("{statement} {value}");
@@ -179,38 +546,90 @@ impl SelectBuilder {
         if let Some(value) = &self.order_by_statement {
             statement = format!("{statement} {value}");
         }
-        if let Some(value) = &self.limit {
-            statement = format!("{statement} LIMIT {value}");
-        }
-        if let Some(value) = &self.offset {
-            statement = format!("{statement} OFFSET {value}");
+        if let Some(value) = self.pagination_statement(dialect)? {
+            statement = format!("{statement} {value}");
         }
+        Ok(statement.trim().to_string())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        let statement = self.render(&Postgres)?;
+        check_unnamed("SelectBuilder", &statement)?;
         match self.placeholder_kind {
-            PlaceholderKind::QuestionMark => Ok(statement.trim().to_string()),
-            PlaceholderKind::DollarSequential => {
-                let mut counter: usize = 0;
-                let values: Vec<String> = statement
-                    .chars()
-                    .map(|c| {
-                        if c.to_string() == "?" {
-                            counter += 1;
-                            return format!("${counter}");
-                        }
-                        c.to_string()
-                    })
-                    .collect();
-                Ok(values.join("").trim().to_string())
-            }
+            PlaceholderKind::QuestionMark => Ok(statement),
+            PlaceholderKind::DollarSequential => Ok(Postgres.render_placeholders(&statement)),
         }
     }
+
+    /// Renders the statement for the dialect, using its placeholders instead of the
+    /// `placeholder_kind`. Errors when the query uses a clause or a JSON operator the
+    /// dialect doesn't support, and on named parameters, see [`build_named`](Self::build_named).
+    ///
+    /// # Example
+    /// ```ignore
+    /// builder.build_with(&Sqlite); // SELECT ... LIMIT -1 OFFSET 10
+    /// ```
+    pub fn build_with(&self, dialect: &dyn Dialect) -> Result<String> {
+        dialect.check_operators("SelectBuilder", &self.operators())?;
+        let statement = self.render(dialect)?;
+        check_unnamed("SelectBuilder", &statement)?;
+        Ok(dialect.render_placeholders(&statement))
+    }
+
+    /// Builds the statement, binding its named parameters (`:name`, `@name`, `$name`)
+    /// from `params`. Returns the statement with the `placeholder_kind` placeholders and
+    /// all the values to bind, in order. Errors when a named parameter has no value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (statement, values) = builder.build_named(&Params::from([("email".to_string(), json!("x"))]))?;
+    /// ```
+    pub fn build_named(&self, params: &Params) -> Result<(String, Vec<Value>)> {
+        let statement = self.render(&Postgres)?;
+        bind_named(
+            "SelectBuilder",
+            &statement,
+            self.get_values(),
+            params,
+            &self.placeholder_kind,
+        )
+    }
+
+    /// Builds the statement with its values inlined as escaped literals instead of
+    /// placeholders, for statements that can't take bind parameters such as views.
+    /// Errors when a value can't be represented as a literal and on named parameters.
+    ///
+    /// # Example
+    /// ```ignore
+    /// builder.build_inline(); // SELECT * FROM users as u WHERE u.status = 'active'
+    /// ```
+    pub fn build_inline(&self) -> Result<String> {
+        let statement = self.render(&Postgres)?;
+        inline_values("SelectBuilder", &statement, self.get_values())
+    }
+
+    /// Renders the statement with its values interpolated as escaped literals, for logs
+    /// and `EXPLAIN` copy-paste. Not for execution with untrusted input, see
+    /// [`interpolate`].
+    pub fn build_interpolated(&self) -> Result<String> {
+        interpolate(
+            "SelectBuilder",
+            &self.render(&Postgres)?,
+            &self.get_values(),
+        )
+    }
 }
+
 #[cfg(test)]
 pub mod test_select_builder {
 
     use serde_json::Number;
 
     use super::*;
-    use crate::postgres::{ConditionBuilder, ConditionValue, Logic, Operator, Sequence};
+    use crate::postgres::{
+        ConditionBuilder, ConditionValue, Logic, Nulls, Operator, OrderByExpression, Sequence,
+        TableSample, TableSource,
+    };
 
     #[tokio::test]
     async fn test_select_builder() {
@@ -244,6 +663,7 @@ pub mod test_select_builder {
             table_alias: Some("t".to_string()),
             field: "myfield1".to_string(),
             sequence: Sequence::Asc,
+            ..Default::default()
         }]);
         assert!(result.is_ok(), "{:?}", result.err());
         let result = builder.build();
@@ -262,11 +682,13 @@ pub mod test_select_builder {
                 table_alias: None,
                 field: "myfield1".to_string(),
                 sequence: Sequence::Asc,
+                ..Default::default()
             },
             OrderByItem {
                 table_alias: None,
                 field: "myfield2".to_string(),
                 sequence: Sequence::Desc,
+                ..Default::default()
             },
         ]);
         assert!(result.is_ok(), "{:?}", result.err());
@@ -286,11 +708,13 @@ pub mod test_select_builder {
                     table_alias: Some("t".to_string()),
                     field: "myfield1".to_string(),
                     sequence: Sequence::Asc,
+                    ..Default::default()
                 },
                 OrderByItem {
                     table_alias: Some("t".to_string()),
                     field: "myfield2".to_string(),
                     sequence: Sequence::Desc,
+                    ..Default::default()
                 },
             ]);
         assert!(result.is_ok(), "{:?}", result.err());
@@ -345,6 +769,7 @@ pub mod test_select_builder {
         let result = builder
             .table("orders", "o")
             .join(JoinKind::Inner, "products", "p", vec![join_clause])
+            .unwrap()
             .columns("o", vec!["id", "user_id", "product_id"])
             .limit(10)
             .offset(0)
@@ -388,6 +813,7 @@ pub mod test_select_builder {
         let result = builder
             .table("orders", "o")
             .join(JoinKind::Left, "products", "p", vec![join_clause.clone()])
+            .unwrap()
             .filter(vec![filter_clause.clone()])
             .columns("o", vec!["id", "user_id", "product_id"])
             .limit(10)
@@ -403,17 +829,20 @@ pub mod test_select_builder {
         let result = builder
             .table("orders", "o")
             .join(JoinKind::Left, "products", "p", vec![join_clause.clone()])
+            .unwrap()
             .filter(vec![filter_clause.clone()])
             .columns("o", vec!["id", "user_id", "product_id"])
             .order_by(vec![OrderByItem {
                 table_alias: Some("o".to_string()),
                 field: "user_id".to_string(),
                 sequence: Sequence::Asc,
+                ..Default::default()
             }])
             .unwrap()
             .group_by(vec![GroupByItem {
                 table_alias: Some("o".to_string()),
                 field: "user_id".to_string(),
+                ..Default::default()
             }])
             .unwrap()
             .limit(10)
@@ -462,17 +891,20 @@ pub mod test_select_builder {
         let result = builder
             .table("orders", "o")
             .join(JoinKind::Left, "products", "p", vec![join_clause])
+            .unwrap()
             .filter(vec![filter_clause])
             .columns("o", vec!["id", "user_id", "product_id"])
             .order_by(vec![OrderByItem {
                 table_alias: Some("o".to_string()),
                 field: "user_id".to_string(),
                 sequence: Sequence::Asc,
+                ..Default::default()
             }])
             .unwrap()
             .group_by(vec![GroupByItem {
                 table_alias: Some("o".to_string()),
                 field: "user_id".to_string(),
+                ..Default::default()
             }])
             .unwrap()
             .limit(10)
@@ -484,4 +916,1000 @@ pub mod test_select_builder {
             "SELECT o.id, o.user_id, o.product_id FROM orders as o LEFT JOIN products as p ON p.id = o.product_id WHERE o.id = $1 AND o.user_id = $2 AND o.product_id = $3 GROUP BY o.user_id ORDER BY o.user_id ASC LIMIT 10 OFFSET 0"
         );
     }
+
+    #[tokio::test]
+    async fn test_select_builder_order_by_bound_value() {
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("t".to_string()),
+                field: "status".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::String("open".to_string()))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .table("tasks", "t")
+            .columns("t", vec!["id", "name"])
+            .filter(vec![filter_clause])
+            .order_by(vec![
+                OrderByItem {
+                    expression: Some(OrderByExpression::Condition(ConditionBuilder {
+                        table_alias: Some("t".to_string()),
+                        field: "id".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(Value::Number(Number::from(42)))),
+                        logic: None,
+                    })),
+                    sequence: Sequence::Desc,
+                    ..Default::default()
+                },
+                OrderByItem {
+                    table_alias: Some("t".to_string()),
+                    field: "due_date".to_string(),
+                    sequence: Sequence::Asc,
+                    nulls: Some(Nulls::Last),
+                    ..Default::default()
+                },
+            ])
+            .unwrap()
+            .limit(10)
+            .bind_limit_offset()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT t.id, t.name FROM tasks as t WHERE t.status = $1 ORDER BY t.id = $2 DESC, t.due_date ASC NULLS LAST LIMIT $3"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::String("open".to_string()),
+                Value::Number(Number::from(42)),
+                Value::Number(Number::from(10)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_rollup() {
+        let region = GroupByItem {
+            table_alias: Some("s".to_string()),
+            field: "region".to_string(),
+            ..Default::default()
+        };
+        let country = GroupByItem {
+            table_alias: Some("s".to_string()),
+            field: "country".to_string(),
+            ..Default::default()
+        };
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("sales", "s")
+            .columns("s", vec!["region", "country"])
+            .columns_raw(vec!["sum(s.amount) as total"])
+            .columns_grouping(vec![region.clone(), country.clone()], "grouping_level")
+            .unwrap()
+            .group_by_elements(vec![GroupingElement::Rollup(vec![region, country])])
+            .unwrap()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT s.region, s.country, sum(s.amount) as total, GROUPING(s.region, s.country) as grouping_level FROM sales as s GROUP BY ROLLUP (s.region, s.country)"
+        );
+
+        let result = builder.columns_grouping(
+            vec![GroupByItem {
+                field: "region".to_string(),
+                ..Default::default()
+            }],
+            "x; DROP TABLE t",
+        );
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::InvalidIdentifier {
+                builder: "SelectBuilder",
+                field: "columns_grouping".to_string(),
+                value: "x; DROP TABLE t".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_joins() {
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .join_using(JoinKind::Inner, "products", "p", vec!["product_id"])
+            .unwrap()
+            .join_natural(JoinKind::Left, "users", "u")
+            .unwrap()
+            .cross_join("regions", "r")
+            .unwrap()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT o.id FROM orders as o INNER JOIN products as p USING (product_id) NATURAL LEFT JOIN users as u CROSS JOIN regions as r"
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("orders", "o")
+            .join(JoinKind::Left, "products", "p", vec![]);
+        assert!(
+            result.is_err(),
+            "expecting a join without conditions to be rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_join_lateral() {
+        let mut latest_orders = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        latest_orders
+            .table("orders", "o")
+            .columns("o", vec!["id", "order_date"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        ConditionBuilder {
+                            table_alias: Some("o".to_string()),
+                            field: "user_id".to_string(),
+                            operator: Operator::Eq,
+                            value: Some(ConditionValue::Field("u".to_string(), "id".to_string())),
+                            logic: None,
+                        },
+                        ConditionBuilder {
+                            table_alias: Some("o".to_string()),
+                            field: "quantity".to_string(),
+                            operator: Operator::Gte,
+                            value: Some(ConditionValue::Single(Value::Number(Number::from(2)))),
+                            logic: Some(Logic::And),
+                        },
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .order_by(vec![OrderByItem {
+                table_alias: Some("o".to_string()),
+                field: "order_date".to_string(),
+                sequence: Sequence::Desc,
+                ..Default::default()
+            }])
+            .unwrap()
+            .limit(3);
+
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("u".to_string()),
+                field: "email".to_string(),
+                operator: Operator::Like,
+                value: Some(ConditionValue::Single(Value::String(
+                    "%@example.com".to_string(),
+                ))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .columns("lo", vec!["id", "order_date"])
+            .filter(vec![filter_clause])
+            .join_lateral(JoinKind::Left, &latest_orders, "lo", vec![])
+            .unwrap()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT u.id, lo.id, lo.order_date FROM users as u LEFT JOIN LATERAL (SELECT o.id, o.order_date FROM orders as o WHERE o.user_id = u.id AND o.quantity >= $1 ORDER BY o.order_date DESC LIMIT 3) as lo ON TRUE WHERE u.email LIKE $2"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::Number(Number::from(2)),
+                Value::String("%@example.com".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_from_sources() {
+        let mut query = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        query
+            .table("orders", "o")
+            .columns("o", vec!["user_id", "quantity"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: Some("o".to_string()),
+                        field: "quantity".to_string(),
+                        operator: Operator::Gt,
+                        value: Some(ConditionValue::Single(Value::Number(Number::from(1)))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("sub".to_string()),
+                field: "user_id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::Number(Number::from(7)))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .from(FromSource::Query {
+                query,
+                table_alias: "sub".to_string(),
+                columns: vec![],
+            })
+            .unwrap()
+            .columns("sub", vec![])
+            .filter(vec![filter_clause])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT sub.* FROM (SELECT o.user_id, o.quantity FROM orders as o WHERE o.quantity > $1) as sub WHERE sub.user_id = $2"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::Number(Number::from(1)),
+                Value::Number(Number::from(7))
+            ]
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .from(FromSource::Values {
+                rows: vec![
+                    vec![
+                        Value::Number(Number::from(1)),
+                        Value::String("Alice".to_string()),
+                    ],
+                    vec![
+                        Value::Number(Number::from(2)),
+                        Value::String("Bob".to_string()),
+                    ],
+                ],
+                table_alias: "v".to_string(),
+                columns: vec!["id".to_string(), "name".to_string()],
+            })
+            .unwrap()
+            .columns("v", vec!["id", "name"])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT v.id, v.name FROM (VALUES ($1, $2), ($3, $4)) as v(id, name)"
+        );
+        assert_eq!(builder.get_values().len(), 4);
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .from(FromSource::Function {
+                function: "unnest(?::int[])".to_string(),
+                values: vec![serde_json::json!([1, 2, 3])],
+                table_alias: "x".to_string(),
+                columns: vec!["id".to_string()],
+            })
+            .unwrap()
+            .columns("x", vec!["id"])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT x.id FROM unnest($1::int[]) as x(id)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_from_list() {
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("u".to_string()),
+                field: "id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Field(
+                    "o".to_string(),
+                    "user_id".to_string(),
+                )),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .from(FromSource::Table(TableSource {
+                schema: Some("Sales".to_string()),
+                table: "orders".to_string(),
+                table_alias: "o".to_string(),
+                only: true,
+                tablesample: Some(TableSample::System(5.0)),
+                ..Default::default()
+            }))
+            .unwrap()
+            .from(FromSource::Table(TableSource {
+                schema: Some("public".to_string()),
+                table: "user".to_string(),
+                table_alias: "u".to_string(),
+                ..Default::default()
+            }))
+            .unwrap()
+            .from(FromSource::Function {
+                function: "generate_series(?, ?)".to_string(),
+                values: vec![
+                    Value::Number(Number::from(1)),
+                    Value::Number(Number::from(3)),
+                ],
+                table_alias: "g".to_string(),
+                columns: vec!["n".to_string()],
+            })
+            .unwrap()
+            .columns("o", vec!["id"])
+            .columns("g", vec!["n"])
+            .filter(vec![filter_clause])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT o.id, g.n FROM ONLY Sales.orders as o TABLESAMPLE SYSTEM (5), public.\"user\" as u, generate_series($1, $2) as g(n) WHERE u.id = o.user_id"
+        );
+        assert_eq!(builder.get_values().len(), 2);
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder.columns_raw(vec!["1"]).build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "SELECT 1");
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder.table("Users", "u").columns("u", vec!["id"]);
+        assert_eq!(builder.table, "Users as u");
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id FROM Users as u".to_string())
+        );
+        builder.table = "\"Users\" as u".to_string();
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id FROM \"Users\" as u".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_bind_limit_offset() {
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("o".to_string()),
+                field: "user_id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::Number(Number::from(7)))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .filter(vec![filter_clause.clone()])
+            .limit(10)
+            .offset(20)
+            .bind_limit_offset()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT o.id FROM orders as o WHERE o.user_id = $1 LIMIT $2 OFFSET $3"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::Number(Number::from(7)),
+                Value::Number(Number::from(10)),
+                Value::Number(Number::from(20)),
+            ]
+        );
+        #[allow(deprecated)]
+        let values = builder.values.to_owned();
+        assert_eq!(values, builder.get_values());
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .offset(20)
+            .bind_limit_offset()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), "SELECT o.id FROM orders as o OFFSET ?");
+        assert_eq!(builder.get_values(), vec![Value::Number(Number::from(20))]);
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_fetch_first() {
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .offset(5)
+            .fetch_first(10, FetchKind::Only)
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT o.id FROM orders as o OFFSET 5 ROWS FETCH FIRST 10 ROWS ONLY"
+        );
+
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("o".to_string()),
+                field: "user_id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::Number(Number::from(7)))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .filter(vec![filter_clause])
+            .order_by(vec![OrderByItem {
+                table_alias: Some("o".to_string()),
+                field: "quantity".to_string(),
+                sequence: Sequence::Desc,
+                ..Default::default()
+            }])
+            .unwrap()
+            .offset(5)
+            .fetch_first(3, FetchKind::WithTies)
+            .bind_limit_offset()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT o.id FROM orders as o WHERE o.user_id = $1 ORDER BY o.quantity DESC OFFSET $2 ROWS FETCH FIRST $3 ROWS WITH TIES"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                Value::Number(Number::from(7)),
+                Value::Number(Number::from(5)),
+                Value::Number(Number::from(3)),
+            ]
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .fetch_first(3, FetchKind::WithTies)
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "order_by".to_string(),
+            })
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .limit(3)
+            .fetch_first(3, FetchKind::Only)
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::DuplicateClause {
+                builder: "SelectBuilder",
+                field: "limit".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_build_with() {
+        use crate::dialect::{MySql, Sqlite};
+
+        let filter = |operator: Operator, value: Value| {
+            ExpressionBuilder::build(
+                vec![ConditionBuilder {
+                    table_alias: Some("u".to_string()),
+                    field: "profile".to_string(),
+                    operator,
+                    value: Some(ConditionValue::Single(value)),
+                    logic: None,
+                }],
+                None,
+            )
+            .unwrap()
+        };
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id", "name"])
+            .filter(vec![filter(Operator::Eq, Value::String("x".to_string()))])
+            .offset(20);
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id, u.name FROM users as u WHERE u.profile = $1 OFFSET 20".to_string())
+        );
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Ok(
+                "SELECT u.id, u.name FROM users as u WHERE u.profile = ? LIMIT -1 OFFSET 20"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            builder.limit(10).build_with(&MySql),
+            Ok(
+                "SELECT u.id, u.name FROM `users` as u WHERE u.profile = ? LIMIT 20, 10"
+                    .to_string()
+            )
+        );
+        // The bound row counts keep the order of get_values, LIMIT then OFFSET
+        assert_eq!(
+            builder.bind_limit_offset().build_with(&MySql),
+            Ok(
+                "SELECT u.id, u.name FROM `users` as u WHERE u.profile = ? LIMIT ? OFFSET ?"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![serde_json::json!("x"), serde_json::json!(10), serde_json::json!(20)]
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec![])
+            .filter(vec![filter(
+                Operator::JsonbContains,
+                Value::String("{}".to_string()),
+            )]);
+        assert!(builder.build_with(&Postgres).is_ok());
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "@>".to_string(),
+                target: "SQLite".to_string(),
+            })
+        );
+        // Replacing the filter drops its operators
+        builder.filter(vec![filter(Operator::Eq, Value::String("x".to_string()))]);
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Ok("SELECT u.* FROM users as u WHERE u.profile = ?".to_string())
+        );
+
+        // The casts of the typed values are PostgreSQL syntax.
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: Some("u".to_string()),
+                        field: "id".to_string(),
+                        operator: Operator::In,
+                        value: Some(ConditionValue::Typed(crate::value::SqlValue::from(vec![
+                            1, 2,
+                        ]))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id FROM users as u WHERE u.id IN ($1::int4, $2::int4)".to_string())
+        );
+        assert_eq!(
+            builder.build_with(&MySql),
+            Ok("SELECT u.id FROM `users` as u WHERE u.id IN (?, ?)".to_string())
+        );
+
+        // The joined tables, the FROM sources and the subqueries follow the dialect too.
+        let mut subquery = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        subquery
+            .table("order", "o")
+            .columns("o", vec!["id"])
+            .offset(5);
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .columns("s", vec!["id"])
+            .from(FromSource::Query {
+                query: subquery.to_owned(),
+                table_alias: "s".to_string(),
+                columns: vec![],
+            })
+            .unwrap()
+            .cross_join("order", "c")
+            .unwrap();
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Ok(r#"SELECT s.id FROM (SELECT o.id FROM "order" as o LIMIT -1 OFFSET 5) as s CROSS JOIN "order" as c"#.to_string())
+        );
+        builder
+            .join_lateral(JoinKind::Left, &subquery, "l", vec![])
+            .unwrap();
+        assert_eq!(
+            builder.build(),
+            Ok(r#"SELECT s.id FROM (SELECT o.id FROM "order" as o OFFSET 5) as s CROSS JOIN "order" as c LEFT JOIN LATERAL (SELECT o.id FROM "order" as o OFFSET 5) as l ON TRUE"#.to_string())
+        );
+        assert_eq!(
+            builder.build_with(&MySql),
+            Ok("SELECT s.id FROM (SELECT o.id FROM `order` as o LIMIT 5, 18446744073709551615) as s CROSS JOIN `order` as c LEFT JOIN LATERAL (SELECT o.id FROM `order` as o LIMIT 5, 18446744073709551615) as l ON TRUE".to_string())
+        );
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "LATERAL".to_string(),
+                target: "SQLite".to_string(),
+            })
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .join_natural(JoinKind::Full, "accounts", "a")
+            .unwrap();
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Ok("SELECT u.id FROM users as u NATURAL FULL JOIN accounts as a".to_string())
+        );
+        assert_eq!(
+            builder.build_with(&MySql),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "FULL JOIN".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec![])
+            .fetch_first(5, FetchKind::Only);
+        assert_eq!(
+            builder.build_with(&MySql),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "fetch_first".to_string(),
+                target: "MySQL".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_build_named() {
+        use crate::placeholder::Params;
+        use serde_json::json;
+
+        let condition =
+            |field: &str, value: ConditionValue, logic: Option<Logic>| ConditionBuilder {
+                table_alias: Some("u".to_string()),
+                field: field.to_string(),
+                operator: Operator::Eq,
+                value: Some(value),
+                logic,
+            };
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![condition(
+                        "active",
+                        ConditionValue::Single(json!(true)),
+                        None,
+                    )],
+                    None,
+                )
+                .unwrap(),
+                ExpressionBuilder::build(
+                    vec![
+                        condition("email", ConditionValue::Named("email".to_string()), None),
+                        condition(
+                            "backup_email",
+                            ConditionValue::Named("email".to_string()),
+                            Some(Logic::Or),
+                        ),
+                    ],
+                    Some(Logic::And),
+                )
+                .unwrap(),
+            ]);
+        let params = Params::from([("email".to_string(), json!("jdc@test.com"))]);
+        let result = builder.build_named(&params);
+        assert_eq!(
+            result,
+            Ok((
+                "SELECT u.id FROM users as u WHERE (u.active = $1) AND (u.email = $2 OR u.backup_email = $2)".to_string(),
+                vec![json!(true), json!("jdc@test.com")]
+            ))
+        );
+        assert_eq!(
+            builder.build_named(&Params::new()),
+            Err(Error::MissingParameter {
+                builder: "SelectBuilder",
+                field: "email".to_string(),
+            })
+        );
+
+        // The named parameters have no value outside of build_named.
+        let missing = Err(Error::MissingParameter {
+            builder: "SelectBuilder",
+            field: "email".to_string(),
+        });
+        assert_eq!(builder.build(), missing);
+        assert_eq!(builder.build_with(&Postgres), missing);
+
+        // A named list can't be expanded into one placeholder per item.
+        let result = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                operator: Operator::In,
+                ..condition("id", ConditionValue::Named("ids".to_string()), None)
+            }],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: "id".to_string(),
+                operator: "IN".to_string(),
+                value: "a named parameter".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_build_inline() {
+        use serde_json::json;
+        let condition = |field: &str, operator: Operator, value: Value, logic: Option<Logic>| {
+            ConditionBuilder {
+                table_alias: Some("u".to_string()),
+                field: field.to_string(),
+                operator,
+                value: Some(ConditionValue::Single(value)),
+                logic,
+            }
+        };
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id", "name"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        condition("name", Operator::Like, json!("O'Br%"), None),
+                        condition("age", Operator::Gte, json!(18), Some(Logic::And)),
+                        condition(
+                            "role",
+                            Operator::In,
+                            json!(["admin", "staff"]),
+                            Some(Logic::And),
+                        ),
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .limit(10);
+        assert_eq!(
+            builder.build_inline(),
+            Ok("SELECT u.id, u.name FROM users as u WHERE u.name LIKE 'O''Br%' AND u.age >= 18 AND u.role IN ('admin', 'staff') LIMIT 10".to_string())
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![condition("name", Operator::Eq, json!("a\0b"), None)],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build_inline(),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "values".to_string(),
+                target: "PostgreSQL literals".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_build_interpolated() {
+        use serde_json::json;
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("orders", "o")
+            .columns("o", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        ConditionBuilder {
+                            table_alias: Some("o".to_string()),
+                            field: "note".to_string(),
+                            operator: Operator::Eq,
+                            value: Some(ConditionValue::Single(json!("it's ? $1"))),
+                            logic: None,
+                        },
+                        ConditionBuilder {
+                            table_alias: Some("o".to_string()),
+                            field: "data".to_string(),
+                            operator: Operator::JsonbContains,
+                            value: Some(ConditionValue::Single(json!({"status": "paid"}))),
+                            logic: Some(Logic::And),
+                        },
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .bind_limit_offset()
+            .limit(5);
+        assert_eq!(
+            builder.build(),
+            Ok(
+                "SELECT o.id FROM orders as o WHERE o.note = $1 AND o.data @> $2::jsonb LIMIT $3"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            builder.build_interpolated(),
+            Ok(r#"SELECT o.id FROM orders as o WHERE o.note = 'it''s ? $1' AND o.data @> '{"status":"paid"}'::jsonb LIMIT 5"#.to_string())
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        ConditionBuilder {
+                            table_alias: Some("u".to_string()),
+                            field: "id".to_string(),
+                            operator: Operator::In,
+                            value: Some(ConditionValue::Single(json!([1, 2]))),
+                            logic: None,
+                        },
+                        ConditionBuilder {
+                            table_alias: Some("u".to_string()),
+                            field: "tags".to_string(),
+                            operator: Operator::JsonbContains,
+                            value: Some(ConditionValue::Single(json!(["a", "b"]))),
+                            logic: Some(Logic::And),
+                        },
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build_interpolated(),
+            Ok(r#"SELECT u.id FROM users as u WHERE u.id IN (1, 2) AND u.tags @> '["a","b"]'::jsonb"#.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_jsonb_key_operators() {
+        use serde_json::json;
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![
+                        ConditionBuilder {
+                            table_alias: Some("u".to_string()),
+                            field: "data".to_string(),
+                            operator: Operator::JsonbHasKey,
+                            value: Some(ConditionValue::Single(json!("email"))),
+                            logic: None,
+                        },
+                        ConditionBuilder {
+                            table_alias: Some("u".to_string()),
+                            field: "data".to_string(),
+                            operator: Operator::JsonbHasAnyKeys,
+                            value: Some(ConditionValue::Single(json!(["phone"]))),
+                            logic: Some(Logic::And),
+                        },
+                        ConditionBuilder {
+                            table_alias: Some("u".to_string()),
+                            field: "note".to_string(),
+                            operator: Operator::Neq,
+                            value: Some(ConditionValue::Single(json!("?"))),
+                            logic: Some(Logic::And),
+                        },
+                    ],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build(),
+            Ok(
+                "SELECT u.id FROM users as u WHERE u.data ? $1 AND u.data ?| $2::text[] AND u.note != $3"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            builder.build_interpolated(),
+            Ok("SELECT u.id FROM users as u WHERE u.data ? 'email' AND u.data ?| ARRAY['phone']::text[] AND u.note != '?'".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_columns_jsonb() {
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder
+            .table("users", "u")
+            .columns_jsonb("u.profile", Operator::JsonbConcatenate, "u.settings");
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "columns_jsonb".to_string(),
+                target: "SelectBuilder".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_table_of() {
+        use crate::table::test_table::User;
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder.table_of::<User>("u");
+        assert_eq!(
+            builder.build(),
+            Ok(
+                "SELECT u.id, u.name, u.email_address, u.nickname, u.profile FROM \"user\" as u"
+                    .to_string()
+            )
+        );
+    }
 }
@@ -1,10 +1,13 @@
+use crate::error::{Error, Result};
+use crate::value::SqlValue;
 use crate::{placeholder::PlaceholderKind, postgres::SelectBuilder};
-use anyhow::anyhow;
 use serde_json::Value;
 
 #[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum SetValue {
     Value(Value),
+    Typed(SqlValue), // A typed value, its placeholder may be cast such as `?::uuid`
     Query(SelectBuilder),
 }
 
@@ -21,7 +24,7 @@ pub struct SetFieldUpdate {
 }
 
 impl SetBuilder {
-    pub fn build(items: Vec<SetFieldUpdate>) -> anyhow::Result<Self> {
+    pub fn build(items: Vec<SetFieldUpdate>) -> Result<Self> {
         let mut expressions: Vec<String> = Vec::new();
         let mut values: Vec<Value> = Vec::new();
 
@@ -31,11 +34,18 @@ impl SetBuilder {
                     expressions.push(format!("{} = ?", item.field));
                     values.push(value.to_owned());
                 }
+                SetValue::Typed(value) => {
+                    expressions.push(format!("{} = {}", item.field, value.placeholder()));
+                    values.push(value.to_json()?);
+                }
                 SetValue::Query(selected_builder) => {
                     if selected_builder.placeholder_kind != PlaceholderKind::QuestionMark {
-                        return Err(anyhow!(
-                            "select builder should be using the question mark placeholder kind"
-                        ));
+                        return Err(Error::PlaceholderKindConflict {
+                            builder: "SetBuilder",
+                            field: item.field.to_owned(),
+                            expected: PlaceholderKind::QuestionMark,
+                            found: selected_builder.placeholder_kind.to_owned(),
+                        });
                     }
                     let result = selected_builder.build()?;
                     expressions.push(format!("{} = ({})", item.field, result));
@@ -70,4 +80,29 @@ pub mod test_set_builder {
         assert_eq!(result.values.len(), 2);
         assert_eq!(result.statement, "SET email = ?, password = ?");
     }
+
+    #[tokio::test]
+    async fn test_builder_placeholder_kind_conflict() {
+        let mut select_builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        select_builder
+            .table("users", "u")
+            .columns("u", vec!["email"]);
+        let result = SetBuilder::build(vec![SetFieldUpdate {
+            field: "email".to_string(),
+            value: SetValue::Query(select_builder),
+        }]);
+        assert!(
+            matches!(
+                result,
+                Err(Error::PlaceholderKindConflict {
+                    builder: "SetBuilder",
+                    ref field,
+                    expected: PlaceholderKind::QuestionMark,
+                    found: PlaceholderKind::DollarSequential,
+                }) if field == "email"
+            ),
+            "{:?}",
+            result
+        );
+    }
 }
@@ -0,0 +1,361 @@
+use crate::dialect::Postgres;
+use crate::error::{Error, Result};
+use crate::placeholder::number_placeholders;
+use crate::postgres::delete_builder::DeleteBuilder;
+use crate::postgres::insert_builder::InsertBuilder;
+use crate::postgres::{SelectBuilder, UpdateBuilder};
+use ::sqlx::postgres::{PgArguments, PgQueryResult, PgRow};
+use ::sqlx::query::{Query, QueryAs};
+use ::sqlx::{AssertSqlSafe, Executor, FromRow};
+use serde_json::Value;
+use std::future::Future;
+
+/// Binds JSON values to a `Query` or a `QueryAs`, by their type.
+macro_rules! bind_values {
+    ($query:expr, $values:expr) => {
+        $values
+            .into_iter()
+            .fold($query, |query, value| match value {
+                Value::Null => query.bind(None::<Untyped>),
+                Value::Bool(value) => query.bind(value),
+                Value::Number(number) => match number.as_i64() {
+                    Some(value) => query.bind(value),
+                    None => query.bind(number.as_f64().unwrap_or_default()),
+                },
+                Value::String(value) => query.bind(value),
+                value => query.bind(value),
+            })
+    };
+}
+
+/// Runs the PostgreSQL builders with sqlx, binding their values in order.
+///
+/// Values are bound by their JSON type: integers as `int8`, other numbers as `float8`,
+/// strings as `text`, booleans as `bool`, and objects and arrays as `jsonb`. `NULL` is
+/// left untyped so PostgreSQL infers it. Array values of `IN (?)` are expanded into one
+/// placeholder per item, and arrays cast as `?::int4[]` are bound as array literals.
+/// Statements are always numbered `$1`, `$2`, ... whatever the `placeholder_kind`.
+///
+/// # Example
+/// ```ignore
+/// let rows = builder.fetch_all(&pool).await?;
+/// let users: Vec<User> = builder.to_query_as::<User>()?.fetch_all(&pool).await?;
+/// ```
+pub trait SqlxQuery {
+    /// Builds the statement with its `$N` placeholders and the values to bind, in order.
+    fn build_sqlx(&self) -> Result<(String, Vec<Value>)>;
+
+    fn to_query(&self) -> Result<Query<'static, ::sqlx::Postgres, PgArguments>> {
+        let (statement, values) = self.build_sqlx()?;
+        Ok(bind_values!(
+            ::sqlx::query(AssertSqlSafe(statement)),
+            values
+        ))
+    }
+
+    fn to_query_as<T>(&self) -> Result<QueryAs<'static, ::sqlx::Postgres, T, PgArguments>>
+    where
+        T: for<'r> FromRow<'r, PgRow>,
+    {
+        let (statement, values) = self.build_sqlx()?;
+        Ok(bind_values!(
+            ::sqlx::query_as(AssertSqlSafe(statement)),
+            values
+        ))
+    }
+
+    fn execute<'e, 'c: 'e, E>(
+        &self,
+        executor: E,
+    ) -> impl Future<Output = std::result::Result<PgQueryResult, ::sqlx::Error>> + Send + 'e
+    where
+        E: 'e + Executor<'c, Database = ::sqlx::Postgres>,
+    {
+        let query = self.to_query();
+        async move { query.map_err(builder_error)?.execute(executor).await }
+    }
+
+    fn fetch_one<'e, 'c: 'e, E>(
+        &self,
+        executor: E,
+    ) -> impl Future<Output = std::result::Result<PgRow, ::sqlx::Error>> + Send + 'e
+    where
+        E: 'e + Executor<'c, Database = ::sqlx::Postgres>,
+    {
+        let query = self.to_query();
+        async move { query.map_err(builder_error)?.fetch_one(executor).await }
+    }
+
+    fn fetch_all<'e, 'c: 'e, E>(
+        &self,
+        executor: E,
+    ) -> impl Future<Output = std::result::Result<Vec<PgRow>, ::sqlx::Error>> + Send + 'e
+    where
+        E: 'e + Executor<'c, Database = ::sqlx::Postgres>,
+    {
+        let query = self.to_query();
+        async move { query.map_err(builder_error)?.fetch_all(executor).await }
+    }
+
+    fn fetch_one_as<'e, 'c: 'e, E, T>(
+        &self,
+        executor: E,
+    ) -> impl Future<Output = std::result::Result<T, ::sqlx::Error>> + Send + 'e
+    where
+        E: 'e + Executor<'c, Database = ::sqlx::Postgres>,
+        T: for<'r> FromRow<'r, PgRow> + Send + Unpin + 'e,
+    {
+        let query = self.to_query_as::<T>();
+        async move { query.map_err(builder_error)?.fetch_one(executor).await }
+    }
+
+    fn fetch_all_as<'e, 'c: 'e, E, T>(
+        &self,
+        executor: E,
+    ) -> impl Future<Output = std::result::Result<Vec<T>, ::sqlx::Error>> + Send + 'e
+    where
+        E: 'e + Executor<'c, Database = ::sqlx::Postgres>,
+        T: for<'r> FromRow<'r, PgRow> + Send + Unpin + 'e,
+    {
+        let query = self.to_query_as::<T>();
+        async move { query.map_err(builder_error)?.fetch_all(executor).await }
+    }
+}
+
+impl SqlxQuery for SelectBuilder {
+    fn build_sqlx(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "SelectBuilder",
+            &self.render(&Postgres)?,
+            self.get_values(),
+            &Postgres,
+        )
+    }
+}
+
+impl SqlxQuery for InsertBuilder {
+    fn build_sqlx(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "InsertBuilder",
+            &self.render(&Postgres)?,
+            self.get_values().concat(),
+            &Postgres,
+        )
+    }
+}
+
+impl SqlxQuery for UpdateBuilder {
+    fn build_sqlx(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "UpdateBuilder",
+            &self.render(&Postgres)?,
+            self.get_values(),
+            &Postgres,
+        )
+    }
+}
+
+impl SqlxQuery for DeleteBuilder {
+    fn build_sqlx(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "DeleteBuilder",
+            &self.render(&Postgres)?,
+            self.get_values(),
+            &Postgres,
+        )
+    }
+}
+
+/// The builder errors are reported as sqlx encoding errors by the helpers, the
+/// [`Error`] can be downcast from the source.
+fn builder_error(error: Error) -> ::sqlx::Error {
+    ::sqlx::Error::Encode(Box::new(error))
+}
+
+/// A `NULL` sent without a type, so PostgreSQL infers it from the statement and a
+/// `NULL` fits any column.
+struct Untyped;
+
+impl ::sqlx::Type<::sqlx::Postgres> for Untyped {
+    fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+        ::sqlx::postgres::PgTypeInfo::with_oid(::sqlx::postgres::types::Oid(0))
+    }
+}
+
+impl ::sqlx::Encode<'_, ::sqlx::Postgres> for Untyped {
+    fn encode_by_ref(
+        &self,
+        _: &mut ::sqlx::postgres::PgArgumentBuffer,
+    ) -> std::result::Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
+        Ok(::sqlx::encode::IsNull::Yes)
+    }
+}
+
+#[cfg(test)]
+pub mod test_sqlx {
+    use super::*;
+    use crate::placeholder::PlaceholderKind;
+    use crate::postgres::{Column, Conditions, SetFieldUpdate, SetValue, col};
+    use crate::value::{SqlType, SqlValue};
+    use ::sqlx::Row;
+    use ::sqlx::postgres::PgPoolOptions;
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_build_sqlx() {
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                col("u", "data").jsonb_has_key("email").build().unwrap(),
+            ]);
+        assert_eq!(
+            builder.build_sqlx(),
+            Ok((
+                "SELECT u.id FROM users as u WHERE u.data ? $1".to_string(),
+                vec![json!("email")]
+            ))
+        );
+    }
+
+    /// Runs the builders against the database of `DATABASE_URL`, e.g. the one of
+    /// `develop/docker-compose.yml`, with `cargo test --features sqlx -- --ignored`.
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+    async fn test_sqlx_postgres() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL is not set");
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+        let mut tx = pool.begin().await.unwrap();
+        ::sqlx::raw_sql(
+            "CREATE TEMP TABLE sqlx_users (
+                id SERIAL PRIMARY KEY,
+                name TEXT NOT NULL,
+                age INT,
+                score NUMERIC(6, 2),
+                external_id UUID,
+                tags TEXT[],
+                profile JSONB,
+                created_at TIMESTAMPTZ
+            )",
+        )
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+
+        let external_id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let created_at = DateTime::parse_from_rfc3339("2025-03-01T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut builder = InsertBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("sqlx_users")
+            .columns(vec![
+                "name",
+                "age",
+                "score",
+                "external_id",
+                "tags",
+                "profile",
+                "created_at",
+            ])
+            .values_typed(vec![
+                SqlValue::from("O'Brien"),
+                SqlValue::from(41),
+                SqlValue::Numeric("12.50".to_string()),
+                SqlValue::from(external_id),
+                SqlValue::array(SqlType::Text, vec!["a", "b\"c"]),
+                SqlValue::from(json!({"theme": "dark"})),
+                SqlValue::from(created_at),
+            ])
+            .unwrap()
+            .values(vec![
+                json!("Ann"),
+                json!(null),
+                json!(3.25),
+                json!(null),
+                json!(null),
+                json!({"theme": "light"}),
+                json!(null),
+            ])
+            .unwrap();
+        let ids: Vec<(i32,)> = builder
+            .returning(vec!["id"])
+            .fetch_all_as(&mut *tx)
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![(1,), (2,)]);
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("sqlx_users", "u")
+            .columns(
+                "u",
+                vec!["name", "age", "external_id", "tags", "created_at"],
+            )
+            .filter(vec![
+                col("u", "id")
+                    .in_list([1, 2])
+                    .and(col("u", "profile").jsonb_contains(json!({"theme": "dark"})))
+                    .build()
+                    .unwrap(),
+            ]);
+        let row = builder.fetch_one(&mut *tx).await.unwrap();
+        assert_eq!(row.get::<String, _>("name"), "O'Brien");
+        assert_eq!(row.get::<Option<i32>, _>("age"), Some(41));
+        assert_eq!(row.get::<Uuid, _>("external_id"), external_id);
+        assert_eq!(row.get::<Vec<String>, _>("tags"), vec!["a", "b\"c"]);
+        assert_eq!(row.get::<DateTime<Utc>, _>("created_at"), created_at);
+
+        let mut builder = UpdateBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("sqlx_users")
+            .set(vec![
+                SetFieldUpdate {
+                    field: "age".to_string(),
+                    value: SetValue::Value(json!(30)),
+                },
+                SetFieldUpdate {
+                    field: "created_at".to_string(),
+                    value: SetValue::Typed(SqlValue::from(created_at)),
+                },
+            ])
+            .unwrap()
+            .filter(vec![col("", "name").eq("Ann").build().unwrap()]);
+        let result = builder.execute(&mut *tx).await.unwrap();
+        assert_eq!(result.rows_affected(), 1);
+
+        let mut builder = DeleteBuilder::new(PlaceholderKind::QuestionMark);
+        builder.table("sqlx_users", None).filter(vec![
+            Conditions::from(
+                Column::<DateTime<Utc>>::new("sqlx_users", None, "created_at").eq(created_at),
+            )
+            .build()
+            .unwrap(),
+        ]);
+        let mut names: Vec<(String, i32)> = builder
+            .returning(vec!["name", "age"])
+            .fetch_all_as(&mut *tx)
+            .await
+            .unwrap();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![("Ann".to_string(), 30), ("O'Brien".to_string(), 41)]
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("sqlx_users", "u")
+            .columns_raw(vec!["count(*)"]);
+        let count: (i64,) = builder.fetch_one_as(&mut *tx).await.unwrap();
+        assert_eq!(count, (0,));
+        tx.rollback().await.unwrap();
+    }
+}
@@ -0,0 +1,597 @@
+use crate::dialect::Postgres;
+use crate::error::{Error, Result};
+use crate::placeholder::{number_placeholders, split_cast};
+use crate::postgres::delete_builder::DeleteBuilder;
+use crate::postgres::insert_builder::InsertBuilder;
+use crate::postgres::{SelectBuilder, UpdateBuilder};
+use ::tokio_postgres::types::{IsNull, Kind, ToSql, Type, to_sql_checked};
+use ::tokio_postgres::{Client, Row, Statement};
+use bytes::{BufMut, BytesMut};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use thiserror::Error as ThisError;
+
+/// Errors of the [`StatementCache`] helpers, from the builder or from the database.
+#[derive(Debug, ThisError)]
+pub enum QueryError {
+    #[error(transparent)]
+    Builder(#[from] Error),
+
+    #[error(transparent)]
+    Postgres(#[from] ::tokio_postgres::Error),
+}
+
+/// Runs the PostgreSQL builders with tokio-postgres.
+///
+/// Each JSON value becomes a parameter of the closest PostgreSQL type: integers are
+/// `int8`, other numbers `float8`, strings `text`, booleans `bool`, objects `jsonb`, and
+/// arrays typed arrays (`int8[]`, `float8[]`, `text[]`, `bool[]`) when their items share a
+/// type, `jsonb` otherwise. Values whose placeholder is cast as `$N::jsonb`, such as the
+/// documents of the JSONB `@>`, `<@` and `||` operators, stay `jsonb` whatever their type.
+/// `NULL` and empty arrays are left untyped so PostgreSQL infers them. Numbers also fit
+/// the `int2`, `int4` and `float4` parameters PostgreSQL infers for the columns they are
+/// compared to. Statements are numbered as by [`number_placeholders`].
+///
+/// # Example
+/// ```ignore
+/// let (statement, params) = builder.to_tokio_postgres()?;
+/// let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|param| param.as_ref()).collect();
+/// let rows = client.query(&statement, &params).await?;
+/// ```
+pub trait TokioPostgresQuery {
+    /// Builds the statement with its `$N` placeholders and the values to bind, in order.
+    fn build_tokio_postgres(&self) -> Result<(String, Vec<Value>)>;
+
+    fn to_tokio_postgres(&self) -> Result<(String, Vec<Box<dyn ToSql + Sync>>)> {
+        let (statement, values) = self.build_tokio_postgres()?;
+        let params = params(&statement, values)
+            .into_iter()
+            .map(|param| Box::new(param) as Box<dyn ToSql + Sync>)
+            .collect();
+        Ok((statement, params))
+    }
+}
+
+impl TokioPostgresQuery for SelectBuilder {
+    fn build_tokio_postgres(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "SelectBuilder",
+            &self.render(&Postgres)?,
+            self.get_values(),
+            &Postgres,
+        )
+    }
+}
+
+impl TokioPostgresQuery for InsertBuilder {
+    fn build_tokio_postgres(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "InsertBuilder",
+            &self.render(&Postgres)?,
+            self.get_values().concat(),
+            &Postgres,
+        )
+    }
+}
+
+impl TokioPostgresQuery for UpdateBuilder {
+    fn build_tokio_postgres(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "UpdateBuilder",
+            &self.render(&Postgres)?,
+            self.get_values(),
+            &Postgres,
+        )
+    }
+}
+
+impl TokioPostgresQuery for DeleteBuilder {
+    fn build_tokio_postgres(&self) -> Result<(String, Vec<Value>)> {
+        number_placeholders(
+            "DeleteBuilder",
+            &self.render(&Postgres)?,
+            self.get_values(),
+            &Postgres,
+        )
+    }
+}
+
+/// A tokio-postgres client preparing the statements of the builders once.
+///
+/// Statements are cached by their SQL text and parameter types, which are declared from
+/// the values, so a `NULL` or a float in place of an integer prepares another statement.
+/// Prepared statements belong to their connection, hence the cache owns the client.
+///
+/// The cache holds at most `capacity` statements, [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY)
+/// with [`new`](Self::new). It is cleared when full, closing the statements on the
+/// server, so queries built with varying `IN` lists or filters don't grow it without end.
+///
+/// # Example
+/// ```ignore
+/// let cache = StatementCache::new(client);
+/// let rows = cache.query(&builder).await?;
+/// ```
+pub struct StatementCache {
+    client: Client,
+    capacity: usize,
+    statements: Mutex<HashMap<(String, Vec<Type>), Statement>>,
+}
+
+impl StatementCache {
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    pub fn new(client: Client) -> Self {
+        Self::with_capacity(client, Self::DEFAULT_CAPACITY)
+    }
+
+    pub fn with_capacity(client: Client, capacity: usize) -> Self {
+        Self {
+            client,
+            capacity: capacity.max(1),
+            statements: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The number of prepared statements.
+    pub fn len(&self) -> usize {
+        self.statements.lock().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops the prepared statements, e.g. after a schema change.
+    pub fn clear(&self) {
+        if let Ok(mut statements) = self.statements.lock() {
+            statements.clear();
+        }
+    }
+
+    /// Returns the prepared statement of the SQL text and parameter types, preparing it
+    /// on first use.
+    pub async fn prepare(
+        &self,
+        statement: &str,
+        types: &[Type],
+    ) -> std::result::Result<Statement, ::tokio_postgres::Error> {
+        let key = (statement.to_string(), types.to_vec());
+        if let Some(prepared) = self
+            .statements
+            .lock()
+            .ok()
+            .and_then(|v| v.get(&key).cloned())
+        {
+            return Ok(prepared);
+        }
+        let prepared = self.client.prepare_typed(statement, types).await?;
+        if let Ok(mut statements) = self.statements.lock() {
+            if statements.len() >= self.capacity {
+                statements.clear();
+            }
+            statements.insert(key, prepared.to_owned());
+        }
+        Ok(prepared)
+    }
+
+    async fn prepare_builder(
+        &self,
+        builder: &impl TokioPostgresQuery,
+    ) -> std::result::Result<(Statement, Vec<Param>), QueryError> {
+        let (statement, values) = builder.build_tokio_postgres()?;
+        let params = params(&statement, values);
+        let types: Vec<Type> = params.iter().map(Param::sql_type).collect();
+        Ok((self.prepare(&statement, &types).await?, params))
+    }
+
+    pub async fn query(
+        &self,
+        builder: &impl TokioPostgresQuery,
+    ) -> std::result::Result<Vec<Row>, QueryError> {
+        let (statement, params) = self.prepare_builder(builder).await?;
+        Ok(self.client.query(&statement, &refs(&params)).await?)
+    }
+
+    pub async fn query_one(
+        &self,
+        builder: &impl TokioPostgresQuery,
+    ) -> std::result::Result<Row, QueryError> {
+        let (statement, params) = self.prepare_builder(builder).await?;
+        Ok(self.client.query_one(&statement, &refs(&params)).await?)
+    }
+
+    /// Runs the statement, returning the number of rows it modified.
+    pub async fn execute(
+        &self,
+        builder: &impl TokioPostgresQuery,
+    ) -> std::result::Result<u64, QueryError> {
+        let (statement, params) = self.prepare_builder(builder).await?;
+        Ok(self.client.execute(&statement, &refs(&params)).await?)
+    }
+}
+
+fn refs(params: &[Param]) -> Vec<&(dyn ToSql + Sync)> {
+    params
+        .iter()
+        .map(|param| param as &(dyn ToSql + Sync))
+        .collect()
+}
+
+/// The `::type` casts of the `$N` placeholders of a numbered statement, by `N`. Quoted
+/// text is skipped.
+fn casts(statement: &str) -> HashMap<usize, String> {
+    let mut casts: HashMap<usize, String> = HashMap::new();
+    let mut quote: Option<char> = None;
+    let mut previous: Option<char> = None;
+    for (index, c) in statement.char_indices() {
+        match quote {
+            Some(value) if c == value => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if c == '$'
+                && !previous.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') =>
+            {
+                let rest = &statement[index + 1..];
+                let end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                if let (Ok(position), (Some(cast), _)) =
+                    (rest[..end].parse(), split_cast(&rest[end..]))
+                {
+                    casts.insert(position, cast.to_ascii_lowercase());
+                }
+            }
+            None => {}
+        }
+        previous = Some(c);
+    }
+    casts
+}
+
+/// Turns the values of a numbered statement into parameters, keeping the values cast as
+/// `jsonb` or `json` as JSON documents rather than typed arrays or text.
+fn params(statement: &str, values: Vec<Value>) -> Vec<Param> {
+    let casts = casts(statement);
+    values
+        .into_iter()
+        .enumerate()
+        .map(
+            |(index, value)| match casts.get(&(index + 1)).map(String::as_str) {
+                Some("jsonb" | "json") if !value.is_null() => Param::Jsonb(value),
+                _ => Param::from(value),
+            },
+        )
+        .collect()
+}
+
+/// A JSON value as the Rust type it's bound as.
+#[derive(Debug, Clone, PartialEq)]
+enum Param {
+    Null,
+    Bool(bool),
+    Int8(i64),
+    Float8(f64),
+    Text(String),
+    Jsonb(Value),
+    BoolArray(Vec<Option<bool>>),
+    Int8Array(Vec<Option<i64>>),
+    Float8Array(Vec<Option<f64>>),
+    TextArray(Vec<Option<String>>),
+    EmptyArray, // Typed by PostgreSQL, as `NULL`
+}
+
+impl Param {
+    fn sql_type(&self) -> Type {
+        match self {
+            Self::Null | Self::EmptyArray => Type::UNKNOWN,
+            Self::Bool(_) => Type::BOOL,
+            Self::Int8(_) => Type::INT8,
+            Self::Float8(_) => Type::FLOAT8,
+            Self::Text(_) => Type::TEXT,
+            Self::Jsonb(_) => Type::JSONB,
+            Self::BoolArray(_) => Type::BOOL_ARRAY,
+            Self::Int8Array(_) => Type::INT8_ARRAY,
+            Self::Float8Array(_) => Type::FLOAT8_ARRAY,
+            Self::TextArray(_) => Type::TEXT_ARRAY,
+        }
+    }
+
+    /// Types an array from its items, `NULL`s aside, or `None` when they don't share one.
+    fn array(items: &[Value]) -> Option<Self> {
+        let present = || items.iter().filter(|item| !item.is_null());
+        if items.is_empty() {
+            return Some(Self::EmptyArray);
+        }
+        let value = match present().next()? {
+            Value::Bool(_) if present().all(Value::is_boolean) => {
+                Self::BoolArray(items.iter().map(Value::as_bool).collect())
+            }
+            Value::Number(_) if present().all(|item| item.is_i64()) => {
+                Self::Int8Array(items.iter().map(Value::as_i64).collect())
+            }
+            Value::Number(_) if present().all(Value::is_number) => {
+                Self::Float8Array(items.iter().map(Value::as_f64).collect())
+            }
+            Value::String(_) if present().all(Value::is_string) => Self::TextArray(
+                items
+                    .iter()
+                    .map(|item| item.as_str().map(str::to_string))
+                    .collect(),
+            ),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+impl From<Value> for Param {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(value) => Self::Bool(value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => Self::Int8(value),
+                None => Self::Float8(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(value) => Self::Text(value),
+            Value::Array(items) => Self::array(&items).unwrap_or(Self::Jsonb(Value::Array(items))),
+            value => Self::Jsonb(value),
+        }
+    }
+}
+
+impl ToSql for Param {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        match self {
+            Self::Null => Ok(IsNull::Yes),
+            Self::Bool(value) => value.to_sql_checked(ty, out),
+            // Numbers fit the narrower types PostgreSQL infers from a column
+            Self::Int8(value) if *ty == Type::INT2 => i16::try_from(*value)?.to_sql(ty, out),
+            Self::Int8(value) if *ty == Type::INT4 => i32::try_from(*value)?.to_sql(ty, out),
+            Self::Int8(value) if *ty == Type::FLOAT8 => (*value as f64).to_sql(ty, out),
+            Self::Int8(value) => value.to_sql_checked(ty, out),
+            Self::Float8(value) if *ty == Type::FLOAT4 => (*value as f32).to_sql(ty, out),
+            Self::Float8(value) => value.to_sql_checked(ty, out),
+            Self::Text(value) => value.to_sql_checked(ty, out),
+            Self::Jsonb(value) => value.to_sql_checked(ty, out),
+            Self::BoolArray(value) => value.to_sql_checked(ty, out),
+            Self::Int8Array(value) => value.to_sql_checked(ty, out),
+            Self::Float8Array(value) => value.to_sql_checked(ty, out),
+            Self::TextArray(value) => value.to_sql_checked(ty, out),
+            Self::EmptyArray => match ty.kind() {
+                // No dimension, no NULL, then the OID of the items
+                Kind::Array(member) => {
+                    out.put_i32(0);
+                    out.put_i32(0);
+                    out.put_u32(member.oid());
+                    Ok(IsNull::No)
+                }
+                _ => Value::Array(Vec::new()).to_sql_checked(ty, out),
+            },
+        }
+    }
+
+    /// Any type is accepted here, each value checks the type it's bound to.
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+pub mod test_tokio_postgres {
+    use super::*;
+    use crate::placeholder::PlaceholderKind;
+    use crate::postgres::{SetFieldUpdate, SetValue, col};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_param() {
+        let cases: Vec<(Value, Param, Type)> = vec![
+            (json!(null), Param::Null, Type::UNKNOWN),
+            (json!(7), Param::Int8(7), Type::INT8),
+            (json!(1.5), Param::Float8(1.5), Type::FLOAT8),
+            (json!("a"), Param::Text("a".to_string()), Type::TEXT),
+            (json!({"a": 1}), Param::Jsonb(json!({"a": 1})), Type::JSONB),
+            (
+                json!([1, null, 2]),
+                Param::Int8Array(vec![Some(1), None, Some(2)]),
+                Type::INT8_ARRAY,
+            ),
+            (
+                json!([1, 2.5]),
+                Param::Float8Array(vec![Some(1.0), Some(2.5)]),
+                Type::FLOAT8_ARRAY,
+            ),
+            (
+                json!(["a", "b"]),
+                Param::TextArray(vec![Some("a".to_string()), Some("b".to_string())]),
+                Type::TEXT_ARRAY,
+            ),
+            (
+                json!([true]),
+                Param::BoolArray(vec![Some(true)]),
+                Type::BOOL_ARRAY,
+            ),
+            (json!([1, "a"]), Param::Jsonb(json!([1, "a"])), Type::JSONB),
+            (json!([]), Param::EmptyArray, Type::UNKNOWN),
+            (json!([[1]]), Param::Jsonb(json!([[1]])), Type::JSONB),
+        ];
+        for (value, param, sql_type) in cases {
+            let result = Param::from(value);
+            assert_eq!(result.sql_type(), sql_type, "{result:?}");
+            assert_eq!(result, param);
+        }
+
+        let mut out = BytesMut::new();
+        let result = Param::Int8(1 << 40).to_sql_checked(&Type::INT4, &mut out);
+        assert!(
+            result.is_err(),
+            "expecting an out of range int4 to be rejected"
+        );
+        let result = Param::Text("a".to_string()).to_sql_checked(&Type::INT4, &mut out);
+        assert!(result.is_err(), "expecting text to be rejected for an int4");
+    }
+
+    #[tokio::test]
+    async fn test_params_jsonb() {
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("products", "p")
+            .columns("p", vec!["id"])
+            .filter(vec![
+                col("p", "details")
+                    .jsonb_contains(json!(["home", "light"]))
+                    .and(col("p", "tags").jsonb_has_any_keys(["a"]))
+                    .and(col("p", "id").eq(1))
+                    .build()
+                    .unwrap(),
+            ]);
+        let (statement, values) = builder.build_tokio_postgres().unwrap();
+        assert_eq!(
+            statement,
+            "SELECT p.id FROM products as p WHERE p.details @> $1::jsonb AND p.tags ?| $2::text[] AND p.id = $3"
+        );
+        let result = params(&statement, values);
+        assert_eq!(
+            result,
+            vec![
+                Param::Jsonb(json!(["home", "light"])),
+                Param::Text("{\"a\"}".to_string()),
+                Param::Int8(1),
+            ]
+        );
+        let types: Vec<Type> = result.iter().map(Param::sql_type).collect();
+        assert_eq!(types, vec![Type::JSONB, Type::TEXT, Type::INT8]);
+
+        let result = params(
+            "SELECT '$1::jsonb', $1::JSONB, $2",
+            vec![json!([1]), json!([1])],
+        );
+        assert_eq!(
+            result,
+            vec![Param::Jsonb(json!([1])), Param::Int8Array(vec![Some(1)])]
+        );
+    }
+
+    /// Runs the builders against the database of `DATABASE_URL`, e.g. the one of
+    /// `develop/docker-compose.yml`, with `cargo test --features tokio-postgres -- --ignored`.
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+    async fn test_tokio_postgres() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL is not set");
+        let (client, connection) = ::tokio_postgres::connect(&url, ::tokio_postgres::NoTls)
+            .await
+            .unwrap();
+        tokio::spawn(connection);
+        client
+            .batch_execute(
+                "CREATE TEMP TABLE tp_products (
+                    id SERIAL PRIMARY KEY,
+                    name TEXT NOT NULL,
+                    stock INT,
+                    weight REAL,
+                    tags TEXT[],
+                    details JSONB
+                )",
+            )
+            .await
+            .unwrap();
+        let cache = StatementCache::new(client);
+
+        let mut builder = InsertBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("tp_products")
+            .columns(vec!["name", "stock", "weight", "tags", "details"])
+            .values(vec![
+                json!("lamp"),
+                json!(3),
+                json!(1.5),
+                json!(["home", "light"]),
+                json!({"color": "red"}),
+            ])
+            .unwrap()
+            .values(vec![
+                json!("desk"),
+                json!(null),
+                json!(null),
+                json!([]),
+                json!(null),
+            ])
+            .unwrap();
+        assert_eq!(cache.execute(&builder).await.unwrap(), 2);
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("tp_products", "p")
+            .columns("p", vec!["name", "stock", "weight", "tags", "details"])
+            .filter(vec![
+                col("p", "name").in_list(["lamp", "chair"]).build().unwrap(),
+            ]);
+        let row = cache.query_one(&builder).await.unwrap();
+        assert_eq!(row.get::<_, String>("name"), "lamp");
+        assert_eq!(row.get::<_, Option<i32>>("stock"), Some(3));
+        assert_eq!(row.get::<_, Option<f32>>("weight"), Some(1.5));
+        assert_eq!(row.get::<_, Vec<String>>("tags"), vec!["home", "light"]);
+        assert_eq!(row.get::<_, Value>("details"), json!({"color": "red"}));
+        cache.query_one(&builder).await.unwrap();
+        assert_eq!(cache.len(), 2, "expecting the select to be prepared once");
+        cache.clear();
+        assert!(cache.is_empty());
+
+        // A JSON array cast as `jsonb` is bound as a document, not as a `text[]`
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("tp_products", "p")
+            .columns("p", vec!["name"])
+            .filter(vec![
+                col("p", "details")
+                    .jsonb_contains(json!(["red"]))
+                    .build()
+                    .unwrap(),
+            ]);
+        assert!(cache.query(&builder).await.unwrap().is_empty());
+        let (statement, params) = builder.to_tokio_postgres().unwrap();
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|param| param.as_ref()).collect();
+        assert!(
+            cache
+                .client()
+                .query(&statement, &params)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        cache.clear();
+
+        let mut builder = UpdateBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("tp_products")
+            .set(vec![SetFieldUpdate {
+                field: "stock".to_string(),
+                value: SetValue::Value(json!(10)),
+            }])
+            .unwrap()
+            .filter(vec![col("", "name").eq("desk").build().unwrap()]);
+        assert_eq!(cache.execute(&builder).await.unwrap(), 1);
+
+        // Without the cache, PostgreSQL infers the int4 of the column for the parameter
+        let mut builder = DeleteBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("tp_products", None)
+            .filter(vec![col("", "stock").gte(10).build().unwrap()]);
+        let (statement, params) = builder.to_tokio_postgres().unwrap();
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|param| param.as_ref()).collect();
+        let deleted = cache.client().execute(&statement, &params).await.unwrap();
+        assert_eq!(deleted, 1);
+    }
+}
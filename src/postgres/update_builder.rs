@@ -1,6 +1,13 @@
-use crate::placeholder::PlaceholderKind;
-use crate::postgres::{ExpressionBuilder, SetBuilder, SetFieldUpdate, WhereBuilder};
-use anyhow::anyhow;
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::literal::interpolate;
+use crate::placeholder::{Params, PlaceholderKind, bind_named, check_unnamed};
+use crate::postgres::{
+    ConditionBuilder, ConditionValue, ExpressionBuilder, Logic, Operator, SetBuilder,
+    SetFieldUpdate, SetValue, WhereBuilder,
+};
+use crate::table::Table;
+use crate::value::SqlValue;
 use serde_json::Value;
 
 #[derive(Clone, Debug, Default)]
@@ -11,6 +18,7 @@ pub struct UpdateBuilder {
     set_statement: String,
     filter_statement: Option<String>,
     returning_statement: Option<String>,
+    pub(crate) operators: Vec<Operator>,
     pub placeholder_kind: PlaceholderKind,
 }
 
@@ -29,6 +37,9 @@ impl UpdateBuilder {
 
     pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
         if !values.is_empty() {
+            for item in &values {
+                self.operators.append(&mut item.operators.to_owned());
+            }
             let mut result = WhereBuilder::build(values);
             self.filter_statement = Some(result.statement);
             if !result.values.is_empty() {
@@ -38,9 +49,12 @@ impl UpdateBuilder {
         self
     }
 
-    pub fn set(&mut self, values: Vec<SetFieldUpdate>) -> anyhow::Result<&mut Self> {
+    pub fn set(&mut self, values: Vec<SetFieldUpdate>) -> Result<&mut Self> {
         if !self.set_statement.is_empty() {
-            return Err(anyhow!("`.set()` can only be calld once"));
+            return Err(Error::DuplicateClause {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
         }
         let mut builder = SetBuilder::build(values)?;
         self.set_statement = builder.statement;
@@ -48,6 +62,59 @@ impl UpdateBuilder {
         Ok(self)
     }
 
+    /// Updates the row of a [`Table`]: sets the table, SETs its columns other than the
+    /// primary key to their typed values, and filters on the primary key. Errors when the
+    /// table has no primary key, as the update would touch every row, or when a key is
+    /// NULL, as it would touch none.
+    ///
+    /// # Example
+    /// ```ignore
+    /// set_from(&user)?; // UPDATE "user" SET name = ?, email = ? WHERE id = ?
+    /// ```
+    pub fn set_from<T: Table>(&mut self, row: &T) -> Result<&mut Self> {
+        let keys = T::primary_key();
+        if keys.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "primary_key".to_string(),
+            });
+        }
+        let mut set = Vec::new();
+        let mut conditions = Vec::new();
+        for (field, value) in T::columns().into_iter().zip(row.values()?) {
+            if keys.contains(&field) {
+                if matches!(value, SqlValue::Null) {
+                    return Err(Error::InvalidValue {
+                        builder: "UpdateBuilder",
+                        field: field.to_string(),
+                        message: "the primary key is NULL".to_string(),
+                    });
+                }
+                conditions.push(ConditionBuilder {
+                    table_alias: None,
+                    field: field.to_string(),
+                    operator: Operator::Eq,
+                    value: Some(ConditionValue::Typed(value)),
+                    logic: (!conditions.is_empty()).then_some(Logic::And),
+                });
+            } else {
+                set.push(SetFieldUpdate {
+                    field: field.to_string(),
+                    value: SetValue::Typed(value),
+                });
+            }
+        }
+        if set.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        self.table(T::table_name()).set(set)?;
+        let expression = ExpressionBuilder::build(conditions, None)?;
+        Ok(self.filter(vec![expression]))
+    }
+
     pub fn get_values(&self) -> Vec<Value> {
         self.values.to_owned()
     }
@@ -66,34 +133,71 @@ impl UpdateBuilder {
         self
     }
 
-    pub fn build(&self) -> anyhow::Result<String> {
-        let mut statement = format!("UPDATE {} {}", self.table, self.set_statement);
-        let mut value_counter: usize = 0;
-
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self, dialect: &dyn Dialect) -> Result<String> {
+        if self.returning_statement.is_some() && !dialect.supports_returning() {
+            return Err(Error::UnsupportedClause {
+                builder: "UpdateBuilder",
+                field: "returning".to_string(),
+                target: dialect.name().to_string(),
+            });
+        }
+        let mut statement = format!(
+            "UPDATE {} {}",
+            dialect.quote_qualified(&self.table),
+            self.set_statement
+        );
         if let Some(stmt) = &self.filter_statement {
             statement.push_str(&format!(" {stmt}"));
         };
         if let Some(stmt) = &self.returning_statement {
             statement.push_str(&format!(" {stmt}"));
         }
+        Ok(statement.trim().to_string())
+    }
 
-        let values: Vec<String> = statement
-            .chars()
-            .map(|c| {
-                if Some(c) == char::from_u32(63) {
-                    match self.placeholder_kind {
-                        PlaceholderKind::QuestionMark => "?".to_string(),
-                        PlaceholderKind::DollarSequential => {
-                            value_counter += 1;
-                            format!("${value_counter}")
-                        }
-                    }
-                } else {
-                    c.to_string()
-                }
-            })
-            .collect();
-        Ok(values.join("").trim().to_string())
+    pub fn build(&self) -> Result<String> {
+        let statement = self.render(&Postgres)?;
+        check_unnamed("UpdateBuilder", &statement)?;
+        match self.placeholder_kind {
+            PlaceholderKind::QuestionMark => Ok(statement),
+            PlaceholderKind::DollarSequential => Ok(Postgres.render_placeholders(&statement)),
+        }
+    }
+
+    /// Renders the statement for the dialect, using its placeholders instead of the
+    /// `placeholder_kind`.
+    pub fn build_with(&self, dialect: &dyn Dialect) -> Result<String> {
+        dialect.check_operators("UpdateBuilder", &self.operators)?;
+        let statement = self.render(dialect)?;
+        check_unnamed("UpdateBuilder", &statement)?;
+        Ok(dialect.render_placeholders(&statement))
+    }
+
+    /// Builds the statement, binding its named parameters (`:name`, `@name`, `$name`)
+    /// from `params`. Returns the statement with the `placeholder_kind` placeholders and
+    /// all the values to bind, in order. Errors when a named parameter has no value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (statement, values) = builder.build_named(&Params::from([("email".to_string(), json!("x"))]))?;
+    /// ```
+    pub fn build_named(&self, params: &Params) -> Result<(String, Vec<Value>)> {
+        let statement = self.render(&Postgres)?;
+        bind_named(
+            "UpdateBuilder",
+            &statement,
+            self.get_values(),
+            params,
+            &self.placeholder_kind,
+        )
+    }
+
+    /// Renders the statement with its values interpolated as escaped literals, for logs
+    /// and `EXPLAIN` copy-paste. Not for execution with untrusted input, see
+    /// [`interpolate`].
+    pub fn build_interpolated(&self) -> Result<String> {
+        interpolate("UpdateBuilder", &self.render(&Postgres)?, &self.get_values())
     }
 }
 
@@ -131,6 +235,13 @@ pub mod test_update_builder {
         ]);
 
         assert!(set_err_result.is_err(), "cannot call set twice");
+        assert_eq!(
+            set_err_result.map(|_| ()),
+            Err(Error::DuplicateClause {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            })
+        );
     }
     #[tokio::test]
     async fn test_update_question_mark() {
@@ -260,4 +371,115 @@ pub mod test_update_builder {
         );
         assert_eq!(set_ok_result.get_values().len(), 3);
     }
+
+    #[tokio::test]
+    async fn test_update_build_with() {
+        use crate::dialect::{MySql, Sqlite};
+
+        let mut builder = UpdateBuilder::new(PlaceholderKind::DollarSequential);
+        let _ = builder
+            .table("users")
+            .set(vec![SetFieldUpdate {
+                field: "name".to_string(),
+                value: SetValue::Value(Value::String("Test Update 1".to_string())),
+            }])
+            .unwrap()
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: None,
+                        field: "id".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(Value::Number(1.into()))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .returning(vec!["id"]);
+        assert_eq!(
+            builder.build_with(&Sqlite),
+            Ok("UPDATE users SET name = ? WHERE id = ? RETURNING id".to_string())
+        );
+        assert!(
+            builder.build_with(&MySql).is_err(),
+            "expecting RETURNING error"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_set_from() {
+        use crate::table::test_table::{Draft, Event, OrderItem, Profile, User};
+        use serde_json::json;
+        use std::collections::HashMap;
+
+        let user = User {
+            id: 1,
+            name: "Juan".to_string(),
+            email: "jdc@test.com".to_string(),
+            nickname: None,
+            profile: Profile {
+                city: "Manila".to_string(),
+            },
+            order_count: 3,
+        };
+        let mut builder = UpdateBuilder::new(PlaceholderKind::DollarSequential);
+        let result = builder.set_from(&user).map(|builder| builder.build());
+        assert_eq!(
+            result,
+            Ok(Ok("UPDATE \"user\" SET name = $1, email_address = $2, nickname = $3, profile = $4::jsonb WHERE id = $5".to_string()))
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                json!("Juan"),
+                json!("jdc@test.com"),
+                json!(null),
+                json!({"city": "Manila"}),
+                json!(1),
+            ]
+        );
+
+        let item = OrderItem {
+            order_id: 7,
+            line: 2,
+            tags: vec!["gift".to_string()],
+        };
+        let mut builder = UpdateBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder.set_from(&item).map(|builder| builder.build());
+        assert_eq!(
+            result,
+            Ok(Ok("UPDATE app.order_lines SET tags = ?::text[] WHERE order_id = ? AND line = ?::int4".to_string()))
+        );
+
+        let event = Event {
+            kind: "click".to_string(),
+            counts: HashMap::new(),
+        };
+        let mut builder = UpdateBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder.set_from(&event).map(|_| ());
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "primary_key".to_string(),
+            })
+        );
+
+        let draft = Draft {
+            id: None,
+            title: "Notes".to_string(),
+        };
+        let mut builder = UpdateBuilder::new(PlaceholderKind::QuestionMark);
+        let result = builder.set_from(&draft).map(|_| ());
+        assert_eq!(
+            result,
+            Err(Error::InvalidValue {
+                builder: "UpdateBuilder",
+                field: "id".to_string(),
+                message: "the primary key is NULL".to_string(),
+            })
+        );
+    }
 }
@@ -0,0 +1,326 @@
+use crate::dialect::{Dialect, Postgres};
+use crate::error::{Error, Result};
+use crate::identifier::quote_identifier;
+use crate::postgres::SelectBuilder;
+
+/// Builds `CREATE [OR REPLACE] VIEW` and `CREATE MATERIALIZED VIEW` statements from a
+/// [`SelectBuilder`]. Views can't take bind parameters, so the values of the query are
+/// inlined as escaped literals, see [`SelectBuilder::build_inline`].
+///
+/// # Example
+/// ```ignore
+/// let mut builder = CreateViewBuilder::new();
+/// builder.name("active_users").or_replace().query(&select);
+/// // CREATE OR REPLACE VIEW active_users AS SELECT ... WHERE u.status = 'active'
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CreateViewBuilder {
+    pub name: String,
+    pub or_replace: bool,
+    pub materialized: bool,
+    pub if_not_exists: bool,
+    pub columns: Vec<String>,
+    pub with_data: Option<bool>, // WITH [NO] DATA, only for materialized views
+    pub query: Option<SelectBuilder>,
+}
+
+impl CreateViewBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the view name, optionally schema-qualified, e.g. `reporting.daily_totals`.
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn or_replace(&mut self) -> &mut Self {
+        self.or_replace = true;
+        self
+    }
+
+    pub fn materialized(&mut self) -> &mut Self {
+        self.materialized = true;
+        self
+    }
+
+    /// Only for materialized views, PostgreSQL has no `IF NOT EXISTS` for plain views.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Names the columns of the view instead of using the names of the query columns.
+    pub fn columns(&mut self, values: Vec<&str>) -> &mut Self {
+        self.columns = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    /// Populates the materialized view when created (`WITH DATA`), or leaves it empty
+    /// until refreshed (`WITH NO DATA`).
+    pub fn with_data(&mut self, value: bool) -> &mut Self {
+        self.with_data = Some(value);
+        self
+    }
+
+    pub fn query(&mut self, value: &SelectBuilder) -> &mut Self {
+        self.query = Some(value.to_owned());
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateViewBuilder",
+                field: "name".to_string(),
+            });
+        }
+        if self.query.is_none() {
+            return Err(Error::EmptyField {
+                builder: "CreateViewBuilder",
+                field: "query".to_string(),
+            });
+        }
+        let unsupported = |field: &str, target: &str| {
+            Err(Error::UnsupportedClause {
+                builder: "CreateViewBuilder",
+                field: field.to_string(),
+                target: target.to_string(),
+            })
+        };
+        if self.materialized && self.or_replace {
+            return unsupported("or_replace", "materialized views");
+        }
+        if !self.materialized && self.if_not_exists {
+            return unsupported("if_not_exists", "views");
+        }
+        if !self.materialized && self.with_data.is_some() {
+            return unsupported("with_data", "views");
+        }
+        Ok(())
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.validate()?;
+        let mut statement = "CREATE".to_string();
+        if self.or_replace {
+            statement = format!("{statement} OR REPLACE");
+        }
+        if self.materialized {
+            statement = format!("{statement} MATERIALIZED");
+        }
+        statement = format!("{statement} VIEW");
+        if self.if_not_exists {
+            statement = format!("{statement} IF NOT EXISTS");
+        }
+        statement = format!("{statement} {}", Postgres.quote_qualified(&self.name));
+        if !self.columns.is_empty() {
+            let columns = self
+                .columns
+                .iter()
+                .map(|column| quote_identifier(column))
+                .collect::<Vec<String>>();
+            statement = format!("{statement} ({})", columns.join(", "));
+        }
+        if let Some(query) = &self.query {
+            statement = format!("{statement} AS {}", query.build_inline()?);
+        }
+        match self.with_data {
+            Some(true) => statement = format!("{statement} WITH DATA"),
+            Some(false) => statement = format!("{statement} WITH NO DATA"),
+            None => {}
+        }
+        Ok(statement)
+    }
+}
+
+/// Builds `REFRESH MATERIALIZED VIEW` statements.
+///
+/// # Example
+/// ```ignore
+/// let mut builder = RefreshViewBuilder::new();
+/// builder.name("daily_totals").concurrently();
+/// // REFRESH MATERIALIZED VIEW CONCURRENTLY daily_totals
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RefreshViewBuilder {
+    pub name: String,
+    pub concurrently: bool,
+    pub with_data: Option<bool>,
+}
+
+impl RefreshViewBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Refreshes without locking out reads, which needs a unique index on the view.
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    pub fn with_data(&mut self, value: bool) -> &mut Self {
+        self.with_data = Some(value);
+        self
+    }
+
+    pub fn build(&self) -> Result<String> {
+        if self.name.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "RefreshViewBuilder",
+                field: "name".to_string(),
+            });
+        }
+        if self.concurrently && self.with_data == Some(false) {
+            return Err(Error::UnsupportedClause {
+                builder: "RefreshViewBuilder",
+                field: "concurrently".to_string(),
+                target: "WITH NO DATA".to_string(),
+            });
+        }
+        let mut statement = "REFRESH MATERIALIZED VIEW".to_string();
+        if self.concurrently {
+            statement = format!("{statement} CONCURRENTLY");
+        }
+        statement = format!("{statement} {}", Postgres.quote_qualified(&self.name));
+        match self.with_data {
+            Some(true) => statement = format!("{statement} WITH DATA"),
+            Some(false) => statement = format!("{statement} WITH NO DATA"),
+            None => {}
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_view_builder {
+    use super::*;
+    use crate::placeholder::PlaceholderKind;
+    use crate::postgres::{ConditionBuilder, ConditionValue, ExpressionBuilder, Operator};
+    use serde_json::json;
+
+    fn active_users() -> SelectBuilder {
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id", "email"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: Some("u".to_string()),
+                        field: "status".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(json!("it's active"))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        builder
+    }
+
+    #[tokio::test]
+    async fn test_create_view_builder() {
+        let mut builder = CreateViewBuilder::new();
+        let result = builder
+            .name("active_users")
+            .or_replace()
+            .columns(vec!["user_id", "email"])
+            .query(&active_users())
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE OR REPLACE VIEW active_users (user_id, email) AS SELECT u.id, u.email FROM users as u WHERE u.status = 'it''s active'".to_string())
+        );
+
+        let mut builder = CreateViewBuilder::new();
+        let result = builder
+            .name("reporting.active_users")
+            .materialized()
+            .if_not_exists()
+            .with_data(false)
+            .query(&active_users())
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE MATERIALIZED VIEW IF NOT EXISTS reporting.active_users AS SELECT u.id, u.email FROM users as u WHERE u.status = 'it''s active' WITH NO DATA".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_view_builder() {
+        let mut builder = RefreshViewBuilder::new();
+        assert_eq!(
+            builder.name("active_users").concurrently().build(),
+            Ok("REFRESH MATERIALIZED VIEW CONCURRENTLY active_users".to_string())
+        );
+
+        let mut builder = RefreshViewBuilder::new();
+        assert_eq!(
+            builder.name("active_users").with_data(true).build(),
+            Ok("REFRESH MATERIALIZED VIEW active_users WITH DATA".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_view_builder_errors() {
+        let mut builder = CreateViewBuilder::new();
+        assert_eq!(
+            builder.name("active_users").build(),
+            Err(Error::EmptyField {
+                builder: "CreateViewBuilder",
+                field: "query".to_string(),
+            })
+        );
+        assert_eq!(
+            builder
+                .query(&active_users())
+                .materialized()
+                .or_replace()
+                .build(),
+            Err(Error::UnsupportedClause {
+                builder: "CreateViewBuilder",
+                field: "or_replace".to_string(),
+                target: "materialized views".to_string(),
+            })
+        );
+
+        let mut builder = CreateViewBuilder::new();
+        let result = builder
+            .name("active_users")
+            .with_data(true)
+            .query(&active_users())
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "CreateViewBuilder",
+                field: "with_data".to_string(),
+                target: "views".to_string(),
+            })
+        );
+
+        let mut builder = RefreshViewBuilder::new();
+        let result = builder
+            .name("active_users")
+            .concurrently()
+            .with_data(false)
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "RefreshViewBuilder",
+                field: "concurrently".to_string(),
+                target: "WITH NO DATA".to_string(),
+            })
+        );
+    }
+}
@@ -39,6 +39,7 @@ impl WhereBuilder {
 }
 
 #[cfg(test)]
+#[allow(clippy::len_zero)]
 pub mod test_where_builder {
     use super::*;
     use crate::postgres::{ConditionBuilder, ConditionValue, Operator};
@@ -0,0 +1,250 @@
+use crate::error::{Error, Result};
+use crate::identifier::quote_identifier;
+use std::fmt::Display;
+
+/// The column types allowed in `STRICT` tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    Integer,
+    Real,
+    Text,
+    Blob,
+    Any,
+}
+
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::Integer => "INTEGER",
+            Self::Real => "REAL",
+            Self::Text => "TEXT",
+            Self::Blob => "BLOB",
+            Self::Any => "ANY",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// A column of `CREATE TABLE`. The `default` is inlined as is, e.g. `0` or `'active'`.
+#[derive(Clone, Debug)]
+pub struct ColumnDefinition {
+    pub name: String,
+    pub data_type: ColumnType,
+    pub not_null: bool,
+    pub primary_key: bool,
+    pub unique: bool,
+    pub default: Option<String>,
+}
+
+impl ColumnDefinition {
+    fn build(&self) -> String {
+        let mut statement = format!("{} {}", quote_identifier(&self.name), self.data_type);
+        if self.primary_key {
+            statement.push_str(" PRIMARY KEY");
+        }
+        if self.not_null {
+            statement.push_str(" NOT NULL");
+        }
+        if self.unique {
+            statement.push_str(" UNIQUE");
+        }
+        if let Some(value) = &self.default {
+            statement.push_str(&format!(" DEFAULT {value}"));
+        }
+        statement
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CreateTableBuilder {
+    pub table: String,
+    pub if_not_exists: bool,
+    pub columns: Vec<ColumnDefinition>,
+    pub primary_key: Vec<String>,
+    pub strict: bool,
+    pub without_rowid: bool,
+}
+
+impl CreateTableBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn column(&mut self, value: ColumnDefinition) -> &mut Self {
+        self.columns.push(value);
+        self
+    }
+
+    /// Sets a table-level `PRIMARY KEY(...)`, for keys made of several columns.
+    pub fn primary_key(&mut self, values: Vec<&str>) -> &mut Self {
+        self.primary_key = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    /// Renders the `STRICT` table option, which enforces the column types.
+    pub fn strict(&mut self) -> &mut Self {
+        self.strict = true;
+        self
+    }
+
+    /// Renders the `WITHOUT ROWID` table option. The table needs a primary key.
+    pub fn without_rowid(&mut self) -> &mut Self {
+        self.without_rowid = true;
+        self
+    }
+
+    pub fn build(&self) -> Result<String> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.columns.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "columns".to_string(),
+            });
+        }
+        let column_keys = self.columns.iter().filter(|v| v.primary_key).count();
+        if column_keys > 1 || (column_keys == 1 && !self.primary_key.is_empty()) {
+            return Err(Error::DuplicateClause {
+                builder: "CreateTableBuilder",
+                field: "primary_key".to_string(),
+            });
+        }
+        if self.without_rowid && column_keys == 0 && self.primary_key.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "primary_key".to_string(),
+            });
+        }
+        let mut definitions: Vec<String> = self.columns.iter().map(|v| v.build()).collect();
+        if !self.primary_key.is_empty() {
+            let columns: Vec<String> = self
+                .primary_key
+                .iter()
+                .map(|v| quote_identifier(v))
+                .collect();
+            definitions.push(format!("PRIMARY KEY({})", columns.join(", ")));
+        }
+        let mut statement = format!(
+            "CREATE TABLE {}{} ({})",
+            if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            quote_identifier(&self.table),
+            definitions.join(", ")
+        );
+        let mut options: Vec<&str> = Vec::new();
+        if self.without_rowid {
+            options.push("WITHOUT ROWID");
+        }
+        if self.strict {
+            options.push("STRICT");
+        }
+        if !options.is_empty() {
+            statement = format!("{statement} {}", options.join(", "));
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_create_table_builder {
+    use super::*;
+    use crate::sqlite::test_database::connection;
+
+    fn column(name: &str, data_type: ColumnType) -> ColumnDefinition {
+        ColumnDefinition {
+            name: name.to_string(),
+            data_type,
+            not_null: true,
+            primary_key: false,
+            unique: false,
+            default: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_table_builder() {
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("order_items")
+            .if_not_exists()
+            .column(column("order_id", ColumnType::Integer))
+            .column(column("product_id", ColumnType::Integer))
+            .column(ColumnDefinition {
+                default: Some("1".to_string()),
+                ..column("quantity", ColumnType::Integer)
+            })
+            .column(ColumnDefinition {
+                not_null: false,
+                ..column("note", ColumnType::Text)
+            })
+            .primary_key(vec!["order_id", "product_id"])
+            .strict()
+            .without_rowid()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "CREATE TABLE IF NOT EXISTS order_items (order_id INTEGER NOT NULL, product_id INTEGER NOT NULL, quantity INTEGER NOT NULL DEFAULT 1, note TEXT, PRIMARY KEY(order_id, product_id)) WITHOUT ROWID, STRICT"
+        );
+
+        let connection = connection();
+        connection.execute(&statement, []).unwrap();
+        // STRICT tables reject values that can't be converted to the column type.
+        let result = connection.execute(
+            "INSERT INTO order_items (order_id, product_id) VALUES ('one', 1)",
+            [],
+        );
+        assert!(result.is_err(), "expecting a type error");
+    }
+
+    #[tokio::test]
+    async fn test_create_table_builder_errors() {
+        let mut builder = CreateTableBuilder::new();
+        let result = builder
+            .table("tags")
+            .column(ColumnDefinition {
+                unique: true,
+                ..column("name", ColumnType::Text)
+            })
+            .without_rowid()
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "CreateTableBuilder",
+                field: "primary_key".to_string(),
+            })
+        );
+
+        let result = builder
+            .column(ColumnDefinition {
+                primary_key: true,
+                ..column("id", ColumnType::Integer)
+            })
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE TABLE tags (name TEXT NOT NULL UNIQUE, id INTEGER PRIMARY KEY NOT NULL) WITHOUT ROWID".to_string())
+        );
+        let result = builder.primary_key(vec!["name"]).build();
+        assert!(result.is_err(), "expecting duplicate primary key");
+    }
+}
@@ -0,0 +1,143 @@
+use crate::dialect::{Dialect, Sqlite};
+use crate::error::{Error, Result};
+use crate::identifier::quote_identifier;
+use crate::placeholder::Params;
+use crate::postgres::{ExpressionBuilder, Operator, WhereBuilder};
+use crate::sqlite::Placeholder;
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct DeleteBuilder {
+    pub table: String,
+    filter_values: Vec<Value>,
+    filter_statement: Option<String>,
+    returning_statement: Option<String>,
+    pub(crate) operators: Vec<Operator>, // Used to check the operators SQLite supports
+    pub placeholder: Placeholder,
+}
+
+impl DeleteBuilder {
+    pub fn new(placeholder: Placeholder) -> Self {
+        Self {
+            placeholder,
+            ..Default::default()
+        }
+    }
+
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = quote_identifier(table);
+        self
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    pub fn returning(&mut self, values: Vec<&str>) -> &mut Self {
+        if !values.is_empty() {
+            self.returning_statement = Some(format!("RETURNING {}", values.join(", ")));
+        }
+        self
+    }
+
+    pub fn get_values(&self) -> Vec<Value> {
+        self.filter_values.to_owned()
+    }
+
+    /// Returns the values paired with their placeholder names, for `Placeholder::Named`.
+    pub fn get_named_values(&self) -> Vec<(String, Value)> {
+        Placeholder::named_values(self.get_values())
+    }
+
+    /// Returns the values paired with their placeholder names, as [`get_named_values`](Self::get_named_values),
+    /// followed by the named parameters of the conditions, e.g. `:email`, with their value
+    /// in `params`. Errors when a named parameter has no value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let values = builder.bind_named(&Params::from([("email".to_string(), json!("x"))]))?;
+    /// ```
+    pub fn bind_named(&self, params: &Params) -> Result<Vec<(String, Value)>> {
+        Placeholder::bind_named("DeleteBuilder", &self.render()?, self.get_values(), params)
+    }
+
+    pub fn build(&self) -> Result<String> {
+        Sqlite.check_operators("DeleteBuilder", &self.operators)?;
+        Ok(self.placeholder.render(&self.render()?))
+    }
+
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self) -> Result<String> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "DeleteBuilder",
+                field: "table".to_string(),
+            });
+        }
+        let mut statement = format!("DELETE FROM {}", self.table);
+        if let Some(stmt) = &self.filter_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        if let Some(stmt) = &self.returning_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_delete_builder {
+    use super::*;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Operator};
+    use crate::sqlite::test_database::{connection, params};
+    use rusqlite::params_from_iter;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_delete_builder() {
+        let mut builder = DeleteBuilder::new(Placeholder::Named);
+        let result = builder
+            .table("orders")
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: None,
+                        field: "user_id".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(json!(1))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .returning(vec!["id"])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "DELETE FROM orders WHERE user_id = :p1 RETURNING id"
+        );
+
+        let connection = connection();
+        let mut query = connection.prepare(&statement).unwrap();
+        let ids: Vec<i64> = query
+            .query_map(params_from_iter(params(&builder.get_values())), |row| {
+                row.get(0)
+            })
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(ids, vec![1, 2]);
+    }
+}
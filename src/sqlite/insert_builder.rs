@@ -0,0 +1,382 @@
+use crate::error::{Error, Result};
+use crate::identifier::quote_identifier;
+use crate::sqlite::Placeholder;
+use serde_json::Value;
+
+/// The `INSERT OR <resolution>` conflict resolution algorithm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConflictResolution {
+    Replace,
+    Ignore,
+    Abort,
+    Fail,
+    Rollback,
+}
+
+impl ConflictResolution {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Replace => "REPLACE",
+            Self::Ignore => "IGNORE",
+            Self::Abort => "ABORT",
+            Self::Fail => "FAIL",
+            Self::Rollback => "ROLLBACK",
+        }
+    }
+}
+
+/// The update applied to a column by `ON CONFLICT ... DO UPDATE`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConflictUpdate {
+    Excluded(String),     // column = excluded.column, the value the row tried to insert
+    Value(String, Value), // column = ?
+    Raw(String, String),  // column = <expression>, e.g. count + 1
+}
+
+/// The `ON CONFLICT` upsert clause.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OnConflict {
+    DoNothing(Vec<String>),                     // ON CONFLICT(columns) DO NOTHING
+    DoUpdate(Vec<String>, Vec<ConflictUpdate>), // ON CONFLICT(columns) DO UPDATE SET ...
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InsertBuilder {
+    pub table: String,
+    pub fields: Vec<String>,
+    pub values: Vec<Vec<Value>>,
+    pub conflict_resolution: Option<ConflictResolution>,
+    pub on_conflict: Option<OnConflict>,
+    pub returning_statement: Option<String>,
+    pub placeholder: Placeholder,
+}
+
+impl InsertBuilder {
+    pub fn new(placeholder: Placeholder) -> Self {
+        Self {
+            placeholder,
+            ..Default::default()
+        }
+    }
+
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = table.to_string();
+        self
+    }
+
+    /// Renders `INSERT OR <resolution> INTO`, e.g. `INSERT OR REPLACE INTO`.
+    pub fn or(&mut self, value: ConflictResolution) -> &mut Self {
+        self.conflict_resolution = Some(value);
+        self
+    }
+
+    pub fn columns(&mut self, values: Vec<&str>) -> &mut Self {
+        self.fields = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    pub fn values(&mut self, values: Vec<Value>) -> Result<&mut Self> {
+        if self.fields.len() != values.len() {
+            return Err(Error::CountMismatch {
+                builder: "InsertBuilder",
+                field: "values".to_string(),
+                expected: self.fields.len(),
+                found: values.len(),
+            });
+        }
+        self.values.push(values);
+        Ok(self)
+    }
+
+    /// Renders the `ON CONFLICT` upsert clause.
+    ///
+    /// # Example
+    /// ```ignore
+    /// on_conflict(OnConflict::DoUpdate(
+    ///     vec!["email".to_string()],
+    ///     vec![ConflictUpdate::Excluded("name".to_string())],
+    /// ));
+    /// ```
+    pub fn on_conflict(&mut self, value: OnConflict) -> &mut Self {
+        self.on_conflict = Some(value);
+        self
+    }
+
+    pub fn returning(&mut self, values: Vec<&str>) -> &mut Self {
+        if !values.is_empty() {
+            self.returning_statement = Some(format!("RETURNING {}", values.join(", ")));
+        }
+        self
+    }
+
+    /// Returns the row values followed by the values of `ON CONFLICT ... DO UPDATE`.
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = self.values.iter().flatten().cloned().collect();
+        if let Some(OnConflict::DoUpdate(_, items)) = &self.on_conflict {
+            for item in items {
+                if let ConflictUpdate::Value(_, value) = item {
+                    values.push(value.to_owned());
+                }
+            }
+        }
+        values
+    }
+
+    /// Returns the values paired with their placeholder names, for `Placeholder::Named`.
+    pub fn get_named_values(&self) -> Vec<(String, Value)> {
+        Placeholder::named_values(self.get_values())
+    }
+
+    fn on_conflict_statement(&self) -> Result<Option<String>> {
+        let Some(on_conflict) = &self.on_conflict else {
+            return Ok(None);
+        };
+        let target = |columns: &[String]| {
+            if columns.is_empty() {
+                String::new()
+            } else {
+                let columns: Vec<String> = columns.iter().map(|v| quote_identifier(v)).collect();
+                format!("({})", columns.join(", "))
+            }
+        };
+        let statement = match on_conflict {
+            OnConflict::DoNothing(columns) => {
+                format!("ON CONFLICT{} DO NOTHING", target(columns))
+            }
+            OnConflict::DoUpdate(columns, items) => {
+                // SQLite needs a conflict target for DO UPDATE.
+                if columns.is_empty() || items.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "InsertBuilder",
+                        field: "on_conflict".to_string(),
+                    });
+                }
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| match item {
+                        ConflictUpdate::Excluded(field) => {
+                            let field = quote_identifier(field);
+                            format!("{field} = excluded.{field}")
+                        }
+                        ConflictUpdate::Value(field, _) => {
+                            format!("{} = ?", quote_identifier(field))
+                        }
+                        ConflictUpdate::Raw(field, expression) => {
+                            format!("{} = {expression}", quote_identifier(field))
+                        }
+                    })
+                    .collect();
+                format!(
+                    "ON CONFLICT{} DO UPDATE SET {}",
+                    target(columns),
+                    items.join(", ")
+                )
+            }
+        };
+        Ok(Some(statement))
+    }
+
+    pub fn build(&self) -> Result<String> {
+        Ok(self.placeholder.render(&self.render()?))
+    }
+
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self) -> Result<String> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "InsertBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.values.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "InsertBuilder",
+                field: "values".to_string(),
+            });
+        }
+        let fields: Vec<String> = self.fields.iter().map(|v| quote_identifier(v)).collect();
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|items| format!("({})", vec!["?"; items.len()].join(", ")))
+            .collect();
+        let insert = match &self.conflict_resolution {
+            Some(value) => format!("INSERT OR {}", value.as_str()),
+            None => "INSERT".to_string(),
+        };
+        let mut statement = format!(
+            "{insert} INTO {}({}) VALUES {}",
+            quote_identifier(&self.table),
+            fields.join(", "),
+            values.join(", ")
+        );
+        if let Some(value) = self.on_conflict_statement()? {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.returning_statement {
+            statement = format!("{statement} {value}");
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_insert_builder {
+    use super::*;
+    use crate::sqlite::test_database::{connection, params};
+    use rusqlite::params_from_iter;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_insert_builder() {
+        let mut builder = InsertBuilder::new(Placeholder::Numbered);
+        let _ = builder.table("users").columns(vec!["name", "email"]);
+        let result = builder.values(vec![
+            Value::String("Juan dela Cruz".to_string()),
+            Value::String("jdc@test.com".to_string()),
+        ]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = builder.values(vec![
+            Value::String("Jose Rizal".to_string()),
+            Value::String("jr@test.com".to_string()),
+        ]);
+        assert!(result.is_ok(), "{:?}", result.err());
+        let result = builder.returning(vec!["id"]).build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "INSERT INTO users(name, email) VALUES (?1, ?2), (?3, ?4) RETURNING id"
+        );
+
+        let connection = connection();
+        let mut query = connection.prepare(&statement).unwrap();
+        let ids: Vec<i64> = query
+            .query_map(params_from_iter(params(&builder.get_values())), |row| {
+                row.get(0)
+            })
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(ids, vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_conflict_resolution() {
+        let connection = connection();
+        let mut builder = InsertBuilder::new(Placeholder::QuestionMark);
+        let result = builder
+            .table("users")
+            .or(ConflictResolution::Ignore)
+            .columns(vec!["name", "email"])
+            .values(vec![json!("Alice Again"), json!("alice@example.com")])
+            .unwrap()
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "INSERT OR IGNORE INTO users(name, email) VALUES (?, ?)"
+        );
+        let changed = connection
+            .execute(&statement, params_from_iter(params(&builder.get_values())))
+            .unwrap();
+        assert_eq!(changed, 0);
+
+        let mut builder = InsertBuilder::new(Placeholder::QuestionMark);
+        let statement = builder
+            .table("users")
+            .or(ConflictResolution::Replace)
+            .columns(vec!["id", "name", "email"])
+            .values(vec![json!(2), json!("Robert"), json!("bob@example.com")])
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            statement,
+            "INSERT OR REPLACE INTO users(id, name, email) VALUES (?, ?, ?)"
+        );
+        connection
+            .execute(&statement, params_from_iter(params(&builder.get_values())))
+            .unwrap();
+        let name: String = connection
+            .query_row("SELECT name FROM users WHERE id = 2", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(name, "Robert");
+    }
+
+    #[tokio::test]
+    async fn test_insert_builder_on_conflict() {
+        let connection = connection();
+        let mut builder = InsertBuilder::new(Placeholder::Named);
+        let result = builder
+            .table("users")
+            .columns(vec!["name", "email"])
+            .values(vec![json!("Alicia"), json!("alice@example.com")])
+            .unwrap()
+            .on_conflict(OnConflict::DoUpdate(
+                vec!["email".to_string()],
+                vec![
+                    ConflictUpdate::Excluded("name".to_string()),
+                    ConflictUpdate::Value("profile".to_string(), json!("{}")),
+                ],
+            ))
+            .returning(vec!["id", "name"])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "INSERT INTO users(name, email) VALUES (:p1, :p2) ON CONFLICT(email) DO UPDATE SET name = excluded.name, profile = :p3 RETURNING id, name"
+        );
+        assert_eq!(
+            builder.get_named_values()[2],
+            (":p3".to_string(), json!("{}"))
+        );
+        let (id, name): (i64, String) = connection
+            .query_row(
+                &statement,
+                params_from_iter(params(&builder.get_values())),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((id, name.as_str()), (1, "Alicia"));
+
+        let mut builder = InsertBuilder::new(Placeholder::QuestionMark);
+        let statement = builder
+            .table("users")
+            .columns(vec!["name", "email"])
+            .values(vec![json!("Bobby"), json!("bob@example.com")])
+            .unwrap()
+            .on_conflict(OnConflict::DoNothing(vec![]))
+            .build()
+            .unwrap();
+        assert_eq!(
+            statement,
+            "INSERT INTO users(name, email) VALUES (?, ?) ON CONFLICT DO NOTHING"
+        );
+        let changed = connection
+            .execute(&statement, params_from_iter(params(&builder.get_values())))
+            .unwrap();
+        assert_eq!(changed, 0);
+
+        let mut builder = InsertBuilder::new(Placeholder::QuestionMark);
+        let result = builder
+            .table("users")
+            .columns(vec!["name"])
+            .values(vec![json!("Dan")])
+            .unwrap()
+            .on_conflict(OnConflict::DoUpdate(
+                vec![],
+                vec![ConflictUpdate::Excluded("name".to_string())],
+            ))
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "InsertBuilder",
+                field: "on_conflict".to_string(),
+            })
+        );
+    }
+}
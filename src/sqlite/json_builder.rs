@@ -0,0 +1,108 @@
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+
+/// Helpers for JSON stored in SQLite text columns.
+///
+/// The extracted values can be used as a column with `SelectBuilder::columns_raw`, or as
+/// the `field` of a `ConditionBuilder` (without a `table_alias`) to filter on a JSON path.
+#[derive(Debug, Clone)]
+pub struct JsonBuilder;
+
+impl JsonBuilder {
+    /// Checks that the JSON path only has characters allowed in a path expression, since
+    /// the path is inlined in the statement.
+    fn path(path: &str) -> Result<String> {
+        let is_valid = path.starts_with('$')
+            && path
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "$._[]#-".contains(c));
+        if !is_valid {
+            return Err(Error::InvalidIdentifier {
+                builder: "JsonBuilder",
+                field: "path".to_string(),
+                value: path.to_string(),
+            });
+        }
+        Ok(format!("'{path}'"))
+    }
+
+    fn column(table_alias: Option<&str>, field: &str) -> Result<String> {
+        if field.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "JsonBuilder",
+                field: "field".to_string(),
+            });
+        }
+        validate_identifier("JsonBuilder", "field", field)?;
+        if let Some(alias) = table_alias {
+            validate_identifier("JsonBuilder", "table_alias", alias)?;
+            Ok(format!("{alias}.{field}"))
+        } else {
+            Ok(field.to_string())
+        }
+    }
+
+    /// Renders `json_extract(t.field, '$.path')`, which returns SQL values for JSON
+    /// scalars and JSON text for objects and arrays.
+    pub fn extract(table_alias: Option<&str>, field: &str, path: &str) -> Result<String> {
+        Ok(format!(
+            "json_extract({}, {})",
+            Self::column(table_alias, field)?,
+            Self::path(path)?
+        ))
+    }
+
+    /// Renders `t.field -> '$.path'`, which always returns JSON text.
+    pub fn extract_json(table_alias: Option<&str>, field: &str, path: &str) -> Result<String> {
+        Ok(format!(
+            "{} -> {}",
+            Self::column(table_alias, field)?,
+            Self::path(path)?
+        ))
+    }
+
+    /// Renders `t.field ->> '$.path'`, which returns the SQL value.
+    pub fn extract_text(table_alias: Option<&str>, field: &str, path: &str) -> Result<String> {
+        Ok(format!(
+            "{} ->> {}",
+            Self::column(table_alias, field)?,
+            Self::path(path)?
+        ))
+    }
+}
+
+#[cfg(test)]
+pub mod test_json_builder {
+    use super::*;
+    use crate::sqlite::test_database::connection;
+
+    #[tokio::test]
+    async fn test_json_builder() {
+        let result = JsonBuilder::extract(Some("u"), "profile", "$.tags[0]");
+        assert_eq!(
+            result,
+            Ok("json_extract(u.profile, '$.tags[0]')".to_string())
+        );
+        let result = JsonBuilder::extract_json(None, "profile", "$.tags");
+        assert_eq!(result, Ok("profile -> '$.tags'".to_string()));
+        let result = JsonBuilder::extract_text(None, "profile", "$.tags[#-1]");
+        assert_eq!(result, Ok("profile ->> '$.tags[#-1]'".to_string()));
+
+        let result = JsonBuilder::extract(None, "profile", "$.city'; DROP TABLE users; --");
+        assert!(result.is_err(), "expecting invalid path");
+        let result = JsonBuilder::extract(Some("u u"), "profile", "$.city");
+        assert!(result.is_err(), "expecting invalid alias");
+
+        let connection = connection();
+        let statement = format!(
+            "SELECT {}, {} FROM users WHERE id = 1",
+            JsonBuilder::extract_json(None, "profile", "$.tags").unwrap(),
+            JsonBuilder::extract_text(None, "profile", "$.tags[#-1]").unwrap(),
+        );
+        let (tags, tag): (String, String) = connection
+            .query_row(&statement, [], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        assert_eq!(tags, "[\"admin\"]");
+        assert_eq!(tag, "admin");
+    }
+}
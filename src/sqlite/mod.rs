@@ -0,0 +1,21 @@
+pub mod create_table_builder;
+pub mod delete_builder;
+pub mod insert_builder;
+pub mod json_builder;
+pub mod placeholder;
+#[cfg(any(feature = "rusqlite", test))]
+pub mod rusqlite;
+pub mod select_builder;
+#[cfg(test)]
+pub mod test_database;
+pub mod update_builder;
+
+pub use create_table_builder::{ColumnDefinition, ColumnType, CreateTableBuilder};
+pub use delete_builder::DeleteBuilder;
+pub use insert_builder::{ConflictResolution, ConflictUpdate, InsertBuilder, OnConflict};
+pub use json_builder::JsonBuilder;
+pub use placeholder::Placeholder;
+#[cfg(any(feature = "rusqlite", test))]
+pub use self::rusqlite::RusqliteQuery;
+pub use select_builder::SelectBuilder;
+pub use update_builder::UpdateBuilder;
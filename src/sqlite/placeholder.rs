@@ -0,0 +1,133 @@
+use crate::error::{Error, Result};
+use crate::placeholder::{
+    Params, Placeholder as Parameter, render_placeholders, split_placeholders,
+};
+use serde_json::Value;
+
+/// Placeholder styles supported by SQLite.
+///
+/// - `QuestionMark` produces `?` placeholders.
+/// - `Numbered` produces `?1`, `?2`, ... placeholders.
+/// - `Named` produces `:p1`, `:p2`, ... placeholders, bound by name.
+///
+/// The named parameters of the conditions, e.g. `:email` from `ConditionValue::Named`,
+/// are kept with any style, SQLite binds them by name. Their names must not clash with
+/// the `:p1`, `:p2`, ... of `Named`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Placeholder {
+    #[default]
+    QuestionMark,
+    Numbered,
+    Named,
+}
+
+impl Placeholder {
+    /// Returns the name of the n-th (starting at 1) named placeholder.
+    pub fn name(index: usize) -> String {
+        format!(":p{index}")
+    }
+
+    /// Renders the n-th (starting at 1) placeholder.
+    pub fn placeholder(&self, index: usize) -> String {
+        match self {
+            Self::QuestionMark => "?".to_string(),
+            Self::Numbered => format!("?{index}"),
+            Self::Named => Self::name(index),
+        }
+    }
+
+    /// Replaces each `?` placeholder in the statement with the placeholder style. Quoted
+    /// text and named parameters are left untouched.
+    pub fn render(&self, statement: &str) -> String {
+        render_placeholders(statement, &|index| self.placeholder(index), false)
+    }
+
+    /// Pairs each value with the name of its placeholder, for use with `Named`.
+    pub fn named_values(values: Vec<Value>) -> Vec<(String, Value)> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| (Self::name(index + 1), value))
+            .collect()
+    }
+
+    /// Pairs each value with the name of its placeholder, as [`named_values`](Self::named_values),
+    /// followed by the named parameters of the statement with their value in `params`.
+    /// `params` is keyed by name without its `:`, `@` or `$` prefix. Errors when a named
+    /// parameter has no value.
+    pub fn bind_named(
+        builder: &'static str,
+        statement: &str,
+        values: Vec<Value>,
+        params: &Params,
+    ) -> Result<Vec<(String, Value)>> {
+        let mut bound = Self::named_values(values);
+        for placeholder in split_placeholders(statement, true).1 {
+            let Parameter::Named { name, text } = placeholder else {
+                continue;
+            };
+            if bound.iter().any(|(bound, _)| *bound == text) {
+                continue;
+            }
+            let Some(value) = params.get(&name) else {
+                return Err(Error::MissingParameter {
+                    builder,
+                    field: name,
+                });
+            };
+            bound.push((text, value.to_owned()));
+        }
+        Ok(bound)
+    }
+}
+
+#[cfg(test)]
+pub mod test_placeholder {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_placeholder_bind_named() {
+        let statement =
+            "SELECT u.id FROM users as u WHERE u.id = ? AND (u.email = :email OR u.alt = :email)";
+        let params = Params::from([("email".to_string(), Value::from("a@b.c"))]);
+        assert_eq!(
+            Placeholder::bind_named("SelectBuilder", statement, vec![Value::from(1)], &params),
+            Ok(vec![
+                (":p1".to_string(), Value::from(1)),
+                (":email".to_string(), Value::from("a@b.c")),
+            ])
+        );
+        assert_eq!(
+            Placeholder::bind_named("SelectBuilder", statement, vec![], &Params::new()),
+            Err(Error::MissingParameter {
+                builder: "SelectBuilder",
+                field: "email".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_placeholder() {
+        let statement = "SELECT u.id FROM users as u WHERE u.id = ? AND u.name = ?";
+        assert_eq!(Placeholder::QuestionMark.render(statement), statement);
+        assert_eq!(
+            Placeholder::Numbered.render(statement),
+            "SELECT u.id FROM users as u WHERE u.id = ?1 AND u.name = ?2"
+        );
+        assert_eq!(
+            Placeholder::Named.render(statement),
+            "SELECT u.id FROM users as u WHERE u.id = :p1 AND u.name = :p2"
+        );
+        assert_eq!(
+            Placeholder::Named.render("SELECT '?' FROM t WHERE a = ? AND b = :email"),
+            "SELECT '?' FROM t WHERE a = :p1 AND b = :email"
+        );
+        assert_eq!(
+            Placeholder::named_values(vec![Value::Null, Value::Bool(true)]),
+            vec![
+                (":p1".to_string(), Value::Null),
+                (":p2".to_string(), Value::Bool(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use crate::dialect::{Dialect, Sqlite};
+use crate::error::{Error, Result};
+use crate::placeholder::number_placeholders;
+use crate::postgres::delete_builder::DeleteBuilder;
+use crate::postgres::insert_builder::InsertBuilder;
+use crate::postgres::{SelectBuilder, UpdateBuilder};
+use crate::sqlite;
+use ::rusqlite::types::Value as SqliteValue;
+use ::rusqlite::{Connection, Row, params_from_iter};
+use serde_json::Value;
+
+/// Runs the builders in-process with rusqlite: the [`sqlite`] builders, and the PostgreSQL
+/// builders rendered for [`Sqlite`].
+///
+/// Values are bound as `rusqlite::types::Value`s: booleans as integers, and arrays and
+/// objects as JSON text, except the arrays of `IN (?)` lists which are expanded into one
+/// placeholder per item. Only the SQLite-compatible subset of the builders can run, the
+/// others are refused as by [`build_with`](SelectBuilder::build_with). The statements are
+/// bound by position, so the named parameters of the conditions, e.g. `:email`, are refused.
+///
+/// # Example
+/// ```ignore
+/// let connection = Connection::open_in_memory()?;
+/// let names = builder.query_map(&connection, |row| row.get::<_, String>(0))?;
+/// ```
+pub trait RusqliteQuery {
+    /// Builds the statement for SQLite with its `?` placeholders and the values to bind.
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)>;
+
+    /// Runs the statement, returning the number of rows it modified.
+    fn execute(&self, connection: &Connection) -> ::rusqlite::Result<usize> {
+        let (statement, values) = self.build_rusqlite().map_err(builder_error)?;
+        connection.execute(&statement, params_from_iter(values))
+    }
+
+    /// Runs the statement, mapping each row it returns.
+    fn query_map<T, F>(&self, connection: &Connection, f: F) -> ::rusqlite::Result<Vec<T>>
+    where
+        F: FnMut(&Row<'_>) -> ::rusqlite::Result<T>,
+    {
+        let (statement, values) = self.build_rusqlite().map_err(builder_error)?;
+        let mut prepared = connection.prepare_cached(&statement)?;
+        prepared.query_map(params_from_iter(values), f)?.collect()
+    }
+}
+
+impl RusqliteQuery for SelectBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        Sqlite.check_operators("SelectBuilder", &self.operators())?;
+        sqlite_values("SelectBuilder", &self.render(&Sqlite)?, self.get_values())
+    }
+}
+
+impl RusqliteQuery for InsertBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        sqlite_values(
+            "InsertBuilder",
+            &self.render(&Sqlite)?,
+            self.get_values().concat(),
+        )
+    }
+}
+
+impl RusqliteQuery for UpdateBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        Sqlite.check_operators("UpdateBuilder", &self.operators)?;
+        sqlite_values("UpdateBuilder", &self.render(&Sqlite)?, self.get_values())
+    }
+}
+
+impl RusqliteQuery for DeleteBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        Sqlite.check_operators("DeleteBuilder", &self.operators)?;
+        sqlite_values("DeleteBuilder", &self.render(&Sqlite)?, self.get_values())
+    }
+}
+
+impl RusqliteQuery for sqlite::SelectBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        Sqlite.check_operators("SelectBuilder", &self.operators())?;
+        sqlite_values("SelectBuilder", &self.render()?, self.get_values())
+    }
+}
+
+impl RusqliteQuery for sqlite::InsertBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        sqlite_values("InsertBuilder", &self.render()?, self.get_values())
+    }
+}
+
+impl RusqliteQuery for sqlite::UpdateBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        Sqlite.check_operators("UpdateBuilder", &self.operators)?;
+        sqlite_values("UpdateBuilder", &self.render()?, self.get_values())
+    }
+}
+
+impl RusqliteQuery for sqlite::DeleteBuilder {
+    fn build_rusqlite(&self) -> Result<(String, Vec<SqliteValue>)> {
+        Sqlite.check_operators("DeleteBuilder", &self.operators)?;
+        sqlite_values("DeleteBuilder", &self.render()?, self.get_values())
+    }
+}
+
+fn sqlite_values(
+    builder: &'static str,
+    statement: &str,
+    values: Vec<Value>,
+) -> Result<(String, Vec<SqliteValue>)> {
+    let (statement, values) = number_placeholders(builder, statement, values, &Sqlite)?;
+    Ok((statement, values.into_iter().map(sqlite_value).collect()))
+}
+
+/// Converts a JSON value to a SQLite value. Arrays and objects are stored as JSON text.
+pub fn sqlite_value(value: Value) -> SqliteValue {
+    match value {
+        Value::Null => SqliteValue::Null,
+        Value::Bool(value) => SqliteValue::Integer(value as i64),
+        Value::Number(value) => match value.as_i64() {
+            Some(value) => SqliteValue::Integer(value),
+            None => SqliteValue::Real(value.as_f64().unwrap_or_default()),
+        },
+        Value::String(value) => SqliteValue::Text(value),
+        value => SqliteValue::Text(value.to_string()),
+    }
+}
+
+/// The builder errors are reported as conversion failures by the helpers, the [`Error`]
+/// can be downcast from the source.
+fn builder_error(error: Error) -> ::rusqlite::Error {
+    ::rusqlite::Error::ToSqlConversionFailure(Box::new(error))
+}
+
+#[cfg(test)]
+pub mod test_rusqlite {
+    use super::*;
+    use crate::dialect::Upsert;
+    use crate::placeholder::PlaceholderKind;
+    use crate::postgres::{
+        ConditionValue, Conditions, GroupByItem, JoinKind, Operator, OrderByItem, Sequence,
+        SetFieldUpdate, SetValue, col,
+    };
+    use crate::sqlite::test_database::connection;
+    use crate::sqlite::{ConflictUpdate, OnConflict, Placeholder};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_rusqlite_insert() {
+        let connection = connection();
+        let mut builder = InsertBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users")
+            .columns(vec!["name", "email", "profile"])
+            .values(vec![
+                json!("Dave"),
+                json!("dave@example.com"),
+                json!({"city": "Davao"}),
+            ])
+            .unwrap();
+        assert_eq!(builder.execute(&connection), Ok(1));
+
+        let mut builder = InsertBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users")
+            .columns(vec!["name", "email"])
+            .values(vec![json!("Alice B."), json!("alice@example.com")])
+            .unwrap()
+            .on_conflict(Upsert::DoUpdate(
+                vec!["email".to_string()],
+                vec!["name".to_string()],
+            ))
+            .returning(vec!["id", "name"]);
+        let rows = builder.query_map(&connection, |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        });
+        assert_eq!(rows, Ok(vec![(1, "Alice B.".to_string())]));
+
+        let profile: String = connection
+            .query_row("SELECT profile FROM users WHERE id = 4", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(profile, r#"{"city":"Davao"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_rusqlite_select() {
+        let connection = connection();
+        let mut builder = SelectBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["name"])
+            .columns_raw(vec!["SUM(o.quantity * p.price) as total"])
+            .join(
+                JoinKind::Inner,
+                "orders",
+                "o",
+                vec![col("o", "user_id").eq_col(&col("u", "id")).build().unwrap()],
+            )
+            .unwrap()
+            .join(
+                JoinKind::Inner,
+                "products",
+                "p",
+                vec![
+                    col("p", "id")
+                        .eq_col(&col("o", "product_id"))
+                        .build()
+                        .unwrap(),
+                ],
+            )
+            .unwrap()
+            .filter(vec![
+                col("u", "email")
+                    .in_list(["alice@example.com", "bob@example.com", "dave@example.com"])
+                    .and(col("o", "quantity").between(1, 4))
+                    .build()
+                    .unwrap(),
+            ])
+            .group_by(vec![GroupByItem {
+                table_alias: Some("u".to_string()),
+                field: "name".to_string(),
+                ..Default::default()
+            }])
+            .unwrap()
+            .order_by(vec![OrderByItem {
+                table_alias: None,
+                field: "total".to_string(),
+                sequence: Sequence::Desc,
+                ..Default::default()
+            }])
+            .unwrap()
+            .limit(2)
+            .offset(0)
+            .bind_limit_offset();
+        let rows = builder.query_map(&connection, |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, f64>(1)?))
+        });
+        assert_eq!(
+            rows,
+            Ok(vec![
+                ("Alice".to_string(), 2800.0),
+                ("Bob".to_string(), 400.0)
+            ])
+        );
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["name"])
+            .filter(vec![col("u", "profile").is_null().build().unwrap()]);
+        let rows = builder.query_map(&connection, |row| row.get::<_, String>(0));
+        assert_eq!(rows, Ok(vec!["Carol".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_rusqlite_update_delete() {
+        let connection = connection();
+        let mut builder = UpdateBuilder::new(PlaceholderKind::DollarSequential);
+        builder
+            .table("users")
+            .set(vec![SetFieldUpdate {
+                field: "profile".to_string(),
+                value: SetValue::Value(json!({"note": "it's closed"})),
+            }])
+            .unwrap()
+            .filter(vec![col("", "id").not_in([1, 2]).build().unwrap()]);
+        assert_eq!(builder.execute(&connection), Ok(1));
+
+        let mut builder = DeleteBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("orders", None)
+            .filter(vec![col("", "quantity").lt(2).build().unwrap()]);
+        let rows = builder
+            .returning(vec!["id"])
+            .query_map(&connection, |row| row.get::<_, i64>(0));
+        assert_eq!(rows, Ok(vec![1, 3]));
+
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["profile"])
+            .filter(vec![col("u", "id").eq(3).build().unwrap()]);
+        let rows = builder.query_map(&connection, |row| row.get::<_, String>(0));
+        assert_eq!(rows, Ok(vec![r#"{"note":"it's closed"}"#.to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_rusqlite_sqlite_builders() {
+        let connection = connection();
+        let mut builder = sqlite::InsertBuilder::new(Placeholder::Named);
+        builder
+            .table("users")
+            .columns(vec!["name", "email"])
+            .values(vec![json!("Alice B."), json!("alice@example.com")])
+            .unwrap()
+            .on_conflict(OnConflict::DoUpdate(
+                vec!["email".to_string()],
+                vec![ConflictUpdate::Excluded("name".to_string())],
+            ));
+        assert_eq!(builder.execute(&connection), Ok(1));
+
+        let mut builder = sqlite::UpdateBuilder::new(Placeholder::Numbered);
+        builder
+            .table("users")
+            .set(vec![SetFieldUpdate {
+                field: "profile".to_string(),
+                value: SetValue::Value(json!({"city": "Davao"})),
+            }])
+            .unwrap()
+            .filter(vec![col("", "id").eq(3).build().unwrap()]);
+        assert_eq!(builder.execute(&connection), Ok(1));
+
+        let mut builder = sqlite::DeleteBuilder::new(Placeholder::QuestionMark);
+        builder
+            .table("orders")
+            .filter(vec![col("", "user_id").eq(2).build().unwrap()]);
+        assert_eq!(builder.execute(&connection), Ok(1));
+
+        let mut builder = sqlite::SelectBuilder::new(Placeholder::Named);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["name", "profile"])
+            .filter(vec![
+                col("u", "id")
+                    .in_list([1, 3])
+                    .and(col("u", "name").like("%a%"))
+                    .build()
+                    .unwrap(),
+            ]);
+        let rows = builder.query_map(&connection, |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        });
+        assert_eq!(
+            rows,
+            Ok(vec![
+                (
+                    "Alice B.".to_string(),
+                    r#"{"city": "Manila", "tags": ["admin"]}"#.to_string()
+                ),
+                ("Carol".to_string(), r#"{"city":"Davao"}"#.to_string()),
+            ])
+        );
+        let orders: i64 = connection
+            .query_row("SELECT COUNT(*) FROM orders", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(orders, 2);
+    }
+
+    #[tokio::test]
+    async fn test_rusqlite_errors() {
+        let connection = connection();
+        let mut builder = SelectBuilder::new(PlaceholderKind::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                col("u", "profile")
+                    .jsonb_contains(json!({"city": "Manila"}))
+                    .build()
+                    .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build_rusqlite(),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "@>".to_string(),
+                target: "SQLite".to_string(),
+            })
+        );
+        let result = builder.query_map(&connection, |row| row.get::<_, i64>(0));
+        assert!(
+            matches!(result, Err(::rusqlite::Error::ToSqlConversionFailure(_))),
+            "{result:?}"
+        );
+
+        let mut builder = sqlite::SelectBuilder::new(Placeholder::Named);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                Conditions::from(col("u", "email").condition(
+                    Operator::Eq,
+                    Some(ConditionValue::Named("email".to_string())),
+                ))
+                .build()
+                .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build_rusqlite(),
+            Err(Error::MissingParameter {
+                builder: "SelectBuilder",
+                field: "email".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,490 @@
+use crate::dialect::{Dialect, Sqlite};
+use crate::error::{Error, Result};
+use crate::identifier::quote_identifier;
+use crate::placeholder::Params;
+use crate::postgres::{
+    ExpressionBuilder, GroupByBuilder, GroupByItem, JoinBuilder, JoinKind, Operator,
+    OrderByBuilder, OrderByItem, Sequence, WhereBuilder,
+};
+use crate::sqlite::Placeholder;
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct SelectBuilder {
+    pub distinct: bool,
+    pub table: String,
+    fields: Vec<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    join_values: Vec<Value>,
+    filter_values: Vec<Value>,
+    order_by_values: Vec<Value>,
+    filter_statement: Option<String>,
+    join_statement: Option<String>,
+    group_by_statement: Option<String>,
+    order_by_statement: Option<String>,
+    pub(crate) operators: Vec<Operator>, // Used to check the operators SQLite supports
+    filter_operators: Vec<Operator>,     // The operators of the filter, replaced with it
+    pub placeholder: Placeholder,
+}
+
+impl SelectBuilder {
+    pub fn new(placeholder: Placeholder) -> Self {
+        Self {
+            placeholder,
+            ..Default::default()
+        }
+    }
+
+    pub fn distinct(&mut self) -> &mut Self {
+        self.distinct = true;
+        self
+    }
+
+    pub fn table(&mut self, table: &str, table_alias: &str) -> &mut Self {
+        self.table = format!("{} as {table_alias}", quote_identifier(table));
+        self
+    }
+
+    fn append_join(&mut self, mut item: JoinBuilder) -> &mut Self {
+        self.join_values.append(&mut item.values);
+        self.join_statement = if let Some(statement) = &self.join_statement {
+            Some(format!("{} {}", statement, item.statement))
+        } else {
+            Some(item.statement)
+        };
+        self
+    }
+
+    pub fn join(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        values: Vec<ExpressionBuilder>,
+    ) -> Result<&mut Self> {
+        self.operators
+            .extend(values.iter().flat_map(|item| item.operators.to_owned()));
+        let item = JoinBuilder::build(kind, &quote_identifier(table), table_alias, values)?;
+        Ok(self.append_join(item))
+    }
+
+    pub fn join_using(
+        &mut self,
+        kind: JoinKind,
+        table: &str,
+        table_alias: &str,
+        columns: Vec<&str>,
+    ) -> Result<&mut Self> {
+        let item = JoinBuilder::build_using(kind, &quote_identifier(table), table_alias, columns)?;
+        Ok(self.append_join(item))
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.filter_operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    /// Defines columns for a query by combining a table alias with column names.
+    /// If no columns are specified, it selects all columns of the table alias.
+    ///
+    /// # Example
+    /// ```ignore
+    /// columns("t", vec!["id", "name", "email"]);
+    /// ```
+    pub fn columns(&mut self, table_alias: &str, values: Vec<&str>) -> &mut Self {
+        let mut fields = if values.is_empty() {
+            vec![format!("{table_alias}.*")]
+        } else {
+            values
+                .iter()
+                .map(|value| format!("{table_alias}.{value}"))
+                .collect()
+        };
+        self.fields.append(&mut fields);
+        self
+    }
+
+    /// Allows users to define columns with custom expressions or functions, such as the
+    /// JSON paths rendered by `JsonBuilder`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// columns_raw(vec!["first_name || ' ' || last_name as full_name"]);
+    /// ```
+    pub fn columns_raw(&mut self, values: Vec<&str>) -> &mut Self {
+        let mut fields = if values.is_empty() {
+            vec!["*".to_string()]
+        } else {
+            values.iter().map(|value| value.to_string()).collect()
+        };
+        self.fields.append(&mut fields);
+        self
+    }
+
+    /// SQLite has no `USING` ordering, so those items are rejected.
+    pub fn order_by(&mut self, values: Vec<OrderByItem>) -> Result<&mut Self> {
+        if values
+            .iter()
+            .any(|item| matches!(item.sequence, Sequence::Using(_)))
+        {
+            return Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "using".to_string(),
+                target: "SQLite".to_string(),
+            });
+        }
+        if !values.is_empty() {
+            let result = OrderByBuilder::build_values(values)?;
+            self.order_by_statement = Some(result.statement);
+            self.order_by_values = result.values;
+        }
+        Ok(self)
+    }
+
+    pub fn group_by(&mut self, values: Vec<GroupByItem>) -> Result<&mut Self> {
+        if !values.is_empty() {
+            self.group_by_statement = Some(GroupByBuilder::build(values)?);
+        }
+        Ok(self)
+    }
+
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.limit = Some(value);
+        self
+    }
+
+    pub fn offset(&mut self, value: usize) -> &mut Self {
+        self.offset = Some(value);
+        self
+    }
+
+    /// The operators of the statement, checked against SQLite.
+    pub(crate) fn operators(&self) -> Vec<Operator> {
+        [self.operators.as_slice(), self.filter_operators.as_slice()].concat()
+    }
+
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = Vec::new();
+        values.append(&mut self.join_values.to_owned());
+        values.append(&mut self.filter_values.to_owned());
+        values.append(&mut self.order_by_values.to_owned());
+        values
+    }
+
+    /// Returns the values paired with their placeholder names, for `Placeholder::Named`.
+    pub fn get_named_values(&self) -> Vec<(String, Value)> {
+        Placeholder::named_values(self.get_values())
+    }
+
+    /// Returns the values paired with their placeholder names, as [`get_named_values`](Self::get_named_values),
+    /// followed by the named parameters of the conditions, e.g. `:email`, with their value
+    /// in `params`. Errors when a named parameter has no value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let values = builder.bind_named(&Params::from([("email".to_string(), json!("x"))]))?;
+    /// ```
+    pub fn bind_named(&self, params: &Params) -> Result<Vec<(String, Value)>> {
+        Placeholder::bind_named("SelectBuilder", &self.render()?, self.get_values(), params)
+    }
+
+    pub fn build(&self) -> Result<String> {
+        Sqlite.check_operators("SelectBuilder", &self.operators())?;
+        Ok(self.placeholder.render(&self.render()?))
+    }
+
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self) -> Result<String> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "table".to_string(),
+            });
+        }
+        let fields = self.fields.join(", ");
+        let mut statement: String = if self.distinct {
+            format!("SELECT DISTINCT {} FROM {}", fields, self.table)
+        } else {
+            format!("SELECT {} FROM {}", fields, self.table)
+        };
+        if let Some(value) = &self.join_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.filter_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.group_by_statement {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(value) = &self.order_by_statement {
+            statement = format!("{statement} {value}");
+        }
+        match (self.limit, self.offset) {
+            (Some(limit), Some(offset)) => {
+                statement = format!("{statement} LIMIT {limit} OFFSET {offset}")
+            }
+            (Some(limit), None) => statement = format!("{statement} LIMIT {limit}"),
+            // SQLite only accepts OFFSET after a LIMIT, -1 means no limit.
+            (None, Some(offset)) => statement = format!("{statement} LIMIT -1 OFFSET {offset}"),
+            (None, None) => {}
+        }
+        Ok(statement.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+pub mod test_select_builder {
+    use super::*;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Logic, Nulls, Operator};
+    use crate::sqlite::JsonBuilder;
+    use crate::sqlite::test_database::{connection, params};
+    use rusqlite::params_from_iter;
+    use serde_json::Number;
+
+    #[tokio::test]
+    async fn test_select_builder() {
+        let join_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: Some("p".to_string()),
+                field: "id".to_string(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Field(
+                    "o".to_string(),
+                    "product_id".to_string(),
+                )),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let filter_clause = ExpressionBuilder::build(
+            vec![
+                ConditionBuilder {
+                    table_alias: Some("o".to_string()),
+                    field: "user_id".to_string(),
+                    operator: Operator::Eq,
+                    value: Some(ConditionValue::Single(Value::Number(Number::from(1)))),
+                    logic: None,
+                },
+                ConditionBuilder {
+                    table_alias: Some("p".to_string()),
+                    field: "price".to_string(),
+                    operator: Operator::Between,
+                    value: Some(ConditionValue::Range(
+                        Value::Number(Number::from(500)),
+                        Value::Number(Number::from(1500)),
+                    )),
+                    logic: Some(Logic::And),
+                },
+            ],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(Placeholder::Numbered);
+        let result = builder
+            .table("orders", "o")
+            .join(JoinKind::Inner, "products", "p", vec![join_clause])
+            .unwrap()
+            .columns("p", vec!["name"])
+            .filter(vec![filter_clause])
+            .order_by(vec![OrderByItem {
+                table_alias: Some("p".to_string()),
+                field: "price".to_string(),
+                sequence: Sequence::Desc,
+                nulls: Some(Nulls::Last),
+                ..Default::default()
+            }])
+            .unwrap()
+            .limit(10)
+            .offset(0)
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "SELECT p.name FROM orders as o INNER JOIN products as p ON p.id = o.product_id WHERE o.user_id = ?1 AND p.price BETWEEN ?2 AND ?3 ORDER BY p.price DESC NULLS LAST LIMIT 10 OFFSET 0"
+        );
+
+        let connection = connection();
+        let mut query = connection.prepare(&statement).unwrap();
+        let names: Vec<String> = query
+            .query_map(params_from_iter(params(&builder.get_values())), |row| {
+                row.get(0)
+            })
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(names, vec!["Laptop".to_string(), "Phone".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_named_placeholders() {
+        let filter_clause = ExpressionBuilder::build(
+            vec![
+                ConditionBuilder {
+                    table_alias: Some("u".to_string()),
+                    field: "email".to_string(),
+                    operator: Operator::Like,
+                    value: Some(ConditionValue::Single(Value::String(
+                        "%@example.com".to_string(),
+                    ))),
+                    logic: None,
+                },
+                ConditionBuilder {
+                    table_alias: Some("u".to_string()),
+                    field: "name".to_string(),
+                    operator: Operator::Neq,
+                    value: Some(ConditionValue::Named("name".to_string())),
+                    logic: Some(Logic::And),
+                },
+            ],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(Placeholder::Named);
+        let result = builder
+            .table("users", "u")
+            .columns("u", vec!["name"])
+            .filter(vec![filter_clause])
+            .offset(1)
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "SELECT u.name FROM users as u WHERE u.email LIKE :p1 AND u.name != :name LIMIT -1 OFFSET 1"
+        );
+        assert_eq!(
+            builder.bind_named(&Params::new()),
+            Err(Error::MissingParameter {
+                builder: "SelectBuilder",
+                field: "name".to_string(),
+            })
+        );
+
+        let connection = connection();
+        let mut query = connection.prepare(&statement).unwrap();
+        let named_values = builder
+            .bind_named(&Params::from([("name".to_string(), Value::from("Alice"))]))
+            .unwrap();
+        let values: Vec<(String, rusqlite::types::Value)> = named_values
+            .iter()
+            .map(|(name, value)| (name.to_owned(), params(&[value.to_owned()]).remove(0)))
+            .collect();
+        let binds: Vec<(&str, &dyn rusqlite::ToSql)> = values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value as &dyn rusqlite::ToSql))
+            .collect();
+        let names: Vec<String> = query
+            .query_map(binds.as_slice(), |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(names, vec!["Carol".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_json() {
+        let city = JsonBuilder::extract_text(Some("u"), "profile", "$.city").unwrap();
+        let filter_clause = ExpressionBuilder::build(
+            vec![ConditionBuilder {
+                table_alias: None,
+                field: JsonBuilder::extract(Some("u"), "profile", "$.tags[0]").unwrap(),
+                operator: Operator::Eq,
+                value: Some(ConditionValue::Single(Value::String("admin".to_string()))),
+                logic: None,
+            }],
+            None,
+        )
+        .unwrap();
+        let mut builder = SelectBuilder::new(Placeholder::QuestionMark);
+        let result = builder
+            .table("users", "u")
+            .columns_raw(vec![&format!("{city} as city")])
+            .filter(vec![filter_clause])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "SELECT u.profile ->> '$.city' as city FROM users as u WHERE json_extract(u.profile, '$.tags[0]') = ?"
+        );
+
+        let connection = connection();
+        let city: String = connection
+            .query_row(
+                &statement,
+                params_from_iter(params(&builder.get_values())),
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(city, "Manila");
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_unsupported() {
+        let mut builder = SelectBuilder::new(Placeholder::QuestionMark);
+        let result = builder.order_by(vec![OrderByItem {
+            field: "id".to_string(),
+            sequence: Sequence::Using(">".to_string()),
+            ..Default::default()
+        }]);
+        assert!(result.is_err(), "expecting USING to be rejected");
+        let builder = SelectBuilder::new(Placeholder::QuestionMark);
+        assert!(builder.build().is_err(), "expecting missing table error");
+        // The PostgreSQL-only operators are refused, as by `build_with(&Sqlite)`.
+        let mut builder = SelectBuilder::new(Placeholder::QuestionMark);
+        builder
+            .table("users", "u")
+            .columns("u", vec!["id"])
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: Some("u".to_string()),
+                        field: "profile".to_string(),
+                        operator: Operator::JsonbHasKey,
+                        value: Some(ConditionValue::Single(Value::String("city".to_string()))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ]);
+        assert_eq!(
+            builder.build(),
+            Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "?".to_string(),
+                target: "SQLite".to_string(),
+            })
+        );
+
+        // Replacing the filter drops its operators.
+        builder.filter(vec![
+            ExpressionBuilder::build(
+                vec![ConditionBuilder {
+                    table_alias: Some("u".to_string()),
+                    field: "name".to_string(),
+                    operator: Operator::Eq,
+                    value: Some(ConditionValue::Single(Value::String("bob".to_string()))),
+                    logic: None,
+                }],
+                None,
+            )
+            .unwrap(),
+        ]);
+        assert_eq!(
+            builder.build(),
+            Ok("SELECT u.id FROM users as u WHERE u.name = ?".to_string())
+        );
+    }
+}
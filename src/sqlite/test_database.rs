@@ -0,0 +1,49 @@
+//! In-memory SQLite database used to check the generated statements against a real engine.
+use crate::sqlite::rusqlite::sqlite_value;
+use rusqlite::Connection;
+use rusqlite::types::Value as SqliteValue;
+use serde_json::Value;
+
+pub fn connection() -> Connection {
+    let connection = Connection::open_in_memory().expect("in-memory database");
+    connection
+        .execute_batch(
+            "CREATE TABLE users (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                email TEXT UNIQUE NOT NULL,
+                profile TEXT
+            );
+            CREATE TABLE products (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                price REAL NOT NULL
+            );
+            CREATE TABLE orders (
+                id INTEGER PRIMARY KEY,
+                user_id INTEGER REFERENCES users(id),
+                product_id INTEGER REFERENCES products(id),
+                quantity INTEGER NOT NULL,
+                order_date TEXT NOT NULL
+            );
+            INSERT INTO users (name, email, profile) VALUES
+                ('Alice', 'alice@example.com', '{\"city\": \"Manila\", \"tags\": [\"admin\"]}'),
+                ('Bob', 'bob@example.com', '{\"city\": \"Cebu\", \"tags\": []}'),
+                ('Carol', 'carol@example.com', NULL);
+            INSERT INTO products (name, price) VALUES
+                ('Laptop', 1200.00),
+                ('Phone', 800.00),
+                ('Tablet', 400.00);
+            INSERT INTO orders (user_id, product_id, quantity, order_date) VALUES
+                (1, 1, 1, '2025-07-01'),
+                (1, 2, 2, '2025-07-02'),
+                (2, 3, 1, '2025-07-03');",
+        )
+        .expect("test tables");
+    connection
+}
+
+/// Converts JSON values to SQLite values with [`sqlite_value`].
+pub fn params(values: &[Value]) -> Vec<SqliteValue> {
+    values.iter().cloned().map(sqlite_value).collect()
+}
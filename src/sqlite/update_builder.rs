@@ -0,0 +1,220 @@
+use crate::dialect::{Dialect, Sqlite};
+use crate::error::{Error, Result};
+use crate::identifier::quote_identifier;
+use crate::placeholder::Params;
+use crate::postgres::{ExpressionBuilder, Operator, SetFieldUpdate, SetValue, WhereBuilder};
+use crate::sqlite::Placeholder;
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct UpdateBuilder {
+    pub table: String,
+    set_values: Vec<Value>,
+    filter_values: Vec<Value>,
+    set_statement: String,
+    filter_statement: Option<String>,
+    returning_statement: Option<String>,
+    pub(crate) operators: Vec<Operator>, // Used to check the operators SQLite supports
+    pub placeholder: Placeholder,
+}
+
+impl UpdateBuilder {
+    pub fn new(placeholder: Placeholder) -> Self {
+        Self {
+            placeholder,
+            ..Default::default()
+        }
+    }
+
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        self.table = quote_identifier(table);
+        self
+    }
+
+    /// Sets the columns to update. Subqueries are not supported.
+    pub fn set(&mut self, values: Vec<SetFieldUpdate>) -> Result<&mut Self> {
+        if !self.set_statement.is_empty() {
+            return Err(Error::DuplicateClause {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        if values.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        let mut expressions: Vec<String> = Vec::new();
+        for item in values {
+            match item.value {
+                SetValue::Value(value) => {
+                    expressions.push(format!("{} = ?", quote_identifier(&item.field)));
+                    self.set_values.push(value);
+                }
+                // Casts are PostgreSQL syntax, the value is bound as is
+                SetValue::Typed(value) => {
+                    expressions.push(format!("{} = ?", quote_identifier(&item.field)));
+                    self.set_values.push(value.to_json()?);
+                }
+                SetValue::Query(_) => {
+                    return Err(Error::UnsupportedClause {
+                        builder: "UpdateBuilder",
+                        field: item.field,
+                        target: "SQLite".to_string(),
+                    });
+                }
+            }
+        }
+        self.set_statement = format!("SET {}", expressions.join(", "));
+        Ok(self)
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        if !values.is_empty() {
+            self.operators = values
+                .iter()
+                .flat_map(|item| item.operators.to_owned())
+                .collect();
+            let result = WhereBuilder::build(values);
+            self.filter_statement = Some(result.statement);
+            self.filter_values = result.values;
+        }
+        self
+    }
+
+    pub fn returning(&mut self, values: Vec<&str>) -> &mut Self {
+        if !values.is_empty() {
+            self.returning_statement = Some(format!("RETURNING {}", values.join(", ")));
+        }
+        self
+    }
+
+    pub fn get_values(&self) -> Vec<Value> {
+        let mut values: Vec<Value> = Vec::new();
+        values.append(&mut self.set_values.to_owned());
+        values.append(&mut self.filter_values.to_owned());
+        values
+    }
+
+    /// Returns the values paired with their placeholder names, for `Placeholder::Named`.
+    pub fn get_named_values(&self) -> Vec<(String, Value)> {
+        Placeholder::named_values(self.get_values())
+    }
+
+    /// Returns the values paired with their placeholder names, as [`get_named_values`](Self::get_named_values),
+    /// followed by the named parameters of the conditions, e.g. `:email`, with their value
+    /// in `params`. Errors when a named parameter has no value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let values = builder.bind_named(&Params::from([("email".to_string(), json!("x"))]))?;
+    /// ```
+    pub fn bind_named(&self, params: &Params) -> Result<Vec<(String, Value)>> {
+        Placeholder::bind_named("UpdateBuilder", &self.render()?, self.get_values(), params)
+    }
+
+    pub fn build(&self) -> Result<String> {
+        Sqlite.check_operators("UpdateBuilder", &self.operators)?;
+        Ok(self.placeholder.render(&self.render()?))
+    }
+
+    /// Renders the statement with `?` placeholders.
+    pub(crate) fn render(&self) -> Result<String> {
+        if self.table.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "table".to_string(),
+            });
+        }
+        if self.set_statement.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            });
+        }
+        let mut statement = format!("UPDATE {} {}", self.table, self.set_statement);
+        if let Some(stmt) = &self.filter_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        if let Some(stmt) = &self.returning_statement {
+            statement.push_str(&format!(" {stmt}"));
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_update_builder {
+    use super::*;
+    use crate::postgres::{ConditionBuilder, ConditionValue, Operator};
+    use crate::sqlite::test_database::{connection, params};
+    use rusqlite::params_from_iter;
+
+    #[tokio::test]
+    async fn test_update_builder() {
+        let mut builder = UpdateBuilder::new(Placeholder::Numbered);
+        let result = builder
+            .table("users")
+            .set(vec![SetFieldUpdate {
+                field: "name".to_string(),
+                value: SetValue::Value(Value::String("Alicia".to_string())),
+            }])
+            .unwrap()
+            .filter(vec![
+                ExpressionBuilder::build(
+                    vec![ConditionBuilder {
+                        table_alias: None,
+                        field: "email".to_string(),
+                        operator: Operator::Eq,
+                        value: Some(ConditionValue::Single(Value::String(
+                            "alice@example.com".to_string(),
+                        ))),
+                        logic: None,
+                    }],
+                    None,
+                )
+                .unwrap(),
+            ])
+            .returning(vec!["id"])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        let statement = result.unwrap();
+        assert_eq!(
+            statement,
+            "UPDATE users SET name = ?1 WHERE email = ?2 RETURNING id"
+        );
+
+        let connection = connection();
+        let id: i64 = connection
+            .query_row(
+                &statement,
+                params_from_iter(params(&builder.get_values())),
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_builder_errors() {
+        let mut builder = UpdateBuilder::new(Placeholder::QuestionMark);
+        let result = builder.table("users").build();
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "set".to_string(),
+            })
+        );
+        let result = builder
+            .set(vec![SetFieldUpdate {
+                field: "name".to_string(),
+                value: SetValue::Query(crate::postgres::SelectBuilder::new(
+                    crate::placeholder::PlaceholderKind::QuestionMark,
+                )),
+            }])
+            .map(|_| ());
+        assert!(result.is_err(), "expecting subqueries to be rejected");
+    }
+}
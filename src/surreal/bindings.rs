@@ -0,0 +1,41 @@
+use serde_json::Value;
+
+/// Collects the values bound to a SurrealQL statement as `$p1`, `$p2`, ... parameters.
+#[derive(Clone, Debug, Default)]
+pub struct Bindings {
+    values: Vec<(String, Value)>,
+}
+
+impl Bindings {
+    /// Stores the value and returns the parameter to use in the statement, e.g. `$p1`.
+    pub fn bind(&mut self, value: &Value) -> String {
+        let name = format!("p{}", self.values.len() + 1);
+        let parameter = format!("${name}");
+        self.values.push((name, value.to_owned()));
+        parameter
+    }
+
+    /// Returns the parameter names (without the `$`) paired with their values.
+    pub fn into_values(self) -> Vec<(String, Value)> {
+        self.values
+    }
+}
+
+#[cfg(test)]
+pub mod test_bindings {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bindings() {
+        let mut bindings = Bindings::default();
+        assert_eq!(bindings.bind(&Value::Null), "$p1");
+        assert_eq!(bindings.bind(&Value::Bool(true)), "$p2");
+        assert_eq!(
+            bindings.into_values(),
+            vec![
+                ("p1".to_string(), Value::Null),
+                ("p2".to_string(), Value::Bool(true)),
+            ]
+        );
+    }
+}
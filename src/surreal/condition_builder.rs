@@ -0,0 +1,284 @@
+use crate::error::{Error, Result};
+use crate::postgres::{Logic, Operator};
+use crate::surreal::idiom::validate_idiom;
+use crate::surreal::{Bindings, ConditionOperator, SurrealOperator};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A single SurrealQL condition, e.g. `tags CONTAINS $p1`. The `field` is a field path
+/// (idiom) and can be a graph traversal such as `->likes->post`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionBuilder {
+    pub field: String,
+    pub operator: ConditionOperator,
+    pub value: Option<Value>,
+    pub logic: Option<Logic>,
+}
+
+impl ConditionBuilder {
+    pub fn validate(item: &ConditionBuilder) -> Result<()> {
+        // Like, ILike, Between and the JSONB operators have no SurrealQL equivalent.
+        if let ConditionOperator::Standard(operator) = &item.operator {
+            let is_supported = matches!(
+                operator,
+                Operator::Eq
+                    | Operator::Neq
+                    | Operator::Gt
+                    | Operator::Gte
+                    | Operator::Lt
+                    | Operator::Lte
+                    | Operator::In
+                    | Operator::NotIn
+                    | Operator::IsNull
+                    | Operator::NotNull
+            );
+            if !is_supported {
+                return Err(Error::UnsupportedClause {
+                    builder: "ConditionBuilder",
+                    field: operator.to_string(),
+                    target: "SurrealDB".to_string(),
+                });
+            }
+        }
+        let is_valid = matches!(
+            (&item.operator, &item.value),
+            (
+                ConditionOperator::Standard(Operator::IsNull | Operator::NotNull),
+                None
+            ) | (
+                ConditionOperator::Standard(Operator::In | Operator::NotIn)
+                    | ConditionOperator::Surreal(
+                        SurrealOperator::ContainsAll
+                            | SurrealOperator::ContainsAny
+                            | SurrealOperator::ContainsNone
+                            | SurrealOperator::AllInside
+                            | SurrealOperator::AnyInside
+                            | SurrealOperator::NoneInside,
+                    ),
+                Some(Value::Array(_)),
+            ) | (
+                ConditionOperator::Surreal(SurrealOperator::Matches),
+                Some(Value::String(_))
+            ) | (
+                ConditionOperator::Standard(
+                    Operator::Eq
+                        | Operator::Neq
+                        | Operator::Gt
+                        | Operator::Gte
+                        | Operator::Lt
+                        | Operator::Lte,
+                ) | ConditionOperator::Surreal(
+                    SurrealOperator::Contains
+                        | SurrealOperator::ContainsNot
+                        | SurrealOperator::Inside
+                        | SurrealOperator::NotInside,
+                ),
+                Some(_),
+            )
+        );
+        if !is_valid {
+            return Err(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: item.field.to_owned(),
+                operator: item.operator.to_string(),
+                value: match &item.value {
+                    None => "no value",
+                    Some(Value::Array(_)) => "an array value",
+                    Some(_) => "a single value",
+                }
+                .to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn build(item: &ConditionBuilder, bindings: &mut Bindings) -> Result<String> {
+        validate_idiom("ConditionBuilder", "field", &item.field)?;
+        Self::validate(item)?;
+        let condition = if let Some(value) = &item.value {
+            format!("{} {} {}", item.field, item.operator, bindings.bind(value))
+        } else {
+            format!("{} {}", item.field, item.operator)
+        };
+        if let Some(logic) = &item.logic {
+            Ok(format!("{logic} {condition}"))
+        } else {
+            Ok(condition)
+        }
+    }
+}
+
+/// A group of conditions, wrapped in parentheses when combined with other groups.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ExpressionBuilder {
+    pub conditions: Vec<ConditionBuilder>,
+    pub logic: Option<Logic>,
+}
+
+impl ExpressionBuilder {
+    pub fn new(conditions: Vec<ConditionBuilder>, logic: Option<Logic>) -> Self {
+        Self { conditions, logic }
+    }
+
+    pub fn build(item: &ExpressionBuilder, bindings: &mut Bindings) -> Result<String> {
+        let conditions = item
+            .conditions
+            .iter()
+            .map(|condition| ConditionBuilder::build(condition, bindings))
+            .collect::<Result<Vec<String>>>()?;
+        Ok(conditions.join(" "))
+    }
+
+    /// Renders the `WHERE` clause of the expressions, or `None` when there are none.
+    pub fn build_where(
+        values: &[ExpressionBuilder],
+        bindings: &mut Bindings,
+    ) -> Result<Option<String>> {
+        if values.is_empty() {
+            return Ok(None);
+        }
+        let do_grouping = values.len() > 1;
+        let mut expressions: Vec<String> = Vec::new();
+        for item in values {
+            let condition = Self::build(item, bindings)?;
+            let expression = if !do_grouping {
+                condition
+            } else if let Some(logic) = &item.logic {
+                format!("{logic} ({condition})")
+            } else {
+                format!("({condition})")
+            };
+            expressions.push(expression);
+        }
+        Ok(Some(format!("WHERE {}", expressions.join(" "))))
+    }
+}
+
+#[cfg(test)]
+pub mod test_condition_builder {
+    use super::*;
+    use serde_json::json;
+
+    fn condition(
+        field: &str,
+        operator: impl Into<ConditionOperator>,
+        value: Option<Value>,
+    ) -> ConditionBuilder {
+        ConditionBuilder {
+            field: field.to_string(),
+            operator: operator.into(),
+            value,
+            logic: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_condition_builder() {
+        let mut bindings = Bindings::default();
+        let cases = vec![
+            (
+                condition("age", Operator::Gte, Some(json!(18))),
+                "age >= $p1",
+            ),
+            (
+                condition("tags", SurrealOperator::Contains, Some(json!("rust"))),
+                "tags CONTAINS $p2",
+            ),
+            (
+                condition(
+                    "role",
+                    SurrealOperator::Inside,
+                    Some(json!(["admin", "owner"])),
+                ),
+                "role INSIDE $p3",
+            ),
+            (
+                condition("name", SurrealOperator::Matches, Some(json!("tobie"))),
+                "name ~ $p4",
+            ),
+            (condition("email", Operator::IsNull, None), "email IS NULL"),
+            (
+                condition("->likes->post.title", Operator::Eq, Some(json!("Hello"))),
+                "->likes->post.title = $p5",
+            ),
+            (
+                condition("status", Operator::In, Some(json!(["active"]))),
+                "status IN $p6",
+            ),
+        ];
+        for (item, expected) in cases {
+            let result = ConditionBuilder::build(&item, &mut bindings);
+            assert_eq!(result, Ok(expected.to_string()));
+        }
+        assert_eq!(bindings.into_values().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_condition_builder_errors() {
+        let mut bindings = Bindings::default();
+        let result = ConditionBuilder::build(
+            &condition("name", Operator::Like, Some(json!("%a%"))),
+            &mut bindings,
+        );
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "ConditionBuilder",
+                field: "LIKE".to_string(),
+                target: "SurrealDB".to_string(),
+            })
+        );
+        let result = ConditionBuilder::build(
+            &condition("tags", SurrealOperator::ContainsAll, Some(json!("rust"))),
+            &mut bindings,
+        );
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedOperatorValue {
+                builder: "ConditionBuilder",
+                field: "tags".to_string(),
+                operator: "CONTAINSALL".to_string(),
+                value: "a single value".to_string(),
+            })
+        );
+        let result = ConditionBuilder::build(
+            &condition("name; DELETE user", Operator::Eq, Some(json!(1))),
+            &mut bindings,
+        );
+        assert!(result.is_err(), "expecting invalid field");
+    }
+
+    #[tokio::test]
+    async fn test_build_where() {
+        let mut bindings = Bindings::default();
+        let result = ExpressionBuilder::build_where(
+            &[
+                ExpressionBuilder::new(
+                    vec![
+                        condition("age", Operator::Gte, Some(json!(18))),
+                        ConditionBuilder {
+                            logic: Some(Logic::And),
+                            ..condition("active", Operator::Eq, Some(json!(true)))
+                        },
+                    ],
+                    None,
+                ),
+                ExpressionBuilder::new(
+                    vec![condition(
+                        "tags",
+                        SurrealOperator::Contains,
+                        Some(json!("admin")),
+                    )],
+                    Some(Logic::Or),
+                ),
+            ],
+            &mut bindings,
+        );
+        assert_eq!(
+            result,
+            Ok(Some(
+                "WHERE (age >= $p1 AND active = $p2) OR (tags CONTAINS $p3)".to_string()
+            ))
+        );
+    }
+}
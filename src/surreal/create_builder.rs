@@ -0,0 +1,122 @@
+use crate::error::{Error, Result};
+use crate::surreal::{Bindings, Data, Return, Target};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct CreateBuilder {
+    target: Option<Target>,
+    data: Option<Data>,
+    returning: Option<Return>,
+}
+
+impl CreateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a record in the table with a generated id, or with the given record id.
+    pub fn target(&mut self, value: Target) -> &mut Self {
+        self.target = Some(value);
+        self
+    }
+
+    /// Sets the `CONTENT` or `SET` clause. `MERGE` and `PATCH` only apply to updates.
+    pub fn data(&mut self, value: Data) -> Result<&mut Self> {
+        if self.data.is_some() {
+            return Err(Error::DuplicateClause {
+                builder: "CreateBuilder",
+                field: "data".to_string(),
+            });
+        }
+        if matches!(value, Data::Merge(_) | Data::Patch(_)) {
+            return Err(Error::UnsupportedClause {
+                builder: "CreateBuilder",
+                field: "data".to_string(),
+                target: "SurrealDB CREATE".to_string(),
+            });
+        }
+        self.data = Some(value);
+        Ok(self)
+    }
+
+    pub fn returning(&mut self, value: Return) -> &mut Self {
+        self.returning = Some(value);
+        self
+    }
+
+    fn render(&self) -> Result<(String, Bindings)> {
+        let Some(target) = &self.target else {
+            return Err(Error::EmptyField {
+                builder: "CreateBuilder",
+                field: "target".to_string(),
+            });
+        };
+        let mut bindings = Bindings::default();
+        let mut statement = format!("CREATE {}", target.build()?);
+        if let Some(data) = &self.data {
+            statement = format!("{statement} {}", data.build(&mut bindings)?);
+        }
+        if let Some(returning) = &self.returning {
+            statement = format!("{statement} {}", returning.build()?);
+        }
+        Ok((statement, bindings))
+    }
+
+    /// Returns the parameter names (without the `$`) paired with their values.
+    pub fn get_values(&self) -> Vec<(String, Value)> {
+        self.render()
+            .map(|(_, bindings)| bindings.into_values())
+            .unwrap_or_default()
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.render().map(|(statement, _)| statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_create_builder {
+    use super::*;
+    use crate::surreal::RecordId;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_create_builder() {
+        let content = json!({"name": "Tobie", "tags": ["rust"]});
+        let mut builder = CreateBuilder::new();
+        let result = builder
+            .target(Target::Record(RecordId::new("person", "tobie")))
+            .data(Data::Content(content.as_object().unwrap().to_owned()))
+            .unwrap()
+            .returning(Return::None)
+            .build();
+        assert_eq!(
+            result,
+            Ok("CREATE person:tobie CONTENT $p1 RETURN NONE".to_string())
+        );
+        assert_eq!(builder.get_values(), vec![("p1".to_string(), content)]);
+
+        let mut builder = CreateBuilder::new();
+        let result = builder
+            .target(Target::Table("person".to_string()))
+            .data(Data::Set(vec![("name".to_string(), json!("Jaime"))]))
+            .unwrap()
+            .build();
+        assert_eq!(result, Ok("CREATE person SET name = $p1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_builder_errors() {
+        let mut builder = CreateBuilder::new();
+        assert!(builder.build().is_err(), "expecting missing target");
+        let result = builder.data(Data::Merge(Default::default())).map(|_| ());
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedClause {
+                builder: "CreateBuilder",
+                field: "data".to_string(),
+                target: "SurrealDB CREATE".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::error::{Error, Result};
+use crate::surreal::Bindings;
+use crate::surreal::idiom::validate_idiom;
+use serde_json::{Map, Value, json};
+
+/// A JSON Patch (RFC 6902) operation used by `UPDATE ... PATCH`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatchOperation {
+    Add(String, Value),     // path, value
+    Remove(String),         // path
+    Replace(String, Value), // path, value
+    Copy(String, String),   // from, path
+    Move(String, String),   // from, path
+    Test(String, Value),    // path, value
+}
+
+impl PatchOperation {
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Add(path, value) => json!({"op": "add", "path": path, "value": value}),
+            Self::Remove(path) => json!({"op": "remove", "path": path}),
+            Self::Replace(path, value) => json!({"op": "replace", "path": path, "value": value}),
+            Self::Copy(from, path) => json!({"op": "copy", "from": from, "path": path}),
+            Self::Move(from, path) => json!({"op": "move", "from": from, "path": path}),
+            Self::Test(path, value) => json!({"op": "test", "path": path, "value": value}),
+        }
+    }
+}
+
+/// The data clause of `CREATE`, `UPDATE` and `RELATE`. Values are always bound.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Data {
+    Content(Map<String, Value>), // CONTENT $p1, replaces the whole record
+    Merge(Map<String, Value>),   // MERGE $p1, merges into the record
+    Patch(Vec<PatchOperation>),  // PATCH $p1
+    Set(Vec<(String, Value)>),   // SET field = $p1, ...
+}
+
+impl Data {
+    pub fn build(&self, bindings: &mut Bindings) -> Result<String> {
+        match self {
+            Self::Content(value) => Ok(format!(
+                "CONTENT {}",
+                bindings.bind(&Value::Object(value.to_owned()))
+            )),
+            Self::Merge(value) => Ok(format!(
+                "MERGE {}",
+                bindings.bind(&Value::Object(value.to_owned()))
+            )),
+            Self::Patch(values) => {
+                if values.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "Data",
+                        field: "patch".to_string(),
+                    });
+                }
+                let values: Vec<Value> = values.iter().map(|v| v.to_value()).collect();
+                Ok(format!("PATCH {}", bindings.bind(&Value::Array(values))))
+            }
+            Self::Set(values) => {
+                if values.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "Data",
+                        field: "set".to_string(),
+                    });
+                }
+                let mut expressions: Vec<String> = Vec::new();
+                for (field, value) in values {
+                    validate_idiom("Data", "set", field)?;
+                    expressions.push(format!("{field} = {}", bindings.bind(value)));
+                }
+                Ok(format!("SET {}", expressions.join(", ")))
+            }
+        }
+    }
+}
+
+/// The `RETURN` clause of a write statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Return {
+    None,
+    Before,
+    After,
+    Diff,
+    Fields(Vec<String>),
+}
+
+impl Return {
+    pub fn build(&self) -> Result<String> {
+        match self {
+            Self::None => Ok("RETURN NONE".to_string()),
+            Self::Before => Ok("RETURN BEFORE".to_string()),
+            Self::After => Ok("RETURN AFTER".to_string()),
+            Self::Diff => Ok("RETURN DIFF".to_string()),
+            Self::Fields(fields) => {
+                if fields.is_empty() {
+                    return Err(Error::EmptyField {
+                        builder: "Return",
+                        field: "fields".to_string(),
+                    });
+                }
+                for field in fields {
+                    validate_idiom("Return", "fields", field)?;
+                }
+                Ok(format!("RETURN {}", fields.join(", ")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_data {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_data() {
+        let mut bindings = Bindings::default();
+        let content = json!({"name": "Tobie"}).as_object().unwrap().to_owned();
+        assert_eq!(
+            Data::Content(content).build(&mut bindings),
+            Ok("CONTENT $p1".to_string())
+        );
+        let result = Data::Set(vec![
+            ("name".to_string(), json!("Tobie")),
+            ("settings.theme".to_string(), json!("dark")),
+        ])
+        .build(&mut bindings);
+        assert_eq!(
+            result,
+            Ok("SET name = $p2, settings.theme = $p3".to_string())
+        );
+        let result = Data::Patch(vec![
+            PatchOperation::Replace("/name".to_string(), json!("Jaime")),
+            PatchOperation::Remove("/tags/0".to_string()),
+        ])
+        .build(&mut bindings);
+        assert_eq!(result, Ok("PATCH $p4".to_string()));
+        assert_eq!(
+            bindings.into_values()[3].1,
+            json!([
+                {"op": "replace", "path": "/name", "value": "Jaime"},
+                {"op": "remove", "path": "/tags/0"},
+            ])
+        );
+
+        assert_eq!(
+            Return::Fields(vec!["id".to_string(), "name".to_string()]).build(),
+            Ok("RETURN id, name".to_string())
+        );
+        assert!(Data::Set(vec![]).build(&mut Bindings::default()).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::surreal::idiom::validate_idiom;
+
+/// The direction of a graph edge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Out,  // ->
+    In,   // <-
+    Both, // <->
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self {
+            Self::Out => "->",
+            Self::In => "<-",
+            Self::Both => "<->",
+        };
+        write!(f, "{direction}")
+    }
+}
+
+/// Builds a graph traversal, e.g. `->likes->post.title`, to use as a column or as the
+/// `field` of a condition. A `?` table matches any table.
+///
+/// # Example
+/// ```ignore
+/// GraphBuilder::new().out("likes").out("post").field("title").build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GraphBuilder {
+    steps: Vec<(Direction, String)>,
+    field: Option<String>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn out(&mut self, table: &str) -> &mut Self {
+        self.steps.push((Direction::Out, table.to_string()));
+        self
+    }
+
+    pub fn r#in(&mut self, table: &str) -> &mut Self {
+        self.steps.push((Direction::In, table.to_string()));
+        self
+    }
+
+    pub fn both(&mut self, table: &str) -> &mut Self {
+        self.steps.push((Direction::Both, table.to_string()));
+        self
+    }
+
+    /// Selects a field of the records at the end of the traversal.
+    pub fn field(&mut self, value: &str) -> &mut Self {
+        self.field = Some(value.to_string());
+        self
+    }
+
+    pub fn build(&self) -> Result<String> {
+        if self.steps.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "GraphBuilder",
+                field: "steps".to_string(),
+            });
+        }
+        let mut statement = String::new();
+        for (direction, table) in &self.steps {
+            if table != "?" {
+                validate_identifier("GraphBuilder", "table", table)?;
+            }
+            statement.push_str(&format!("{direction}{table}"));
+        }
+        if let Some(field) = &self.field {
+            validate_idiom("GraphBuilder", "field", field)?;
+            statement.push_str(&format!(".{field}"));
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_graph_builder {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_graph_builder() {
+        let result = GraphBuilder::new()
+            .out("likes")
+            .out("post")
+            .field("title")
+            .build();
+        assert_eq!(result, Ok("->likes->post.title".to_string()));
+        let result = GraphBuilder::new().r#in("likes").r#in("person").build();
+        assert_eq!(result, Ok("<-likes<-person".to_string()));
+        let result = GraphBuilder::new().both("knows").out("?").build();
+        assert_eq!(result, Ok("<->knows->?".to_string()));
+
+        assert!(GraphBuilder::new().build().is_err(), "expecting no steps");
+        let result = GraphBuilder::new().out("likes; DELETE post").build();
+        assert!(result.is_err(), "expecting invalid table");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::error::{Error, Result};
+
+/// Checks that `value` is a SurrealQL field path (idiom), e.g. `name`, `address.city`,
+/// `tags[0]`, `emails[*]` or `->likes->post.title`. Idioms are inlined in the statement.
+pub fn is_valid_idiom(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.*[]-<>?".contains(c))
+}
+
+/// Returns an [`Error::InvalidIdentifier`] when `value` is not a SurrealQL field path.
+pub fn validate_idiom(builder: &'static str, field: &str, value: &str) -> Result<()> {
+    if !is_valid_idiom(value) {
+        return Err(Error::InvalidIdentifier {
+            builder,
+            field: field.to_string(),
+            value: value.to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod test_idiom {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_idiom() {
+        assert!(is_valid_idiom("name"));
+        assert!(is_valid_idiom("address.city"));
+        assert!(is_valid_idiom("tags[0]"));
+        assert!(is_valid_idiom("emails[*].address"));
+        assert!(is_valid_idiom("->likes->post.title"));
+        assert!(!is_valid_idiom(""));
+        assert!(!is_valid_idiom("name; DELETE user"));
+        assert!(!is_valid_idiom("$auth.id"));
+    }
+}
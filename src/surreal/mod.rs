@@ -0,0 +1,22 @@
+pub mod bindings;
+pub mod condition_builder;
+pub mod create_builder;
+pub mod data;
+pub mod graph_builder;
+pub mod idiom;
+pub mod operator;
+pub mod record_id;
+pub mod relate_builder;
+pub mod select_builder;
+pub mod update_builder;
+
+pub use bindings::Bindings;
+pub use condition_builder::{ConditionBuilder, ExpressionBuilder};
+pub use create_builder::CreateBuilder;
+pub use data::{Data, PatchOperation, Return};
+pub use graph_builder::{Direction, GraphBuilder};
+pub use operator::{ConditionOperator, SurrealOperator};
+pub use record_id::{RecordId, RecordKey, Target};
+pub use relate_builder::RelateBuilder;
+pub use select_builder::SelectBuilder;
+pub use update_builder::UpdateBuilder;
@@ -0,0 +1,68 @@
+use crate::postgres::Operator;
+use serde::{Deserialize, Serialize};
+
+/// SurrealQL operators that have no equivalent in [`Operator`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SurrealOperator {
+    Contains,     // Field contains the value (CONTAINS)
+    ContainsNot,  // Field does not contain the value (CONTAINSNOT)
+    ContainsAll,  // Field contains all the values (CONTAINSALL)
+    ContainsAny,  // Field contains any of the values (CONTAINSANY)
+    ContainsNone, // Field contains none of the values (CONTAINSNONE)
+    Inside,       // Field is inside the value (INSIDE)
+    NotInside,    // Field is not inside the value (NOTINSIDE)
+    AllInside,    // All values of the field are inside the value (ALLINSIDE)
+    AnyInside,    // Any value of the field is inside the value (ANYINSIDE)
+    NoneInside,   // No value of the field is inside the value (NONEINSIDE)
+    Matches,      // Fuzzy match (~)
+}
+
+impl std::fmt::Display for SurrealOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            Self::Contains => "CONTAINS",
+            Self::ContainsNot => "CONTAINSNOT",
+            Self::ContainsAll => "CONTAINSALL",
+            Self::ContainsAny => "CONTAINSANY",
+            Self::ContainsNone => "CONTAINSNONE",
+            Self::Inside => "INSIDE",
+            Self::NotInside => "NOTINSIDE",
+            Self::AllInside => "ALLINSIDE",
+            Self::AnyInside => "ANYINSIDE",
+            Self::NoneInside => "NONEINSIDE",
+            Self::Matches => "~",
+        };
+        write!(f, "{operator}")
+    }
+}
+
+/// The operator of a SurrealQL condition. The shared [`Operator`] variants are reused
+/// where SurrealQL has the same semantics; the others are rejected when building.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ConditionOperator {
+    Standard(Operator),
+    Surreal(SurrealOperator),
+}
+
+impl From<Operator> for ConditionOperator {
+    fn from(value: Operator) -> Self {
+        Self::Standard(value)
+    }
+}
+
+impl From<SurrealOperator> for ConditionOperator {
+    fn from(value: SurrealOperator) -> Self {
+        Self::Surreal(value)
+    }
+}
+
+impl std::fmt::Display for ConditionOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Standard(operator) => write!(f, "{operator}"),
+            Self::Surreal(operator) => write!(f, "{operator}"),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::error::{Error, Result};
+use crate::identifier::{is_valid_identifier, validate_identifier};
+
+/// The id part of a record id, e.g. the `tobie` in `person:tobie`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordKey {
+    Number(i64),
+    String(String),
+}
+
+impl From<i64> for RecordKey {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for RecordKey {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for RecordKey {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// A SurrealDB record id, `table:id`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordId {
+    pub table: String,
+    pub key: RecordKey,
+}
+
+impl RecordId {
+    pub fn new(table: &str, key: impl Into<RecordKey>) -> Self {
+        Self {
+            table: table.to_string(),
+            key: key.into(),
+        }
+    }
+
+    /// Renders `table:id`. Ids that are not plain identifiers are escaped with `⟨⟩`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// RecordId::new("person", "tobie").build(); // person:tobie
+    /// RecordId::new("person", 1).build(); // person:1
+    /// RecordId::new("person", "tobie morgan").build(); // person:⟨tobie morgan⟩
+    /// ```
+    pub fn build(&self) -> Result<String> {
+        validate_identifier("RecordId", "table", &self.table)?;
+        let key = match &self.key {
+            RecordKey::Number(value) => value.to_string(),
+            RecordKey::String(value) if value.is_empty() => {
+                return Err(Error::EmptyField {
+                    builder: "RecordId",
+                    field: "key".to_string(),
+                });
+            }
+            RecordKey::String(value) if is_valid_identifier(value) && !value.contains('$') => {
+                value.to_string()
+            }
+            RecordKey::String(value) => {
+                format!("⟨{}⟩", value.replace('\\', "\\\\").replace('⟩', "\\⟩"))
+            }
+        };
+        Ok(format!("{}:{key}", self.table))
+    }
+}
+
+/// What a statement reads from or writes to: a whole table or a single record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    Table(String),
+    Record(RecordId),
+}
+
+impl Target {
+    pub fn build(&self) -> Result<String> {
+        match self {
+            Self::Table(table) => {
+                validate_identifier("Target", "table", table)?;
+                Ok(table.to_string())
+            }
+            Self::Record(record) => record.build(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_record_id {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_id() {
+        assert_eq!(
+            RecordId::new("person", "tobie").build(),
+            Ok("person:tobie".to_string())
+        );
+        assert_eq!(
+            RecordId::new("person", 1).build(),
+            Ok("person:1".to_string())
+        );
+        assert_eq!(
+            RecordId::new("person", "tobie morgan").build(),
+            Ok("person:⟨tobie morgan⟩".to_string())
+        );
+        assert_eq!(
+            RecordId::new("person", "a⟩; DELETE person; ⟨").build(),
+            Ok("person:⟨a\\⟩; DELETE person; ⟨⟩".to_string())
+        );
+        assert!(RecordId::new("person;", 1).build().is_err());
+        assert!(RecordId::new("person", "").build().is_err());
+        assert_eq!(
+            Target::Table("person".to_string()).build(),
+            Ok("person".to_string())
+        );
+    }
+}
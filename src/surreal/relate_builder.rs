@@ -0,0 +1,126 @@
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::surreal::{Bindings, Data, RecordId, Return};
+use serde_json::Value;
+
+/// Builds `RELATE from->edge->to`, which creates an edge record between two records.
+#[derive(Clone, Debug, Default)]
+pub struct RelateBuilder {
+    from: Option<RecordId>,
+    edge: String,
+    to: Option<RecordId>,
+    data: Option<Data>,
+    returning: Option<Return>,
+}
+
+impl RelateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the records and the edge table, e.g. `person:tobie->likes->post:1`.
+    pub fn relate(&mut self, from: RecordId, edge: &str, to: RecordId) -> &mut Self {
+        self.from = Some(from);
+        self.edge = edge.to_string();
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `CONTENT` or `SET` clause of the edge record.
+    pub fn data(&mut self, value: Data) -> Result<&mut Self> {
+        if self.data.is_some() {
+            return Err(Error::DuplicateClause {
+                builder: "RelateBuilder",
+                field: "data".to_string(),
+            });
+        }
+        if matches!(value, Data::Merge(_) | Data::Patch(_)) {
+            return Err(Error::UnsupportedClause {
+                builder: "RelateBuilder",
+                field: "data".to_string(),
+                target: "SurrealDB RELATE".to_string(),
+            });
+        }
+        self.data = Some(value);
+        Ok(self)
+    }
+
+    pub fn returning(&mut self, value: Return) -> &mut Self {
+        self.returning = Some(value);
+        self
+    }
+
+    fn render(&self) -> Result<(String, Bindings)> {
+        let (Some(from), Some(to)) = (&self.from, &self.to) else {
+            return Err(Error::EmptyField {
+                builder: "RelateBuilder",
+                field: "relate".to_string(),
+            });
+        };
+        validate_identifier("RelateBuilder", "edge", &self.edge)?;
+        let mut bindings = Bindings::default();
+        let mut statement = format!("RELATE {}->{}->{}", from.build()?, self.edge, to.build()?);
+        if let Some(data) = &self.data {
+            statement = format!("{statement} {}", data.build(&mut bindings)?);
+        }
+        if let Some(returning) = &self.returning {
+            statement = format!("{statement} {}", returning.build()?);
+        }
+        Ok((statement, bindings))
+    }
+
+    /// Returns the parameter names (without the `$`) paired with their values.
+    pub fn get_values(&self) -> Vec<(String, Value)> {
+        self.render()
+            .map(|(_, bindings)| bindings.into_values())
+            .unwrap_or_default()
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.render().map(|(statement, _)| statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_relate_builder {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_relate_builder() {
+        let mut builder = RelateBuilder::new();
+        let result = builder
+            .relate(
+                RecordId::new("person", "tobie"),
+                "likes",
+                RecordId::new("post", 1),
+            )
+            .data(Data::Set(vec![(
+                "created_at".to_string(),
+                json!("2025-07-01T00:00:00Z"),
+            )]))
+            .unwrap()
+            .returning(Return::After)
+            .build();
+        assert_eq!(
+            result,
+            Ok("RELATE person:tobie->likes->post:1 SET created_at = $p1 RETURN AFTER".to_string())
+        );
+        assert_eq!(builder.get_values().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_relate_builder_errors() {
+        let builder = RelateBuilder::new();
+        assert!(builder.build().is_err(), "expecting missing records");
+        let mut builder = RelateBuilder::new();
+        let result = builder
+            .relate(
+                RecordId::new("person", "tobie"),
+                "likes->post:2",
+                RecordId::new("post", 1),
+            )
+            .build();
+        assert!(result.is_err(), "expecting invalid edge");
+    }
+}
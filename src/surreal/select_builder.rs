@@ -0,0 +1,285 @@
+use crate::error::{Error, Result};
+use crate::identifier::validate_identifier;
+use crate::postgres::Sequence;
+use crate::surreal::idiom::validate_idiom;
+use crate::surreal::{Bindings, ExpressionBuilder, GraphBuilder, Target};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct SelectBuilder {
+    pub value: bool,
+    fields: Vec<(String, Option<String>)>,
+    targets: Vec<Target>,
+    filter: Vec<ExpressionBuilder>,
+    group_by: Vec<String>,
+    order_by: Vec<(String, Sequence)>,
+    limit: Option<usize>,
+    start: Option<usize>,
+    fetch: Vec<String>,
+}
+
+impl SelectBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders `SELECT VALUE field`, which returns the values instead of objects.
+    pub fn value(&mut self, field: &str) -> &mut Self {
+        self.value = true;
+        self.fields = vec![(field.to_string(), None)];
+        self
+    }
+
+    /// Adds the tables or records to select from, e.g. `FROM person, post:1`.
+    pub fn from(&mut self, values: Vec<Target>) -> &mut Self {
+        self.targets.extend(values);
+        self
+    }
+
+    /// Selects fields by their path. If no fields are specified, it selects all fields.
+    ///
+    /// # Example
+    /// ```ignore
+    /// columns(vec!["name", "address.city"]);
+    /// ```
+    pub fn columns(&mut self, values: Vec<&str>) -> &mut Self {
+        if values.is_empty() {
+            self.fields.push(("*".to_string(), None));
+        } else {
+            self.fields
+                .extend(values.iter().map(|value| (value.to_string(), None)));
+        }
+        self
+    }
+
+    /// Selects a field under another name, `field AS alias`.
+    pub fn column_as(&mut self, field: &str, alias: &str) -> &mut Self {
+        self.fields
+            .push((field.to_string(), Some(alias.to_string())));
+        self
+    }
+
+    /// Selects a graph traversal, e.g. `->likes->post AS liked_posts`.
+    pub fn graph(&mut self, value: &GraphBuilder, alias: &str) -> Result<&mut Self> {
+        let field = value.build()?;
+        Ok(self.column_as(&field, alias))
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        self.filter = values;
+        self
+    }
+
+    pub fn group_by(&mut self, values: Vec<&str>) -> &mut Self {
+        self.group_by = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    /// SurrealQL has no `USING` ordering, so those items are rejected.
+    pub fn order_by(&mut self, values: Vec<(&str, Sequence)>) -> Result<&mut Self> {
+        if values
+            .iter()
+            .any(|(_, sequence)| matches!(sequence, Sequence::Using(_)))
+        {
+            return Err(Error::UnsupportedClause {
+                builder: "SelectBuilder",
+                field: "using".to_string(),
+                target: "SurrealDB".to_string(),
+            });
+        }
+        self.order_by = values
+            .into_iter()
+            .map(|(field, sequence)| (field.to_string(), sequence))
+            .collect();
+        Ok(self)
+    }
+
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.limit = Some(value);
+        self
+    }
+
+    /// Skips the first records, `START n`.
+    pub fn start(&mut self, value: usize) -> &mut Self {
+        self.start = Some(value);
+        self
+    }
+
+    /// Replaces record links with the linked records, `FETCH author, comments`.
+    pub fn fetch(&mut self, values: Vec<&str>) -> &mut Self {
+        self.fetch = values.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    fn render(&self) -> Result<(String, Bindings)> {
+        if self.targets.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "from".to_string(),
+            });
+        }
+        let mut bindings = Bindings::default();
+        let mut fields: Vec<String> = Vec::new();
+        for (field, alias) in &self.fields {
+            if field != "*" {
+                validate_idiom("SelectBuilder", "columns", field)?;
+            }
+            if let Some(alias) = alias {
+                validate_identifier("SelectBuilder", "columns", alias)?;
+                fields.push(format!("{field} AS {alias}"));
+            } else {
+                fields.push(field.to_string());
+            }
+        }
+        if fields.is_empty() {
+            fields.push("*".to_string());
+        }
+        let targets = self
+            .targets
+            .iter()
+            .map(|target| target.build())
+            .collect::<Result<Vec<String>>>()?;
+        let mut statement = format!(
+            "SELECT {}{} FROM {}",
+            if self.value { "VALUE " } else { "" },
+            fields.join(", "),
+            targets.join(", ")
+        );
+        if let Some(value) = ExpressionBuilder::build_where(&self.filter, &mut bindings)? {
+            statement = format!("{statement} {value}");
+        }
+        if !self.group_by.is_empty() {
+            for field in &self.group_by {
+                validate_idiom("SelectBuilder", "group_by", field)?;
+            }
+            statement = format!("{statement} GROUP BY {}", self.group_by.join(", "));
+        }
+        if !self.order_by.is_empty() {
+            let mut items: Vec<String> = Vec::new();
+            for (field, sequence) in &self.order_by {
+                validate_idiom("SelectBuilder", "order_by", field)?;
+                items.push(format!("{field} {sequence}"));
+            }
+            statement = format!("{statement} ORDER BY {}", items.join(", "));
+        }
+        if let Some(limit) = self.limit {
+            statement = format!("{statement} LIMIT {limit}");
+        }
+        if let Some(start) = self.start {
+            statement = format!("{statement} START {start}");
+        }
+        if !self.fetch.is_empty() {
+            for field in &self.fetch {
+                validate_idiom("SelectBuilder", "fetch", field)?;
+            }
+            statement = format!("{statement} FETCH {}", self.fetch.join(", "));
+        }
+        Ok((statement, bindings))
+    }
+
+    /// Returns the parameter names (without the `$`) paired with their values.
+    pub fn get_values(&self) -> Vec<(String, Value)> {
+        self.render()
+            .map(|(_, bindings)| bindings.into_values())
+            .unwrap_or_default()
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.render().map(|(statement, _)| statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_select_builder {
+    use super::*;
+    use crate::postgres::{Logic, Operator};
+    use crate::surreal::{ConditionBuilder, RecordId, SurrealOperator};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_select_builder() {
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .columns(vec!["name", "address.city"])
+            .graph(GraphBuilder::new().out("likes").out("post"), "liked_posts")
+            .unwrap()
+            .from(vec![Target::Table("person".to_string())])
+            .filter(vec![ExpressionBuilder::new(
+                vec![
+                    ConditionBuilder {
+                        field: "age".to_string(),
+                        operator: Operator::Gte.into(),
+                        value: Some(json!(18)),
+                        logic: None,
+                    },
+                    ConditionBuilder {
+                        field: "tags".to_string(),
+                        operator: SurrealOperator::Contains.into(),
+                        value: Some(json!("rust")),
+                        logic: Some(Logic::And),
+                    },
+                ],
+                None,
+            )])
+            .order_by(vec![("name", Sequence::Asc)])
+            .unwrap()
+            .limit(10)
+            .start(20)
+            .fetch(vec!["company"])
+            .build();
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(
+            result.unwrap(),
+            "SELECT name, address.city, ->likes->post AS liked_posts FROM person WHERE age >= $p1 AND tags CONTAINS $p2 ORDER BY name ASC LIMIT 10 START 20 FETCH company"
+        );
+        assert_eq!(
+            builder.get_values(),
+            vec![
+                ("p1".to_string(), json!(18)),
+                ("p2".to_string(), json!("rust")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_record() {
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .from(vec![Target::Record(RecordId::new("person", "tobie"))])
+            .build();
+        assert_eq!(result, Ok("SELECT * FROM person:tobie".to_string()));
+
+        let mut builder = SelectBuilder::new();
+        let result = builder
+            .value("name")
+            .from(vec![
+                Target::Record(RecordId::new("person", 1)),
+                Target::Record(RecordId::new("person", 2)),
+            ])
+            .build();
+        assert_eq!(
+            result,
+            Ok("SELECT VALUE name FROM person:1, person:2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_builder_errors() {
+        let builder = SelectBuilder::new();
+        assert_eq!(
+            builder.build(),
+            Err(Error::EmptyField {
+                builder: "SelectBuilder",
+                field: "from".to_string(),
+            })
+        );
+        let mut builder = SelectBuilder::new();
+        let result = builder.order_by(vec![("name", Sequence::Using(">".to_string()))]);
+        assert!(result.is_err(), "expecting USING to be rejected");
+        let result = builder
+            .from(vec![Target::Table("person".to_string())])
+            .fetch(vec!["company; REMOVE TABLE person"])
+            .build();
+        assert!(result.is_err(), "expecting invalid fetch field");
+    }
+}
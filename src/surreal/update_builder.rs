@@ -0,0 +1,163 @@
+use crate::error::{Error, Result};
+use crate::surreal::{Bindings, Data, ExpressionBuilder, Return, Target};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct UpdateBuilder {
+    targets: Vec<Target>,
+    data: Option<Data>,
+    filter: Vec<ExpressionBuilder>,
+    returning: Option<Return>,
+}
+
+impl UpdateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the tables or records to update.
+    pub fn targets(&mut self, values: Vec<Target>) -> &mut Self {
+        self.targets.extend(values);
+        self
+    }
+
+    /// Sets the `CONTENT`, `MERGE`, `PATCH` or `SET` clause. Only one can be used.
+    ///
+    /// # Example
+    /// ```ignore
+    /// data(Data::Merge(json!({"settings": {"theme": "dark"}}).as_object().unwrap().to_owned()));
+    /// ```
+    pub fn data(&mut self, value: Data) -> Result<&mut Self> {
+        if self.data.is_some() {
+            return Err(Error::DuplicateClause {
+                builder: "UpdateBuilder",
+                field: "data".to_string(),
+            });
+        }
+        self.data = Some(value);
+        Ok(self)
+    }
+
+    pub fn filter(&mut self, values: Vec<ExpressionBuilder>) -> &mut Self {
+        self.filter = values;
+        self
+    }
+
+    pub fn returning(&mut self, value: Return) -> &mut Self {
+        self.returning = Some(value);
+        self
+    }
+
+    fn render(&self) -> Result<(String, Bindings)> {
+        if self.targets.is_empty() {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "targets".to_string(),
+            });
+        }
+        let Some(data) = &self.data else {
+            return Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "data".to_string(),
+            });
+        };
+        let mut bindings = Bindings::default();
+        let targets = self
+            .targets
+            .iter()
+            .map(|target| target.build())
+            .collect::<Result<Vec<String>>>()?;
+        let mut statement = format!(
+            "UPDATE {} {}",
+            targets.join(", "),
+            data.build(&mut bindings)?
+        );
+        if let Some(value) = ExpressionBuilder::build_where(&self.filter, &mut bindings)? {
+            statement = format!("{statement} {value}");
+        }
+        if let Some(returning) = &self.returning {
+            statement = format!("{statement} {}", returning.build()?);
+        }
+        Ok((statement, bindings))
+    }
+
+    /// Returns the parameter names (without the `$`) paired with their values.
+    pub fn get_values(&self) -> Vec<(String, Value)> {
+        self.render()
+            .map(|(_, bindings)| bindings.into_values())
+            .unwrap_or_default()
+    }
+
+    pub fn build(&self) -> Result<String> {
+        self.render().map(|(statement, _)| statement)
+    }
+}
+
+#[cfg(test)]
+pub mod test_update_builder {
+    use super::*;
+    use crate::postgres::Operator;
+    use crate::surreal::{ConditionBuilder, PatchOperation, RecordId};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_update_builder() {
+        let merge = json!({"settings": {"theme": "dark"}});
+        let mut builder = UpdateBuilder::new();
+        let result = builder
+            .targets(vec![Target::Table("person".to_string())])
+            .data(Data::Merge(merge.as_object().unwrap().to_owned()))
+            .unwrap()
+            .filter(vec![ExpressionBuilder::new(
+                vec![ConditionBuilder {
+                    field: "settings.theme".to_string(),
+                    operator: Operator::NotNull.into(),
+                    value: None,
+                    logic: None,
+                }],
+                None,
+            )])
+            .returning(Return::Diff)
+            .build();
+        assert_eq!(
+            result,
+            Ok("UPDATE person MERGE $p1 WHERE settings.theme IS NOT NULL RETURN DIFF".to_string())
+        );
+        assert_eq!(builder.get_values(), vec![("p1".to_string(), merge)]);
+
+        let mut builder = UpdateBuilder::new();
+        let result = builder
+            .targets(vec![Target::Record(RecordId::new("person", "tobie"))])
+            .data(Data::Patch(vec![PatchOperation::Add(
+                "/tags/-".to_string(),
+                json!("surrealdb"),
+            )]))
+            .unwrap()
+            .build();
+        assert_eq!(result, Ok("UPDATE person:tobie PATCH $p1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_update_builder_errors() {
+        let mut builder = UpdateBuilder::new();
+        let result = builder
+            .targets(vec![Target::Table("person".to_string())])
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::EmptyField {
+                builder: "UpdateBuilder",
+                field: "data".to_string(),
+            })
+        );
+        let _ = builder.data(Data::Set(vec![("name".to_string(), json!("Tobie"))]));
+        let result = builder.data(Data::Merge(Default::default())).map(|_| ());
+        assert_eq!(
+            result,
+            Err(Error::DuplicateClause {
+                builder: "UpdateBuilder",
+                field: "data".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::error::{Error, Result};
+use crate::value::SqlValue;
+use serde::Serialize;
+
+/// A struct mapped to a table, usually derived with `#[derive(Table)]` from the `derive`
+/// feature. Used by [`InsertBuilder::from_rows`](crate::postgres::insert_builder::InsertBuilder::from_rows),
+/// [`UpdateBuilder::set_from`](crate::postgres::UpdateBuilder::set_from) and
+/// [`SelectBuilder::table_of`](crate::postgres::SelectBuilder::table_of).
+///
+/// # Example
+/// ```ignore
+/// #[derive(Table)]
+/// struct User {
+///     id: i64,
+///     email: String,
+///     #[column(json)]
+///     profile: Profile,
+/// }
+///
+/// User::table_name(); // user
+/// User::columns(); // ["id", "email", "profile"]
+/// user.values()?; // [Int8(1), Text("x"), Json({..})]
+/// ```
+pub trait Table {
+    /// The table name, may be schema qualified such as `app.users`.
+    fn table_name() -> &'static str;
+
+    /// The column names, in the order of [`values`](Self::values).
+    fn columns() -> Vec<&'static str>;
+
+    /// The columns of the primary key, empty when the table has none.
+    fn primary_key() -> Vec<&'static str>;
+
+    /// The values of the row to bind, in the order of [`columns`](Self::columns).
+    fn values(&self) -> Result<Vec<SqlValue>>;
+}
+
+/// Serializes a `#[column(json)]` field as a `jsonb` value, used by the derived
+/// [`Table::values`].
+pub fn json_value<T: Serialize + ?Sized>(
+    table: &'static str,
+    field: &str,
+    value: &T,
+) -> Result<SqlValue> {
+    serde_json::to_value(value)
+        .map(SqlValue::Json)
+        .map_err(|e| Error::InvalidValue {
+            builder: table,
+            field: field.to_string(),
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+pub mod test_table {
+    use super::*;
+    use rquery_builder_derive::Table;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Serialize)]
+    pub struct Profile {
+        pub city: String,
+    }
+
+    #[derive(Table)]
+    pub struct User {
+        pub id: i64,
+        pub name: String,
+        #[column(rename = "email_address")]
+        pub email: String,
+        pub nickname: Option<String>,
+        #[column(json)]
+        pub profile: Profile,
+        #[column(skip)]
+        pub order_count: i64,
+    }
+
+    #[derive(Table)]
+    #[table(name = "app.order_lines")]
+    pub struct OrderItem {
+        #[column(primary_key)]
+        pub order_id: i64,
+        #[column(primary_key)]
+        pub line: i32,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(Table)]
+    pub struct Draft {
+        pub id: Option<i64>,
+        pub title: String,
+    }
+
+    #[derive(Table)]
+    pub struct Event {
+        pub kind: String,
+        #[column(json)]
+        pub counts: HashMap<(i32, i32), i32>,
+    }
+
+    #[tokio::test]
+    async fn test_derive_table() {
+        let user = User {
+            id: 1,
+            name: "Juan".to_string(),
+            email: "jdc@test.com".to_string(),
+            nickname: None,
+            profile: Profile {
+                city: "Manila".to_string(),
+            },
+            order_count: 3,
+        };
+        assert_eq!(User::table_name(), "user");
+        assert_eq!(
+            User::columns(),
+            vec!["id", "name", "email_address", "nickname", "profile"]
+        );
+        assert_eq!(User::primary_key(), vec!["id"]);
+        assert_eq!(
+            user.values(),
+            Ok(vec![
+                SqlValue::Int8(1),
+                SqlValue::Text("Juan".to_string()),
+                SqlValue::Text("jdc@test.com".to_string()),
+                SqlValue::Null,
+                SqlValue::Json(json!({"city": "Manila"})),
+            ])
+        );
+
+        let item = OrderItem {
+            order_id: 7,
+            line: 2,
+            tags: vec!["gift".to_string()],
+        };
+        assert_eq!(OrderItem::table_name(), "app.order_lines");
+        assert_eq!(OrderItem::primary_key(), vec!["order_id", "line"]);
+        assert_eq!(
+            item.values(),
+            Ok(vec![
+                SqlValue::Int8(7),
+                SqlValue::Int4(2),
+                SqlValue::from(vec!["gift".to_string()]),
+            ])
+        );
+
+        assert_eq!(Event::table_name(), "event");
+        assert_eq!(Event::primary_key(), Vec::<&str>::new());
+        let event = Event {
+            kind: "click".to_string(),
+            counts: HashMap::from([((1, 2), 3)]),
+        };
+        assert_eq!(
+            event.values(),
+            Err(Error::InvalidValue {
+                builder: "Event",
+                field: "counts".to_string(),
+                message: "key must be a string".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use crate::error::{Error, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// PostgreSQL types of the bind values, used for the `$1::type` casts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SqlType {
+    Bool,
+    Int4,
+    Int8,
+    Float8,
+    Numeric,
+    Text,
+    Bytea,
+    Uuid,
+    Date,
+    Timestamp,
+    Timestamptz,
+    Jsonb,
+    Array(Box<SqlType>), // e.g. int4[]
+}
+
+impl std::fmt::Display for SqlType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int4 => write!(f, "int4"),
+            Self::Int8 => write!(f, "int8"),
+            Self::Float8 => write!(f, "float8"),
+            Self::Numeric => write!(f, "numeric"),
+            Self::Text => write!(f, "text"),
+            Self::Bytea => write!(f, "bytea"),
+            Self::Uuid => write!(f, "uuid"),
+            Self::Date => write!(f, "date"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Timestamptz => write!(f, "timestamptz"),
+            Self::Jsonb => write!(f, "jsonb"),
+            Self::Array(value) => write!(f, "{value}[]"),
+        }
+    }
+}
+
+/// A typed bind value, keeping the distinctions a `serde_json::Value` loses.
+///
+/// The builders still carry `serde_json::Value`s, so a typed value is bound as its
+/// [`to_json`](Self::to_json) form, and its placeholder gets a cast to its type when
+/// JSON can't carry it as is, e.g. `$1::uuid` or `$2::timestamptz`. Use
+/// [`cast`](Self::cast) to pick the cast explicitly. Casts are PostgreSQL syntax, they
+/// are dropped when rendering for a dialect without them, see
+/// [`Dialect::supports_casts`](crate::dialect::Dialect::supports_casts).
+///
+/// # Example
+/// ```ignore
+/// ConditionValue::Typed(SqlValue::from(Utc::now())); // o.created_at > $1::timestamptz
+/// ConditionValue::Typed(SqlValue::from("2025-01-01").cast(SqlType::Date)); // $1::date
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum SqlValue {
+    Null,
+    Bool(bool),
+    Int4(i32),
+    Int8(i64),
+    Float8(f64),
+    Numeric(String), // An exact decimal kept as text, e.g. "12.50"
+    Text(String),
+    Bytea(Vec<u8>),
+    Uuid(Uuid),
+    Date(NaiveDate),
+    Timestamp(NaiveDateTime),
+    Timestamptz(DateTime<Utc>),
+    Json(Value),
+    Array(SqlType, Vec<SqlValue>), // (element type, items)
+    Cast(Box<SqlValue>, SqlType),  // A value with an explicit cast
+}
+
+impl SqlValue {
+    /// Builds a typed array, e.g. `SqlValue::array(SqlType::Int4, vec![1, 2])` for `int4[]`.
+    pub fn array<T: Into<SqlValue>>(element: SqlType, items: Vec<T>) -> Self {
+        Self::Array(element, items.into_iter().map(Into::into).collect())
+    }
+
+    /// Casts the placeholder of the value to `value`, e.g. `$1::timestamptz`.
+    pub fn cast(self, value: SqlType) -> Self {
+        match self {
+            Self::Cast(inner, _) => Self::Cast(inner, value),
+            _ => Self::Cast(Box::new(self), value),
+        }
+    }
+
+    /// Whether the value is an array, cast or not.
+    pub fn is_array(&self) -> bool {
+        match self {
+            Self::Array(..) => true,
+            Self::Cast(value, _) => value.is_array(),
+            _ => false,
+        }
+    }
+
+    /// The items of an array value, cast to the element type of an explicit array cast.
+    /// Used to bind an `IN` list one placeholder per item.
+    pub fn items(&self) -> Option<Vec<SqlValue>> {
+        match self {
+            Self::Array(_, items) => Some(items.to_owned()),
+            Self::Cast(value, SqlType::Array(element)) => value.items().map(|items| {
+                items
+                    .into_iter()
+                    .map(|item| item.cast(element.as_ref().to_owned()))
+                    .collect()
+            }),
+            Self::Cast(value, _) => value.items(),
+            _ => None,
+        }
+    }
+
+    /// The type of the value, `None` for `NULL` which PostgreSQL types from its context.
+    pub fn sql_type(&self) -> Option<SqlType> {
+        let value = match self {
+            Self::Null => return None,
+            Self::Bool(_) => SqlType::Bool,
+            Self::Int4(_) => SqlType::Int4,
+            Self::Int8(_) => SqlType::Int8,
+            Self::Float8(_) => SqlType::Float8,
+            Self::Numeric(_) => SqlType::Numeric,
+            Self::Text(_) => SqlType::Text,
+            Self::Bytea(_) => SqlType::Bytea,
+            Self::Uuid(_) => SqlType::Uuid,
+            Self::Date(_) => SqlType::Date,
+            Self::Timestamp(_) => SqlType::Timestamp,
+            Self::Timestamptz(_) => SqlType::Timestamptz,
+            Self::Json(_) => SqlType::Jsonb,
+            Self::Array(element, _) => SqlType::Array(Box::new(element.to_owned())),
+            Self::Cast(_, value) => value.to_owned(),
+        };
+        Some(value)
+    }
+
+    /// The cast of the placeholder: the explicit one, or the type of the value when its
+    /// JSON form would lose it. Booleans, `int8`, `float8` and text need no cast.
+    pub fn placeholder_cast(&self) -> Option<SqlType> {
+        match self {
+            Self::Null | Self::Bool(_) | Self::Int8(_) | Self::Float8(_) | Self::Text(_) => None,
+            _ => self.sql_type(),
+        }
+    }
+
+    /// Renders the `?` placeholder of the value, with its cast if any.
+    pub fn placeholder(&self) -> String {
+        match self.placeholder_cast() {
+            Some(value) => format!("?::{value}"),
+            None => "?".to_string(),
+        }
+    }
+
+    /// The value as bound by the builders. Numerics, UUIDs and dates become strings in
+    /// the format PostgreSQL parses back, and byteas a `\x` hex string. Errors on the
+    /// `NaN` and infinite floats, which JSON can't carry.
+    pub fn to_json(&self) -> Result<Value> {
+        let value = match self {
+            Self::Null => Value::Null,
+            Self::Bool(value) => Value::Bool(*value),
+            Self::Int4(value) => Value::from(*value),
+            Self::Int8(value) => Value::from(*value),
+            Self::Float8(value) if !value.is_finite() => {
+                return Err(Error::InvalidValue {
+                    builder: "SqlValue",
+                    field: "float8".to_string(),
+                    message: format!("{value} has no JSON form"),
+                });
+            }
+            Self::Float8(value) => Value::from(*value),
+            Self::Numeric(value) | Self::Text(value) => Value::String(value.to_owned()),
+            Self::Bytea(value) => {
+                let hex: String = value.iter().map(|byte| format!("{byte:02x}")).collect();
+                Value::String(format!("\\x{hex}"))
+            }
+            Self::Uuid(value) => Value::String(value.to_string()),
+            Self::Date(value) => Value::String(value.format("%Y-%m-%d").to_string()),
+            Self::Timestamp(value) => {
+                Value::String(value.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+            }
+            Self::Timestamptz(value) => {
+                Value::String(value.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+            }
+            Self::Json(value) => value.to_owned(),
+            Self::Array(_, items) => {
+                Value::Array(items.iter().map(Self::to_json).collect::<Result<_>>()?)
+            }
+            Self::Cast(value, _) => value.to_json()?,
+        };
+        Ok(value)
+    }
+}
+
+/// Maps JSON values to their closest type: integers to `int8`, other numbers to
+/// `float8`, strings to text, and arrays and objects to `jsonb`.
+impl From<Value> for SqlValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(value) => Self::Bool(value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => Self::Int8(value),
+                None => Self::Float8(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(value) => Self::Text(value),
+            value => Self::Json(value),
+        }
+    }
+}
+
+impl<T: Into<SqlValue>> From<Option<T>> for SqlValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Null)
+    }
+}
+
+impl From<&str> for SqlValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<f32> for SqlValue {
+    fn from(value: f32) -> Self {
+        Self::Float8(value.into())
+    }
+}
+
+impl From<DateTime<chrono::FixedOffset>> for SqlValue {
+    fn from(value: DateTime<chrono::FixedOffset>) -> Self {
+        Self::Timestamptz(value.with_timezone(&Utc))
+    }
+}
+
+macro_rules! impl_from {
+    ($($source:ty => $variant:ident, $element:ident;)*) => {
+        $(
+            impl From<$source> for SqlValue {
+                fn from(value: $source) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            impl From<Vec<$source>> for SqlValue {
+                fn from(value: Vec<$source>) -> Self {
+                    Self::array(SqlType::$element, value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    bool => Bool, Bool;
+    i32 => Int4, Int4;
+    i64 => Int8, Int8;
+    f64 => Float8, Float8;
+    String => Text, Text;
+    Uuid => Uuid, Uuid;
+    NaiveDate => Date, Date;
+    NaiveDateTime => Timestamp, Timestamp;
+    DateTime<Utc> => Timestamptz, Timestamptz;
+}
+
+impl From<Vec<u8>> for SqlValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytea(value)
+    }
+}
+
+impl From<&[u8]> for SqlValue {
+    fn from(value: &[u8]) -> Self {
+        Self::Bytea(value.to_vec())
+    }
+}
+
+#[cfg(test)]
+pub mod test_value {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_sql_value() {
+        let created_at = DateTime::parse_from_rfc3339("2025-03-01T10:30:00.5+02:00").unwrap();
+        let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let cases: Vec<(SqlValue, &str, Value)> = vec![
+            (SqlValue::from(7), "?::int4", json!(7)),
+            (SqlValue::from(7_i64), "?", json!(7)),
+            (SqlValue::from(1.5), "?", json!(1.5)),
+            (
+                SqlValue::Numeric("12.50".to_string()),
+                "?::numeric",
+                json!("12.50"),
+            ),
+            (SqlValue::from("it's"), "?", json!("it's")),
+            (SqlValue::from(None::<i32>), "?", json!(null)),
+            (
+                SqlValue::from(vec![0xde_u8, 0xad]),
+                "?::bytea",
+                json!("\\xdead"),
+            ),
+            (
+                SqlValue::from(id),
+                "?::uuid",
+                json!("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            ),
+            (
+                SqlValue::from(created_at),
+                "?::timestamptz",
+                json!("2025-03-01T08:30:00.500Z"),
+            ),
+            (
+                SqlValue::from(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()),
+                "?::date",
+                json!("2025-03-01"),
+            ),
+            (SqlValue::from(vec![1, 2]), "?::int4[]", json!([1, 2])),
+            (
+                SqlValue::array(SqlType::Text, vec!["a", "b"]),
+                "?::text[]",
+                json!(["a", "b"]),
+            ),
+            (SqlValue::from(json!({"a": 1})), "?::jsonb", json!({"a": 1})),
+            (
+                SqlValue::from("2025-03-01").cast(SqlType::Date),
+                "?::date",
+                json!("2025-03-01"),
+            ),
+        ];
+        for (value, placeholder, json) in cases {
+            assert_eq!(value.placeholder(), placeholder, "{value:?}");
+            assert_eq!(value.to_json(), Ok(json), "{value:?}");
+        }
+
+        assert_eq!(
+            SqlValue::from(vec![1.5, f64::NAN]).to_json(),
+            Err(Error::InvalidValue {
+                builder: "SqlValue",
+                field: "float8".to_string(),
+                message: "NaN has no JSON form".to_string(),
+            })
+        );
+        assert!(SqlValue::from(f64::INFINITY).to_json().is_err());
+        assert_eq!(
+            SqlValue::from(vec![1, 2])
+                .cast(SqlType::Array(Box::new(SqlType::Int8)))
+                .items(),
+            Some(vec![
+                SqlValue::from(1).cast(SqlType::Int8),
+                SqlValue::from(2).cast(SqlType::Int8)
+            ])
+        );
+
+        assert_eq!(SqlValue::from(json!(3)), SqlValue::Int8(3));
+        assert_eq!(SqlValue::from(json!(3.5)), SqlValue::Float8(3.5));
+        assert_eq!(
+            SqlValue::from(7).cast(SqlType::Int8).cast(SqlType::Numeric),
+            SqlValue::Cast(Box::new(SqlValue::Int4(7)), SqlType::Numeric)
+        );
+    }
+}